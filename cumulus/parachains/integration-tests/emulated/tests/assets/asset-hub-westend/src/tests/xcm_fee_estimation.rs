@@ -17,7 +17,10 @@
 
 use crate::imports::*;
 
-use emulated_integration_tests_common::test_can_estimate_and_pay_exact_fees;
+use emulated_integration_tests_common::{
+	test_can_estimate_and_pay_exact_fees,
+	xcm_emulator::dry_run::{HopEffects, MultiHopDryRun, WasmChain},
+};
 use frame_support::dispatch::RawOrigin;
 use xcm_runtime_apis::{
 	dry_run::runtime_decl_for_dry_run_api::DryRunApiV1,
@@ -277,6 +280,191 @@ fn multi_hop_works() {
 	);
 }
 
+/// Same as `multi_hop_works`, but the fees of every hop are estimated in one go with
+/// `MultiHopDryRun`.
+#[test]
+fn multi_hop_dry_run_works() {
+	let destination = PenpalA::sibling_location_of(PenpalB::para_id());
+	let sender = PenpalASender::get();
+	let amount_to_send = 1_000_000_000_000;
+	let asset_owner = PenpalAssetOwner::get();
+	let assets: Assets = (Parent, amount_to_send).into();
+	let relay_native_asset_location = Location::parent();
+	let sender_as_seen_by_ah = AssetHubWestend::sibling_location_of(PenpalA::para_id());
+	let sov_of_sender_on_ah = AssetHubWestend::sovereign_account_id_of(sender_as_seen_by_ah);
+
+	// fund Parachain's sender account
+	PenpalA::mint_foreign_asset(
+		<PenpalA as Chain>::RuntimeOrigin::signed(asset_owner),
+		relay_native_asset_location.clone(),
+		sender.clone(),
+		amount_to_send * 2,
+	);
+
+	// fund the Parachain Origin's SA on AssetHub with the native tokens held in reserve.
+	AssetHubWestend::fund_accounts(vec![(sov_of_sender_on_ah, amount_to_send * 2)]);
+
+	// Init values for Parachain Destination
+	let beneficiary_id = PenpalBReceiver::get();
+
+	let test_args = TestContext {
+		sender: PenpalASender::get(),
+		receiver: PenpalBReceiver::get(),
+		args: TestArgs::new_para(
+			destination,
+			beneficiary_id.clone(),
+			amount_to_send,
+			assets,
+			None,
+			0,
+		),
+	};
+	let mut test = ParaToParaThroughAHTest::new(test_args);
+
+	let sender_assets_before = PenpalA::execute_with(|| {
+		type ForeignAssets = <PenpalA as PenpalAPallet>::ForeignAssets;
+		<ForeignAssets as Inspect<_>>::balance(relay_native_asset_location.clone(), &sender)
+	});
+	let receiver_assets_before = PenpalB::execute_with(|| {
+		type ForeignAssets = <PenpalB as PenpalBPallet>::ForeignAssets;
+		<ForeignAssets as Inspect<_>>::balance(relay_native_asset_location.clone(), &beneficiary_id)
+	});
+
+	// Dry-run the whole journey.
+	let effects = MultiHopDryRun::new()
+		.with_parachain::<PenpalA>()
+		.with_parachain::<AssetHubWestend>()
+		.with_parachain::<PenpalB>()
+		.dry_run_call::<PenpalA>(
+			<PenpalA as Chain>::OriginCaller::system(RawOrigin::Signed(sender.clone())),
+			transfer_assets_para_to_para_through_ah_call(test.clone()),
+		)
+		.unwrap();
+	assert!(effects.is_success());
+	assert_eq!(effects.unrouted_xcms().count(), 0);
+	assert_eq!(effects.hops_on::<AssetHubWestend>().count(), 1);
+	assert_eq!(effects.hops_on::<PenpalB>().count(), 1);
+
+	let fee_asset = VersionedAssetId::from(AssetId(Location::parent()));
+	let delivery_fees = |hop: &HopEffects| {
+		hop.forwarded_xcms
+			.iter()
+			.map(|forwarded| get_amount_from_versioned_assets(forwarded.delivery_fees.clone()))
+			.sum::<u128>()
+	};
+	let execution_fees = |hop: &HopEffects| {
+		hop.execution_fees.iter().find(|(asset, _)| *asset == fee_asset).unwrap().1
+	};
+	let intermediate_hop = effects.hops_on::<AssetHubWestend>().next().unwrap();
+	let final_hop = effects.hops_on::<PenpalB>().next().unwrap();
+	let delivery_fees_amount = delivery_fees(&effects.hops[0]);
+	let intermediate_execution_fees = execution_fees(intermediate_hop);
+	let intermediate_delivery_fees_amount = delivery_fees(intermediate_hop);
+	let final_execution_fees = execution_fees(final_hop);
+
+	// Dry-running left the state untouched.
+	PenpalA::execute_with(|| {
+		type ForeignAssets = <PenpalA as PenpalAPallet>::ForeignAssets;
+		assert_eq!(
+			<ForeignAssets as Inspect<_>>::balance(relay_native_asset_location.clone(), &sender),
+			sender_assets_before
+		);
+	});
+
+	// Actually run the extrinsic.
+	test.set_assertion::<PenpalA>(sender_assertions);
+	test.set_assertion::<AssetHubWestend>(hop_assertions);
+	test.set_assertion::<PenpalB>(receiver_assertions);
+	let call = transfer_assets_para_to_para_through_ah_call(test.clone());
+	test.set_call(call);
+	test.assert();
+
+	let sender_assets_after = PenpalA::execute_with(|| {
+		type ForeignAssets = <PenpalA as PenpalAPallet>::ForeignAssets;
+		<ForeignAssets as Inspect<_>>::balance(relay_native_asset_location.clone(), &sender)
+	});
+	let receiver_assets_after = PenpalB::execute_with(|| {
+		type ForeignAssets = <PenpalB as PenpalBPallet>::ForeignAssets;
+		<ForeignAssets as Inspect<_>>::balance(relay_native_asset_location, &beneficiary_id)
+	});
+
+	// The dry-run reported the exact fees of every hop.
+	assert_eq!(sender_assets_after, sender_assets_before - amount_to_send - delivery_fees_amount);
+	assert_eq!(
+		receiver_assets_after,
+		receiver_assets_before + amount_to_send -
+			intermediate_execution_fees -
+			intermediate_delivery_fees_amount -
+			final_execution_fees
+	);
+}
+
+/// Dry-running a journey against the runtime wasm blobs and genesis state of the chains reports
+/// the same fees as dry-running it against the native chains, and leaves no changes behind.
+#[test]
+fn multi_hop_dry_run_on_wasm_works() {
+	use westend_system_emulated_network::{
+		asset_hub_westend_emulated_chain::genesis as asset_hub_westend_genesis,
+		westend_emulated_chain::{genesis as westend_genesis, westend_runtime},
+	};
+
+	let sender = WestendSender::get();
+	let call =
+		<Westend as Chain>::RuntimeCall::XcmPallet(pallet_xcm::Call::limited_teleport_assets {
+			dest: bx!(Westend::child_location_of(AssetHubWestend::para_id()).into()),
+			beneficiary: bx!(AccountId32Junction {
+				network: None,
+				id: AssetHubWestendReceiver::get().into()
+			}
+			.into()),
+			assets: bx!((Here, WESTEND_ED * 1000).into()),
+			fee_asset_item: 0,
+			weight_limit: Unlimited,
+		});
+	let origin = <Westend as Chain>::OriginCaller::system(RawOrigin::Signed(sender));
+
+	let native_effects = MultiHopDryRun::new()
+		.with_relay_chain::<Westend>()
+		.with_parachain::<AssetHubWestend>()
+		.dry_run_call::<Westend>(origin.clone(), call.clone())
+		.unwrap();
+
+	let westend = WasmChain::<westend_runtime::RuntimeEvent>::new(
+		"westend",
+		westend_runtime::WASM_BINARY.unwrap().to_vec(),
+		westend_genesis::genesis(),
+	);
+	let asset_hub = WasmChain::<asset_hub_westend_runtime::RuntimeEvent>::new(
+		"asset-hub-westend",
+		asset_hub_westend_runtime::WASM_BINARY.unwrap().to_vec(),
+		asset_hub_westend_genesis::genesis(),
+	);
+	let mut dry_run = MultiHopDryRun::new()
+		.with_chain(Location::here(), westend)
+		.with_chain(Location::new(0, [Parachain(AssetHubWestend::para_id().into())]), asset_hub);
+	let wasm_effects = dry_run
+		.dry_run_encoded_call("westend", &origin.encode(), &call.encode())
+		.unwrap();
+	assert!(wasm_effects.is_success());
+	assert_eq!(wasm_effects.unrouted_xcms().count(), 0);
+	assert_eq!(wasm_effects.hops_on_chain("asset-hub-westend").count(), 1);
+
+	// Both dry-runs estimated the same fees.
+	assert_eq!(native_effects.hops.len(), wasm_effects.hops.len());
+	for (native_hop, wasm_hop) in native_effects.hops.iter().zip(&wasm_effects.hops) {
+		assert_eq!(native_hop.result, wasm_hop.result);
+		assert_eq!(native_hop.execution_fees, wasm_hop.execution_fees);
+		assert_eq!(native_hop.forwarded_xcms, wasm_hop.forwarded_xcms);
+	}
+
+	// The changes of the first journey were discarded, so dry-running it again gives the same
+	// effects.
+	assert_eq!(
+		dry_run.dry_run_encoded_call("westend", &origin.encode(), &call.encode()),
+		Ok(wasm_effects)
+	);
+}
+
 #[test]
 fn multi_hop_pay_fees_works() {
 	test_can_estimate_and_pay_exact_fees!(
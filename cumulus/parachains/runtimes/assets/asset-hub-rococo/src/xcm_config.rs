@@ -512,6 +512,7 @@ impl pallet_xcm::Config for Runtime {
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
+	type MaxTrappedAssetsIndexed = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
 }
 
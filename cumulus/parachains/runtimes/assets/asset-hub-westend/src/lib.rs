@@ -276,6 +276,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Freezer = AssetsFreezer;
+	type Holder = ();
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = pallet_assets::AutoIncAssetId<Runtime, TrustBackedAssetsInstance>;
@@ -320,6 +321,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = PoolAssetsFreezer;
+	type Holder = ();
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -545,6 +547,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type ApprovalDeposit = ForeignAssetsApprovalDeposit;
 	type StringLimit = ForeignAssetsAssetsStringLimit;
 	type Freezer = ForeignAssetsFreezer;
+	type Holder = ();
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -1622,6 +1625,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -1639,6 +1643,11 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: xcm::v5::Location, asset2: xcm::v5::Location) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn get_pool_kind(asset1: xcm::v5::Location, asset2: xcm::v5::Location) -> Option<pallet_asset_conversion::PoolKind> {
+			AssetConversion::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+			AssetConversion::pool_kind(&asset1, &asset2).ok()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -1714,6 +1723,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
@@ -1722,6 +1732,14 @@ impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_xcm::<Runtime, xcm_config::XcmRouter, RuntimeCall, xcm_config::XcmConfig>(origin_location, xcm)
 		}
+
+		fn dry_run_call_with_trace(origin: OriginCaller, call: RuntimeCall) -> Result<(CallDryRunEffects<RuntimeEvent>, Vec<xcm_runtime_apis::dry_run::VersionedXcmTraceStep>), XcmDryRunApiError> {
+			PolkadotXcm::dry_run_call_with_trace::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
+		}
+
+		fn dry_run_xcm_with_trace(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<(XcmDryRunEffects<RuntimeEvent>, Vec<xcm_runtime_apis::dry_run::VersionedXcmTraceStep>), XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm_with_trace::<Runtime, xcm_config::XcmRouter, RuntimeCall, xcm_config::XcmConfig>(origin_location, xcm)
+		}
 	}
 
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
@@ -2205,6 +2223,13 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::trapped_assets::TrappedAssetsApi<Block, BlockNumber> for Runtime {
+		fn trapped_assets(origin: VersionedLocation, start_after: Option<sp_core::H256>, limit: u32) -> Result<Vec<xcm_runtime_apis::trapped_assets::TrappedAssets<BlockNumber>>, xcm_runtime_apis::trapped_assets::Error> {
+			PolkadotXcm::trapped_assets(origin, start_after, limit)
+		}
+	}
+
+	#[api_version(2)]
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber> for Runtime
 	{
 		fn balance(address: H160) -> U256 {
@@ -2237,6 +2262,27 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			pallet_revive::evm::trace_tx::<Runtime, _>(
+				block,
+				tx_index,
+				config,
+				|header| { Executive::initialize_block(header); },
+				|ext| { let _ = Executive::apply_extrinsic(ext); },
+			)
+		}
+
+		fn trace_call(
+			tx: pallet_revive::evm::GenericTransaction,
+			config: pallet_revive::evm::TracerConfig
+		) -> Result<pallet_revive::evm::Trace, pallet_revive::EthTransactError> {
+			pallet_revive::evm::trace_call::<Runtime, _>(config, || Self::eth_transact(tx))
+		}
+
 		fn call(
 			origin: AccountId,
 			dest: H160,
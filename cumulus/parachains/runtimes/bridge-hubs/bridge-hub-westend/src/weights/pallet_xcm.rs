@@ -372,6 +372,8 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 	/// Proof: `PolkadotXcm::ShouldRecordXcm` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
 	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::TrappedAssetsIndex` (r:0 w:1)
+	/// Proof: `PolkadotXcm::TrappedAssetsIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn claim_assets() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `90`
@@ -380,6 +382,26 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(42_750_000, 0)
 			.saturating_add(Weight::from_parts(0, 3555))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::TrappedAssetsIndexHead` (r:1 w:1)
+	/// Proof: `PolkadotXcm::TrappedAssetsIndexHead` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::TrappedAssetsIndexTail` (r:1 w:1)
+	/// Proof: `PolkadotXcm::TrappedAssetsIndexTail` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::TrappedAssetsIndexQueue` (r:2 w:3)
+	/// Proof: `PolkadotXcm::TrappedAssetsIndexQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::TrappedAssetsIndex` (r:2 w:3)
+	/// Proof: `PolkadotXcm::TrappedAssetsIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn drop_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `6172`
+		// Minimum execution time: 29_313_000 picoseconds.
+		Weight::from_parts(29_925_000, 0)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 }
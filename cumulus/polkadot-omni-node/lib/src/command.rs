@@ -26,7 +26,7 @@ use crate::{
 		NodeBlock, NodeExtraArgs,
 	},
 	fake_runtime_api,
-	nodes::DynNodeSpecExt,
+	nodes::{DynNodeSpecExt, DynSolochainNodeSpec, NodeKind},
 	runtime::BlockNumber,
 };
 #[cfg(feature = "runtime-benchmarks")]
@@ -35,6 +35,7 @@ use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
 use sc_cli::{CliConfiguration, Result, SubstrateCli};
+use sc_sysinfo::HwBench;
 use sp_runtime::traits::AccountIdConversion;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_runtime::traits::HashingFor;
@@ -81,23 +82,61 @@ where
 	}
 }
 
+pub fn new_aura_grandpa_node_spec<Block>(aura_id: AuraConsensusId) -> Box<dyn DynSolochainNodeSpec>
+where
+	Block: NodeBlock,
+	sp_runtime::traits::NumberFor<Block>: sc_consensus_grandpa::BlockNumberOps,
+{
+	match aura_id {
+		AuraConsensusId::Sr25519 => crate::nodes::aura_grandpa::new_aura_grandpa_node_spec::<
+			Block,
+			fake_runtime_api::aura_sr25519::RuntimeApi,
+			sp_consensus_aura::sr25519::AuthorityId,
+		>(),
+		AuraConsensusId::Ed25519 => crate::nodes::aura_grandpa::new_aura_grandpa_node_spec::<
+			Block,
+			fake_runtime_api::aura_ed25519::RuntimeApi,
+			sp_consensus_aura::ed25519::AuthorityId,
+		>(),
+	}
+}
+
 fn new_node_spec(
 	config: &sc_service::Configuration,
 	runtime_resolver: &Box<dyn RuntimeResolverT>,
 	extra_args: &NodeExtraArgs,
-) -> std::result::Result<Box<dyn DynNodeSpecExt>, sc_cli::Error> {
+) -> std::result::Result<NodeKind, sc_cli::Error> {
 	let runtime = runtime_resolver.runtime(config.chain_spec.as_ref())?;
 
 	Ok(match runtime {
 		Runtime::Omni(block_number, consensus) => match (block_number, consensus) {
 			(BlockNumber::U32, Consensus::Aura(aura_id)) =>
-				new_aura_node_spec::<Block<u32>>(aura_id, extra_args),
+				NodeKind::Parachain(new_aura_node_spec::<Block<u32>>(aura_id, extra_args)),
 			(BlockNumber::U64, Consensus::Aura(aura_id)) =>
-				new_aura_node_spec::<Block<u64>>(aura_id, extra_args),
+				NodeKind::Parachain(new_aura_node_spec::<Block<u64>>(aura_id, extra_args)),
+			(BlockNumber::U32, Consensus::AuraGrandpa(aura_id)) =>
+				NodeKind::Solochain(new_aura_grandpa_node_spec::<Block<u32>>(aura_id)),
+			(BlockNumber::U64, Consensus::AuraGrandpa(aura_id)) =>
+				NodeKind::Solochain(new_aura_grandpa_node_spec::<Block<u64>>(aura_id)),
 		},
 	})
 }
 
+/// Gather the hardware benchmarks of the machine, unless they are disabled.
+fn gather_hwbench(
+	no_hardware_benchmarks: bool,
+	config: &sc_service::Configuration,
+) -> Option<HwBench> {
+	(!no_hardware_benchmarks)
+		.then(|| {
+			config.database.path().map(|database_path| {
+				let _ = std::fs::create_dir_all(database_path);
+				sc_sysinfo::gather_hwbench(Some(database_path), &SUBSTRATE_REFERENCE_HARDWARE)
+			})
+		})
+		.flatten()
+}
+
 /// Parse command line arguments into service configuration.
 pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()> {
 	let mut cli = Cli::<CliConfig>::from_args();
@@ -112,7 +151,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.prepare_check_block_cmd(config, cmd)
 			})
 		},
@@ -120,7 +160,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.prepare_export_blocks_cmd(config, cmd)
 			})
 		},
@@ -128,7 +169,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.prepare_export_state_cmd(config, cmd)
 			})
 		},
@@ -136,7 +178,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.prepare_import_blocks_cmd(config, cmd)
 			})
 		},
@@ -144,7 +187,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.prepare_revert_cmd(config, cmd)
 			})
 		},
@@ -168,7 +212,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| {
 				let node =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?
+						.into_command_runner();
 				node.run_export_genesis_head_cmd(config, cmd)
 			})
 		},
@@ -195,7 +240,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 						&config,
 						&cmd_config.runtime_resolver,
 						&cli.node_extra_args(),
-					)?;
+					)?
+					.into_command_runner();
 					node.run_benchmark_block_cmd(config, cmd)
 				}),
 				#[cfg(feature = "runtime-benchmarks")]
@@ -204,7 +250,8 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 						&config,
 						&cmd_config.runtime_resolver,
 						&cli.node_extra_args(),
-					)?;
+					)?
+					.into_command_runner();
 					node.run_benchmark_storage_cmd(config, cmd)
 				}),
 				BenchmarkCmd::Machine(cmd) =>
@@ -224,8 +271,30 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 			let collator_options = cli.run.collator_options();
 
 			runner.run_node_until_exit(|config| async move {
-				let node_spec =
-					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
+				let node_spec = match new_node_spec(
+					&config,
+					&cmd_config.runtime_resolver,
+					&cli.node_extra_args(),
+				)? {
+					NodeKind::Parachain(node_spec) => node_spec,
+					NodeKind::Solochain(node_spec) => {
+						if cli.dev_block_time.is_some() {
+							return Err(
+								"`--dev-block-time` is only supported by parachain runtimes. \
+								Standalone chains produce blocks with Aura, also in dev mode."
+									.into(),
+							);
+						}
+
+						let hwbench = gather_hwbench(cli.no_hardware_benchmarks, &config);
+						info!(
+							"✍️ Is authoring: {}",
+							if config.role.is_authority() { "yes" } else { "no" }
+						);
+
+						return node_spec.start_node(config, hwbench).map_err(Into::into);
+					},
+				};
 				let para_id = ParaId::from(
 					Extensions::try_get(&*config.chain_spec)
 						.map(|e| e.para_id)
@@ -286,17 +355,7 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 					}
 				}
 
-				let hwbench = (!cli.no_hardware_benchmarks)
-					.then(|| {
-						config.database.path().map(|database_path| {
-							let _ = std::fs::create_dir_all(database_path);
-							sc_sysinfo::gather_hwbench(
-								Some(database_path),
-								&SUBSTRATE_REFERENCE_HARDWARE,
-							)
-						})
-					})
-					.flatten();
+				let hwbench = gather_hwbench(cli.no_hardware_benchmarks, &config);
 
 				let parachain_account =
 					AccountIdConversion::<polkadot_primitives::AccountId>::into_account_truncating(
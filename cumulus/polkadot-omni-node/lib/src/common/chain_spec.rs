@@ -17,7 +17,7 @@
 //! Chain spec primitives.
 
 pub use sc_chain_spec::ChainSpec;
-use sc_chain_spec::ChainSpecExtension;
+use sc_chain_spec::{ChainSpecExtension, NoExtension};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -32,7 +32,13 @@ pub struct DiskChainSpecLoader;
 
 impl LoadSpec for DiskChainSpecLoader {
 	fn load_spec(&self, path: &str) -> Result<Box<dyn ChainSpec>, String> {
-		Ok(Box::new(GenericChainSpec::from_json_file(path.into())?))
+		match GenericChainSpec::from_json_file(path.into()) {
+			Ok(chain_spec) => Ok(Box::new(chain_spec)),
+			// Chain specs of standalone chains don't have the parachain extensions.
+			Err(err) => sc_service::GenericChainSpec::<NoExtension>::from_json_file(path.into())
+				.map(|chain_spec| Box::new(chain_spec) as Box<dyn ChainSpec>)
+				.map_err(|_| err),
+		}
 	}
 }
 
@@ -21,7 +21,7 @@ use cumulus_client_service::ParachainHostFunctions;
 use sc_chain_spec::ChainSpec;
 use sc_executor::WasmExecutor;
 use sc_runtime_utilities::fetch_latest_metadata_from_code_blob;
-use scale_info::{form::PortableForm, TypeDef, TypeDefPrimitive};
+use scale_info::{form::PortableForm, Path, TypeDef, TypeDefPrimitive};
 use std::fmt::Display;
 use subxt_metadata::{Metadata, StorageEntryType};
 
@@ -29,9 +29,17 @@ use subxt_metadata::{Metadata, StorageEntryType};
 pub const DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME: &str = "ParachainSystem";
 /// Expected frame system pallet runtime type name.
 pub const DEFAULT_FRAME_SYSTEM_PALLET_NAME: &str = "System";
+/// Expected aura pallet runtime type name.
+pub const DEFAULT_AURA_PALLET_NAME: &str = "Aura";
+/// Expected aura runtime API name.
+pub const DEFAULT_AURA_API_NAME: &str = "AuraApi";
+/// Expected grandpa pallet runtime type name.
+pub const DEFAULT_GRANDPA_PALLET_NAME: &str = "Grandpa";
+/// Expected grandpa runtime API name.
+pub const DEFAULT_GRANDPA_API_NAME: &str = "GrandpaApi";
 
 /// The Aura ID used by the Aura consensus
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 pub enum AuraConsensusId {
 	/// Ed25519
 	Ed25519,
@@ -39,11 +47,25 @@ pub enum AuraConsensusId {
 	Sr25519,
 }
 
-/// The choice of consensus for the parachain omni-node.
-#[derive(PartialEq)]
+impl AuraConsensusId {
+	/// Get the Aura ID from the path of the authority id type, e.g.
+	/// `sp_consensus_aura::sr25519::app_sr25519::Public`.
+	fn from_type_path(path: &Path<PortableForm>) -> Option<AuraConsensusId> {
+		path.segments.iter().find_map(|segment| match segment.as_str() {
+			"ed25519" | "app_ed25519" => Some(AuraConsensusId::Ed25519),
+			"sr25519" | "app_sr25519" => Some(AuraConsensusId::Sr25519),
+			_ => None,
+		})
+	}
+}
+
+/// The choice of consensus for the omni-node.
+#[derive(PartialEq, Debug)]
 pub enum Consensus {
-	/// Aura consensus.
+	/// Aura consensus, for parachains.
 	Aura(AuraConsensusId),
+	/// Aura block production with GRANDPA finality, for standalone chains.
+	AuraGrandpa(AuraConsensusId),
 }
 
 /// The choice of block number for the parachain omni-node.
@@ -97,7 +119,11 @@ pub trait RuntimeResolver {
 	fn runtime(&self, chain_spec: &dyn ChainSpec) -> sc_cli::Result<Runtime>;
 }
 
-/// Default implementation for `RuntimeResolver` that just returns
+/// Default implementation for `RuntimeResolver` that inspects the runtime metadata to find the
+/// block number type, the Aura authority id type and whether the runtime is a parachain or a
+/// standalone chain finalized by GRANDPA.
+///
+/// When the metadata can't be inspected, it returns
 /// `Runtime::Omni(BlockNumber::U32, Consensus::Aura(AuraConsensusId::Sr25519))`.
 pub struct DefaultRuntimeResolver;
 
@@ -121,7 +147,21 @@ impl RuntimeResolver for DefaultRuntimeResolver {
 			},
 		};
 
-		if !metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) {
+		let consensus = metadata_inspector.consensus().map_err(|err| {
+			format!(
+				"{err}. The omni-node only supports runtimes using Aura consensus, with sr25519 or \
+				ed25519 authority ids, optionally with GRANDPA finality for standalone chains. \
+				Please check Omni Node docs for runtime conventions: \
+				https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/omni_node/index.html#runtime-conventions"
+			)
+		})?;
+
+		if let Consensus::AuraGrandpa(_) = consensus {
+			log::info!(
+				"🏛️  The runtime has a GRANDPA pallet and no parachain system pallet, running it as \
+				a standalone chain."
+			);
+		} else if !metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) {
 			log::warn!(
 				r#"⚠️  The parachain system pallet (https://docs.rs/crate/cumulus-pallet-parachain-system/latest) is
 			   missing from the runtime’s metadata. Please check Omni Node docs for runtime conventions:
@@ -129,7 +169,7 @@ impl RuntimeResolver for DefaultRuntimeResolver {
 			);
 		}
 
-		Ok(Runtime::Omni(block_number, Consensus::Aura(AuraConsensusId::Sr25519)))
+		Ok(Runtime::Omni(block_number, consensus))
 	}
 }
 
@@ -157,6 +197,81 @@ impl MetadataInspector {
 			.and_then(|portable_type| BlockNumber::from_type_def(&portable_type.type_def))
 	}
 
+	/// Whether the runtime is a standalone chain finalized by GRANDPA, rather than a parachain.
+	fn is_grandpa_solochain(&self) -> bool {
+		!self.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) &&
+			(self.pallet_exists(DEFAULT_GRANDPA_PALLET_NAME) ||
+				self.0.runtime_api_trait_by_name(DEFAULT_GRANDPA_API_NAME).is_some())
+	}
+
+	/// The consensus used by the runtime, detected from the type of its Aura authority ids and
+	/// from the presence of GRANDPA in a runtime without the parachain system pallet.
+	fn consensus(&self) -> Result<Consensus, String> {
+		let Some(authority_ty_id) = self.aura_authority_type_id() else {
+			if self.pallet_exists("Babe") || self.0.runtime_api_trait_by_name("BabeApi").is_some() {
+				return Err("The runtime uses BABE consensus".into())
+			}
+			return Err(format!(
+				"There is neither an `{DEFAULT_AURA_API_NAME}` runtime API nor an \
+				`{DEFAULT_AURA_PALLET_NAME}` pallet in the runtime"
+			))
+		};
+
+		let authority_ty = self
+			.0
+			.types()
+			.resolve(authority_ty_id)
+			.ok_or_else(|| format!("Unknown Aura authority id type {authority_ty_id}"))?;
+		let aura_id = AuraConsensusId::from_type_path(&authority_ty.path).ok_or_else(|| {
+			format!(
+				"Unsupported Aura authority id type `{}`",
+				authority_ty.path.segments.join("::")
+			)
+		})?;
+
+		if self.is_grandpa_solochain() {
+			Ok(Consensus::AuraGrandpa(aura_id))
+		} else {
+			Ok(Consensus::Aura(aura_id))
+		}
+	}
+
+	/// The type id of the Aura authority ids, found in the return type of
+	/// `AuraApi::authorities` or in the `Authorities` storage of the Aura pallet.
+	fn aura_authority_type_id(&self) -> Option<u32> {
+		let from_runtime_api = self
+			.0
+			.runtime_api_trait_by_name(DEFAULT_AURA_API_NAME)
+			.and_then(|api| api.method_by_name("authorities"))
+			.map(|method| method.output_ty());
+
+		let from_storage = || {
+			self.0
+				.pallet_by_name(DEFAULT_AURA_PALLET_NAME)
+				.and_then(|pallet| pallet.storage())
+				.and_then(|storage| storage.entry_by_name("Authorities"))
+				.and_then(|entry| match entry.entry_type() {
+					StorageEntryType::Plain(ty_id) => Some(*ty_id),
+					_ => None,
+				})
+		};
+
+		from_runtime_api
+			.or_else(from_storage)
+			.and_then(|ty_id| self.sequence_item_type_id(ty_id))
+	}
+
+	/// The type id of the items of a sequence, looking through single field wrappers such as
+	/// `BoundedVec`.
+	fn sequence_item_type_id(&self, ty_id: u32) -> Option<u32> {
+		match &self.0.types().resolve(ty_id)?.type_def {
+			TypeDef::Sequence(sequence) => Some(sequence.type_param.id),
+			TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+				self.sequence_item_type_id(composite.fields[0].ty.id),
+			_ => None,
+		}
+	}
+
 	fn fetch_metadata(chain_spec: &dyn ChainSpec) -> Result<Metadata, sc_cli::Error> {
 		let mut storage = chain_spec.build_storage()?;
 		let code_bytes = storage
@@ -178,26 +293,30 @@ impl MetadataInspector {
 #[cfg(test)]
 mod tests {
 	use crate::runtime::{
-		BlockNumber, MetadataInspector, DEFAULT_FRAME_SYSTEM_PALLET_NAME,
-		DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME,
+		AuraConsensusId, BlockNumber, Consensus, MetadataInspector,
+		DEFAULT_FRAME_SYSTEM_PALLET_NAME, DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME,
 	};
 	use codec::Decode;
 	use cumulus_client_service::ParachainHostFunctions;
 	use sc_executor::WasmExecutor;
 	use sc_runtime_utilities::fetch_latest_metadata_from_code_blob;
 
-	fn cumulus_test_runtime_metadata() -> subxt_metadata::Metadata {
+	fn runtime_metadata(code: &[u8]) -> subxt_metadata::Metadata {
 		let opaque_metadata = fetch_latest_metadata_from_code_blob(
 			&WasmExecutor::<ParachainHostFunctions>::builder()
 				.with_allow_missing_host_functions(true)
 				.build(),
-			sp_runtime::Cow::Borrowed(cumulus_test_runtime::WASM_BINARY.unwrap()),
+			sp_runtime::Cow::Borrowed(code),
 		)
 		.unwrap();
 
 		subxt_metadata::Metadata::decode(&mut (*opaque_metadata).as_slice()).unwrap()
 	}
 
+	fn cumulus_test_runtime_metadata() -> subxt_metadata::Metadata {
+		runtime_metadata(cumulus_test_runtime::WASM_BINARY.unwrap())
+	}
+
 	#[test]
 	fn test_pallet_exists() {
 		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
@@ -210,4 +329,47 @@ mod tests {
 		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
 		assert_eq!(metadata_inspector.block_number().unwrap(), BlockNumber::U32);
 	}
+
+	#[test]
+	fn test_runtime_consensus() {
+		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
+		assert_eq!(metadata_inspector.consensus(), Ok(Consensus::Aura(AuraConsensusId::Sr25519)));
+	}
+
+	#[test]
+	fn test_solochain_runtime_consensus() {
+		let metadata_inspector =
+			MetadataInspector(runtime_metadata(solochain_template_runtime::WASM_BINARY.unwrap()));
+		assert!(metadata_inspector.is_grandpa_solochain());
+		assert_eq!(
+			metadata_inspector.consensus(),
+			Ok(Consensus::AuraGrandpa(AuraConsensusId::Sr25519))
+		);
+	}
+
+	#[test]
+	fn test_aura_consensus_id_from_type_path() {
+		let path = |segments: &[&str]| scale_info::Path::<scale_info::form::PortableForm> {
+			segments: segments.iter().map(|s| s.to_string()).collect(),
+		};
+		assert_eq!(
+			AuraConsensusId::from_type_path(&path(&[
+				"sp_consensus_aura",
+				"ed25519",
+				"app_ed25519",
+				"Public"
+			])),
+			Some(AuraConsensusId::Ed25519)
+		);
+		assert_eq!(
+			AuraConsensusId::from_type_path(&path(&[
+				"sp_consensus_aura",
+				"sr25519",
+				"app_sr25519",
+				"Public"
+			])),
+			Some(AuraConsensusId::Sr25519)
+		);
+		assert_eq!(AuraConsensusId::from_type_path(&path(&["sp_core", "ecdsa", "Public"])), None);
+	}
 }
@@ -0,0 +1,459 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Node spec for standalone chains, using Aura for block production and GRANDPA for finality.
+
+use crate::{
+	common::{
+		aura::{AuraIdT, AuraRuntimeApi},
+		command::NodeCommandRunner,
+		rpc::{BuildParachainRpcExtensions, BuildRpcExtensions},
+		spec::{warn_if_slow_hardware, BaseNodeSpec, BuildImportQueue, ClientBlockImport},
+		types::{
+			AccountId, Balance, Hash, Nonce, ParachainBackend, ParachainBlockImport,
+			ParachainClient, ParachainHostFunctions,
+		},
+		ConstructNodeRuntimeApi, NodeBlock,
+	},
+	nodes::DynSolochainNodeSpec,
+};
+use sc_client_api::BlockBackend;
+use sc_consensus::{BlockImport, BoxJustificationImport, DefaultImportQueue, LongestChain};
+use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
+use sc_consensus_grandpa::{BlockNumberOps, SharedVoterState};
+use sc_network::NetworkBackend;
+use sc_service::{Configuration, TaskManager, WarpSyncConfig};
+use sc_sysinfo::HwBench;
+use sc_telemetry::{TelemetryHandle, TelemetryWorker};
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_consensus_grandpa::GrandpaApi;
+use sp_runtime::{
+	app_crypto::{AppCrypto, Pair},
+	traits::NumberFor,
+};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+/// The minimum period of blocks on which justifications will be imported and generated.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
+/// Build an Aura import queue, which checks the slot of the imported blocks against the system
+/// time.
+fn build_aura_import_queue<Block, RuntimeApi, AuraId, BI>(
+	client: Arc<ParachainClient<Block, RuntimeApi>>,
+	block_import: BI,
+	justification_import: Option<BoxJustificationImport<Block>>,
+	config: &Configuration,
+	telemetry: Option<TelemetryHandle>,
+	task_manager: &TaskManager,
+) -> sc_service::error::Result<DefaultImportQueue<Block>>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>,
+	AuraId: AuraIdT + Sync,
+	BI: BlockImport<Block, Error = sp_consensus::Error> + Send + Sync + 'static,
+{
+	let client_for_cidp = client.clone();
+	let import_queue = sc_consensus_aura::import_queue::<<AuraId as AppCrypto>::Pair, _, _, _, _, _>(
+		ImportQueueParams {
+			block_import,
+			justification_import,
+			client,
+			create_inherent_data_providers: move |parent_hash, _| {
+				let client = client_for_cidp.clone();
+				async move {
+					let slot_duration = sc_consensus_aura::standalone::slot_duration_at::<
+						<AuraId::BoundedPair as Pair>::Public,
+						_,
+						_,
+					>(&*client, parent_hash)?;
+					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+					let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+							*timestamp,
+							slot_duration,
+						);
+
+					Ok((slot, timestamp))
+				}
+			},
+			spawner: &task_manager.spawn_essential_handle(),
+			registry: config.prometheus_registry(),
+			check_for_equivocation: Default::default(),
+			telemetry,
+			compatibility_mode: Default::default(),
+		},
+	)?;
+
+	Ok(import_queue)
+}
+
+/// Start a standalone chain node, authoring blocks with Aura and finalizing them with GRANDPA.
+pub(crate) struct AuraGrandpaNode<Block, RuntimeApi, AuraId>(
+	pub PhantomData<(Block, RuntimeApi, AuraId)>,
+);
+
+impl<Block, RuntimeApi, AuraId> Default for AuraGrandpaNode<Block, RuntimeApi, AuraId> {
+	fn default() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<Block, RuntimeApi, AuraId>
+	BuildImportQueue<Block, RuntimeApi, Arc<ParachainClient<Block, RuntimeApi>>>
+	for AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>,
+	AuraId: AuraIdT + Sync,
+{
+	fn build_import_queue(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: ParachainBlockImport<Block, Arc<ParachainClient<Block, RuntimeApi>>>,
+		config: &Configuration,
+		telemetry_handle: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> sc_service::error::Result<DefaultImportQueue<Block>> {
+		// This queue is only used by the chain operation subcommands, which don't need to track
+		// the GRANDPA authority set.
+		build_aura_import_queue::<Block, RuntimeApi, AuraId, _>(
+			client,
+			block_import,
+			None,
+			config,
+			telemetry_handle,
+			task_manager,
+		)
+	}
+}
+
+impl<Block, RuntimeApi, AuraId> BaseNodeSpec for AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>,
+	AuraId: AuraIdT + Sync,
+{
+	type Block = Block;
+	type RuntimeApi = RuntimeApi;
+	type BuildImportQueue = Self;
+	type InitBlockImport = ClientBlockImport;
+}
+
+impl<Block, RuntimeApi, AuraId> AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	NumberFor<Block>: BlockNumberOps,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	fn start_solochain_node<Net>(
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> sc_service::error::Result<TaskManager>
+	where
+		Net: NetworkBackend<Block, Hash>,
+	{
+		let telemetry = config
+			.telemetry_endpoints
+			.clone()
+			.filter(|x| !x.is_empty())
+			.map(|endpoints| -> Result<_, sc_telemetry::Error> {
+				let worker = TelemetryWorker::new(16)?;
+				let telemetry = worker.handle().new_telemetry(endpoints);
+				Ok((worker, telemetry))
+			})
+			.transpose()?;
+
+		let executor = sc_service::new_wasm_executor::<ParachainHostFunctions>(&config.executor);
+		let (client, backend, keystore_container, mut task_manager) =
+			sc_service::new_full_parts::<Block, RuntimeApi, _>(
+				&config,
+				telemetry.as_ref().map(|(_, telemetry)| telemetry.handle()),
+				executor,
+			)?;
+		let client = Arc::new(client);
+
+		let mut telemetry = telemetry.map(|(worker, telemetry)| {
+			task_manager.spawn_handle().spawn("telemetry", None, worker.run());
+			telemetry
+		});
+
+		let select_chain = LongestChain::new(backend.clone());
+
+		let transaction_pool = Arc::from(
+			sc_transaction_pool::Builder::new(
+				task_manager.spawn_essential_handle(),
+				client.clone(),
+				config.role.is_authority().into(),
+			)
+			.with_options(config.transaction_pool.clone())
+			.with_prometheus(config.prometheus_registry())
+			.build(),
+		);
+
+		let (block_import, grandpa_link) = sc_consensus_grandpa::block_import(
+			client.clone(),
+			GRANDPA_JUSTIFICATION_PERIOD,
+			&client,
+			select_chain.clone(),
+			telemetry.as_ref().map(|telemetry| telemetry.handle()),
+		)?;
+
+		let import_queue = build_aura_import_queue::<Block, RuntimeApi, AuraId, _>(
+			client.clone(),
+			block_import.clone(),
+			Some(Box::new(block_import.clone())),
+			&config,
+			telemetry.as_ref().map(|telemetry| telemetry.handle()),
+			&task_manager,
+		)?;
+
+		let mut net_config = sc_network::config::FullNetworkConfiguration::<_, _, Net>::new(
+			&config.network,
+			config.prometheus_registry().cloned(),
+		);
+		let metrics = Net::register_notification_metrics(config.prometheus_registry());
+
+		let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
+			&client
+				.block_hash(0u32.into())
+				.ok()
+				.flatten()
+				.expect("Genesis block exists; qed"),
+			&config.chain_spec,
+		);
+		let (grandpa_protocol_config, grandpa_notification_service) =
+			sc_consensus_grandpa::grandpa_peers_set_config::<_, Net>(
+				grandpa_protocol_name.clone(),
+				metrics.clone(),
+				net_config.peer_store_handle(),
+			);
+		net_config.add_notification_protocol(grandpa_protocol_config);
+
+		let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+			backend.clone(),
+			grandpa_link.shared_authority_set().clone(),
+			Vec::default(),
+		));
+
+		let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+			sc_service::build_network(sc_service::BuildNetworkParams {
+				config: &config,
+				net_config,
+				client: client.clone(),
+				transaction_pool: transaction_pool.clone(),
+				spawn_handle: task_manager.spawn_handle(),
+				import_queue,
+				block_announce_validator_builder: None,
+				warp_sync_config: Some(WarpSyncConfig::WithProvider(warp_sync)),
+				block_relay: None,
+				metrics,
+			})?;
+
+		let role = config.role;
+		let force_authoring = config.force_authoring;
+		let name = config.network.node_name.clone();
+		let enable_grandpa = !config.disable_grandpa;
+		let prometheus_registry = config.prometheus_registry().cloned();
+
+		let rpc_builder = {
+			let client = client.clone();
+			let transaction_pool = transaction_pool.clone();
+			let backend_for_rpc = backend.clone();
+
+			Box::new(move |_| {
+				BuildParachainRpcExtensions::<Block, RuntimeApi>::build_rpc_extensions(
+					client.clone(),
+					backend_for_rpc.clone(),
+					transaction_pool.clone(),
+				)
+			})
+		};
+
+		sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+			rpc_builder,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			task_manager: &mut task_manager,
+			config,
+			keystore: keystore_container.keystore(),
+			backend,
+			network: network.clone(),
+			sync_service: sync_service.clone(),
+			system_rpc_tx,
+			tx_handler_controller,
+			telemetry: telemetry.as_mut(),
+		})?;
+
+		if let Some(hwbench) = hwbench {
+			sc_sysinfo::print_hwbench(&hwbench);
+			if role.is_authority() {
+				warn_if_slow_hardware(&hwbench);
+			}
+
+			if let Some(ref mut telemetry) = telemetry {
+				let telemetry_handle = telemetry.handle();
+				task_manager.spawn_handle().spawn(
+					"telemetry_hwbench",
+					None,
+					sc_sysinfo::initialize_hwbench_telemetry(telemetry_handle, hwbench),
+				);
+			}
+		}
+
+		if role.is_authority() {
+			let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+				task_manager.spawn_handle(),
+				client.clone(),
+				transaction_pool.clone(),
+				prometheus_registry.as_ref(),
+				telemetry.as_ref().map(|telemetry| telemetry.handle()),
+			);
+
+			let slot_duration =
+				sc_consensus_aura::slot_duration::<<AuraId::BoundedPair as Pair>::Public, _, _>(
+					&*client,
+				)?;
+
+			let aura = sc_consensus_aura::start_aura::<
+				<AuraId as AppCrypto>::Pair,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+				_,
+			>(StartAuraParams {
+				slot_duration,
+				client,
+				select_chain,
+				block_import,
+				proposer_factory,
+				create_inherent_data_providers: move |_, ()| async move {
+					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+					let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+						*timestamp,
+						slot_duration,
+					);
+
+					Ok((slot, timestamp))
+				},
+				force_authoring,
+				backoff_authoring_blocks: None::<()>,
+				keystore: keystore_container.keystore(),
+				sync_oracle: sync_service.clone(),
+				justification_sync_link: sync_service.clone(),
+				block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
+				max_block_proposal_slot_portion: None,
+				telemetry: telemetry.as_ref().map(|telemetry| telemetry.handle()),
+				compatibility_mode: Default::default(),
+			})?;
+
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"aura",
+				Some("block-authoring"),
+				aura,
+			);
+		}
+
+		if enable_grandpa {
+			// Non-authorities don't vote, so they don't need a keystore.
+			let keystore = role.is_authority().then(|| keystore_container.keystore());
+
+			let grandpa_params = sc_consensus_grandpa::GrandpaParams {
+				config: sc_consensus_grandpa::Config {
+					gossip_duration: Duration::from_millis(333),
+					justification_generation_period: GRANDPA_JUSTIFICATION_PERIOD,
+					name: Some(name),
+					observer_enabled: false,
+					keystore,
+					local_role: role,
+					telemetry: telemetry.as_ref().map(|telemetry| telemetry.handle()),
+					protocol_name: grandpa_protocol_name,
+				},
+				link: grandpa_link,
+				network,
+				sync: Arc::new(sync_service),
+				notification_service: grandpa_notification_service,
+				voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
+				prometheus_registry,
+				shared_voter_state: SharedVoterState::empty(),
+				telemetry: telemetry.as_ref().map(|telemetry| telemetry.handle()),
+				offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
+			};
+
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"grandpa-voter",
+				None,
+				sc_consensus_grandpa::run_grandpa_voter(grandpa_params)?,
+			);
+		}
+
+		Ok(task_manager)
+	}
+}
+
+impl<Block, RuntimeApi, AuraId> DynSolochainNodeSpec for AuraGrandpaNode<Block, RuntimeApi, AuraId>
+where
+	Block: NodeBlock,
+	NumberFor<Block>: BlockNumberOps,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	fn start_node(
+		self: Box<Self>,
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> sc_service::error::Result<TaskManager> {
+		match config.network.network_backend {
+			sc_network::config::NetworkBackendType::Libp2p =>
+				Self::start_solochain_node::<sc_network::NetworkWorker<_, _>>(config, hwbench),
+			sc_network::config::NetworkBackendType::Litep2p =>
+				Self::start_solochain_node::<sc_network::Litep2pNetworkBackend>(config, hwbench),
+		}
+	}
+
+	fn into_command_runner(self: Box<Self>) -> Box<dyn NodeCommandRunner> {
+		self
+	}
+}
+
+pub fn new_aura_grandpa_node_spec<Block, RuntimeApi, AuraId>() -> Box<dyn DynSolochainNodeSpec>
+where
+	Block: NodeBlock,
+	NumberFor<Block>: BlockNumberOps,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ GrandpaApi<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	Box::new(AuraGrandpaNode::<Block, RuntimeApi, AuraId>::default())
+}
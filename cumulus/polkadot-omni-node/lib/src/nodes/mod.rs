@@ -15,12 +15,17 @@
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
 pub mod aura;
+pub mod aura_grandpa;
 mod manual_seal;
 
-use crate::common::spec::{DynNodeSpec, NodeSpec as NodeSpecT};
+use crate::common::{
+	command::NodeCommandRunner,
+	spec::{DynNodeSpec, NodeSpec as NodeSpecT},
+};
 use cumulus_primitives_core::ParaId;
 use manual_seal::ManualSealNode;
 use sc_service::{Configuration, TaskManager};
+use sc_sysinfo::HwBench;
 
 /// The current node version for cumulus official binaries, which takes the basic
 /// SemVer form `<major>.<minor>.<patch>`. It should correspond to the latest
@@ -38,11 +43,14 @@ pub trait DynNodeSpecExt: DynNodeSpec {
 		para_id: ParaId,
 		block_time: u64,
 	) -> sc_service::error::Result<TaskManager>;
+
+	/// Convert the node spec into a runner for the chain operation subcommands.
+	fn into_command_runner(self: Box<Self>) -> Box<dyn NodeCommandRunner>;
 }
 
 impl<T> DynNodeSpecExt for T
 where
-	T: NodeSpecT + DynNodeSpec,
+	T: NodeSpecT + DynNodeSpec + 'static,
 {
 	#[sc_tracing::logging::prefix_logs_with("Parachain")]
 	fn start_manual_seal_node(
@@ -59,4 +67,37 @@ where
 				node.start_node::<sc_network::Litep2pNetworkBackend>(config, para_id, block_time),
 		}
 	}
+	fn into_command_runner(self: Box<Self>) -> Box<dyn NodeCommandRunner> {
+		self
+	}
+}
+
+/// Trait for the node specs of standalone chains, which run without a relay chain.
+pub trait DynSolochainNodeSpec: NodeCommandRunner {
+	fn start_node(
+		self: Box<Self>,
+		config: Configuration,
+		hwbench: Option<HwBench>,
+	) -> sc_service::error::Result<TaskManager>;
+
+	/// Convert the node spec into a runner for the chain operation subcommands.
+	fn into_command_runner(self: Box<Self>) -> Box<dyn NodeCommandRunner>;
+}
+
+/// The kind of node matching the runtime of the chain, together with its node spec.
+pub enum NodeKind {
+	/// A parachain node spec.
+	Parachain(Box<dyn DynNodeSpecExt>),
+	/// A standalone chain node spec.
+	Solochain(Box<dyn DynSolochainNodeSpec>),
+}
+
+impl NodeKind {
+	/// Convert the node spec into a runner for the chain operation subcommands.
+	pub fn into_command_runner(self) -> Box<dyn NodeCommandRunner> {
+		match self {
+			NodeKind::Parachain(node_spec) => node_spec.into_command_runner(),
+			NodeKind::Solochain(node_spec) => node_spec.into_command_runner(),
+		}
+	}
 }
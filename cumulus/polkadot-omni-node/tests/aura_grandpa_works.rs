@@ -0,0 +1,76 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use sc_chain_spec::{ChainType, GenericChainSpec, NoExtension};
+use std::{
+	path::Path,
+	process::{Command, Stdio},
+	time::Duration,
+};
+use substrate_cli_test_utils as common;
+
+/// Write the development chain spec of the solochain template to the given `path`.
+fn write_solochain_chain_spec(path: &Path) {
+	let chain_spec = GenericChainSpec::<NoExtension>::builder(
+		solochain_template_runtime::WASM_BINARY.expect("Development wasm not available"),
+		None,
+	)
+	.with_name("Development")
+	.with_id("dev")
+	.with_chain_type(ChainType::Development)
+	.with_genesis_config_preset_name(sp_genesis_builder::DEV_RUNTIME_PRESET)
+	.build();
+
+	std::fs::write(path, chain_spec.as_json(false).unwrap()).unwrap();
+}
+
+/// The omni-node runs a standalone Aura + GRANDPA runtime: it authors blocks with Aura and
+/// finalizes them with GRANDPA.
+#[tokio::test]
+async fn aura_grandpa_node_authors_and_finalizes_blocks() {
+	let tmp_dir = tempfile::tempdir().expect("could not create a temp dir");
+	let chain_spec_path = tmp_dir.path().join("solochain_chain_spec.json");
+	write_solochain_chain_spec(&chain_spec_path);
+
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut child = common::KillChildOnDrop(
+			Command::new(cargo_bin("polkadot-omni-node"))
+				.stdout(Stdio::piped())
+				.stderr(Stdio::piped())
+				.arg("--chain")
+				.arg(&chain_spec_path)
+				.args(["--alice", "--tmp", "--no-hardware-benchmarks", "--port", "0"])
+				.args(["--rpc-port", "0"])
+				.spawn()
+				.unwrap(),
+		);
+
+		let mut stderr = child.stderr.take().unwrap();
+		let node_info = common::extract_info_from_output(&mut stderr).0;
+
+		// Blocks are only finalized if they are authored and GRANDPA is voting on them.
+		common::wait_n_finalized_blocks(3, &node_info.ws_url).await;
+
+		child.assert_still_running();
+
+		// Stop the process
+		child.stop();
+	})
+	.await;
+}
@@ -0,0 +1,772 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-running of XCM journeys spanning several chains.
+//!
+//! The `DryRunApi` of a chain only simulates the local execution and returns the messages it
+//! forwards to other chains. [`MultiHopDryRun`] follows those messages hop by hop through the
+//! chains added to it, dry-running each of them with the same API, and reports the effects and
+//! fees of every hop.
+//!
+//! Chains are either the native chains of an emulated network, see [`NativeChain`], or runtime
+//! wasm blobs executed on top of a given state, see [`WasmChain`].
+//!
+//! The hops of a journey see the changes made by the previous hops on the same chain. These
+//! changes are discarded once the journey is complete, so dry-running leaves the state of the
+//! chains untouched.
+
+use crate::{
+	type_name, Chain, Debug, Decode, Encode, Location, Parachain, ParachainJunction, RelayChain,
+	SystemConfig, TestExt, VecDeque,
+};
+use core::{marker::PhantomData, mem};
+use frame_support::{dispatch::DispatchResultWithPostInfo, weights::Weight};
+use sc_executor::{sp_wasm_interface::HostFunctions, WasmExecutor};
+use sp_core::{
+	storage::Storage,
+	traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+};
+use sp_state_machine::BasicExternalities;
+use xcm::{latest::VERSION as XCM_VERSION, prelude::*};
+use xcm_runtime_apis::{
+	dry_run::{
+		runtime_decl_for_dry_run_api::DryRunApiV1, CallDryRunEffects, Error as XcmDryRunApiError,
+		XcmDryRunEffects,
+	},
+	fees::{runtime_decl_for_xcm_payment_api::XcmPaymentApiV1, Error as XcmPaymentApiError},
+};
+
+/// The default maximum number of hops of a dry-run.
+pub const DEFAULT_MAX_HOPS: usize = 16;
+
+type BlockOf<C> = <<C as Chain>::Runtime as SystemConfig>::Block;
+
+/// Errors of a multi-hop dry-run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultiHopDryRunError {
+	/// The chain dispatching the call wasn't added to the dry-run.
+	UnknownChain(&'static str),
+	/// The `DryRunApi` of a chain failed.
+	DryRun { chain: &'static str, error: XcmDryRunApiError },
+	/// The `XcmPaymentApi` of a chain failed to estimate fees.
+	Fees { chain: &'static str, error: XcmPaymentApiError },
+	/// The runtime of a chain couldn't be called, or its arguments or result couldn't be decoded.
+	Runtime { chain: &'static str, error: String },
+	/// More than the maximum number of hops were needed, messages are probably bouncing between
+	/// chains.
+	TooManyHops,
+}
+
+/// The result of a hop.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HopResult {
+	/// The dispatch result of the call which started the journey.
+	Call(DispatchResultWithPostInfo),
+	/// The outcome of executing a forwarded message.
+	Xcm {
+		/// The chain which forwarded the message, as seen by the executing chain.
+		origin: VersionedLocation,
+		/// The outcome of the execution.
+		outcome: Outcome,
+	},
+}
+
+/// A message forwarded by a hop.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForwardedXcm {
+	/// The destination of the message, as seen by the forwarding chain.
+	pub destination: VersionedLocation,
+	/// The forwarded message.
+	pub message: VersionedXcm<()>,
+	/// The fees charged by the forwarding chain to deliver the message.
+	pub delivery_fees: VersionedAssets,
+	/// The index of the hop executing the message in [`MultiHopDryRunEffects::hops`], or `None`
+	/// if its destination wasn't added to the dry-run.
+	pub hop: Option<usize>,
+}
+
+/// Effects of dry-running a single hop of a journey.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HopEffects {
+	/// The name of the chain the hop was executed on.
+	pub chain: &'static str,
+	/// The result of the hop.
+	pub result: HopResult,
+	/// The XCM executed by the hop, if any.
+	pub xcm: Option<VersionedXcm<()>>,
+	/// The fees to execute `xcm`, in each of the payment assets accepted by the chain.
+	pub execution_fees: Vec<(VersionedAssetId, u128)>,
+	/// The events emitted by the hop, formatted with `Debug` since every chain has its own event
+	/// type.
+	pub events: Vec<String>,
+	/// The messages forwarded by the hop.
+	pub forwarded_xcms: Vec<ForwardedXcm>,
+}
+
+impl HopEffects {
+	/// Whether the call or message of this hop executed successfully.
+	pub fn is_success(&self) -> bool {
+		match &self.result {
+			HopResult::Call(result) => result.is_ok(),
+			HopResult::Xcm { outcome, .. } => outcome.clone().ensure_complete().is_ok(),
+		}
+	}
+}
+
+/// Effects of dry-running a journey, hop by hop.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiHopDryRunEffects {
+	/// The hops of the journey in execution order, the first one being the call which started it.
+	pub hops: Vec<HopEffects>,
+}
+
+impl MultiHopDryRunEffects {
+	/// Whether every hop of the journey executed successfully.
+	pub fn is_success(&self) -> bool {
+		self.hops.iter().all(HopEffects::is_success)
+	}
+
+	/// The hops executed on the chain named `chain`.
+	pub fn hops_on_chain(&self, chain: &'static str) -> impl Iterator<Item = &HopEffects> {
+		self.hops.iter().filter(move |hop| hop.chain == chain)
+	}
+
+	/// The hops executed on the chain `C`.
+	pub fn hops_on<C: Chain>(&self) -> impl Iterator<Item = &HopEffects> {
+		self.hops_on_chain(type_name::<C>())
+	}
+
+	/// The messages forwarded to chains which weren't added to the dry-run, with the name of the
+	/// chain forwarding them.
+	pub fn unrouted_xcms(&self) -> impl Iterator<Item = (&'static str, &ForwardedXcm)> {
+		self.hops.iter().flat_map(|hop| {
+			hop.forwarded_xcms
+				.iter()
+				.filter(|forwarded| forwarded.hop.is_none())
+				.map(|forwarded| (hop.chain, forwarded))
+		})
+	}
+}
+
+/// A chain the hops of a journey can be dry-run on.
+///
+/// The changes made by a hop are kept until [`DryRunChain::rollback`] is called, so that the
+/// following hops on the same chain execute on top of them.
+pub trait DryRunChain {
+	/// The name of the chain, reported in [`HopEffects::chain`].
+	fn name(&self) -> &'static str;
+
+	/// Dry-run the SCALE-encoded runtime `call` dispatched by the SCALE-encoded `origin`, an
+	/// `OriginCaller` of the chain.
+	fn dry_run_call(
+		&mut self,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<HopEffects, MultiHopDryRunError>;
+
+	/// Dry-run the message `xcm` sent by `origin`.
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<HopEffects, MultiHopDryRunError>;
+
+	/// Discard the changes made by the hops dry-run since the last rollback.
+	fn rollback(&mut self);
+}
+
+/// A chain of an emulated network, dry-run with its native runtime on its current state.
+pub struct NativeChain<C> {
+	/// Whether a storage transaction holding the changes of the current journey is open.
+	in_journey: bool,
+	_phantom: PhantomData<C>,
+}
+
+impl<C> Default for NativeChain<C> {
+	fn default() -> Self {
+		Self { in_journey: false, _phantom: PhantomData }
+	}
+}
+
+impl<C: Chain> NativeChain<C> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Run `f` on the chain, in the storage transaction of the current journey.
+	fn in_journey<R>(&mut self, f: impl FnOnce() -> R) -> R {
+		let start = !mem::replace(&mut self.in_journey, true);
+		C::ext_wrapper(|| {
+			if start {
+				sp_io::storage::start_transaction();
+			}
+			f()
+		})
+	}
+}
+
+impl<C> DryRunChain for NativeChain<C>
+where
+	C: Chain,
+	C::Runtime: DryRunApiV1<BlockOf<C>, C::RuntimeCall, C::RuntimeEvent, C::OriginCaller>
+		+ XcmPaymentApiV1<BlockOf<C>>,
+	C::RuntimeCall: Encode + Decode,
+	C::RuntimeEvent: Decode + Debug,
+	C::OriginCaller: Encode + Decode,
+{
+	fn name(&self) -> &'static str {
+		type_name::<C>()
+	}
+
+	fn dry_run_call(
+		&mut self,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<HopEffects, MultiHopDryRunError> {
+		self.in_journey(|| dry_run_call(&mut NativeApis::<C>(PhantomData), origin, call))
+	}
+
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<HopEffects, MultiHopDryRunError> {
+		self.in_journey(|| dry_run_xcm(&mut NativeApis::<C>(PhantomData), origin, xcm))
+	}
+
+	fn rollback(&mut self) {
+		if mem::take(&mut self.in_journey) {
+			C::ext_wrapper(sp_io::storage::rollback_transaction);
+		}
+	}
+}
+
+/// A runtime wasm blob, dry-run on top of a given state.
+///
+/// `Event` is the `RuntimeEvent` of the runtime, used to decode the events emitted by the hops.
+/// `EHF` are the host functions the runtime needs on top of `sp_io::SubstrateHostFunctions`, by
+/// default the ones of parachain runtimes.
+pub struct WasmChain<
+	Event,
+	EHF = cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+> where
+	EHF: HostFunctions,
+{
+	name: &'static str,
+	code: Vec<u8>,
+	code_hash: Vec<u8>,
+	state: Storage,
+	executor: WasmExecutor<(sp_io::SubstrateHostFunctions, EHF)>,
+	/// The state of the chain during the current journey, `None` outside of journeys.
+	journey: Option<BasicExternalities>,
+	_phantom: PhantomData<fn() -> Event>,
+}
+
+impl<Event, EHF: HostFunctions> WasmChain<Event, EHF> {
+	/// Create a chain named `name` executing the runtime `code` on top of `state`.
+	pub fn new(name: &'static str, code: Vec<u8>, state: Storage) -> Self {
+		Self {
+			name,
+			code_hash: sp_crypto_hashing::blake2_256(&code).to_vec(),
+			code,
+			state,
+			executor: WasmExecutor::<(sp_io::SubstrateHostFunctions, EHF)>::builder()
+				.with_allow_missing_host_functions(true)
+				.build(),
+			journey: None,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Call the runtime API function `method` with the SCALE-encoded arguments `data`.
+	fn call<R: Decode>(&mut self, method: &str, data: &[u8]) -> Result<R, MultiHopDryRunError> {
+		let chain = self.name;
+		let runtime_error = |error: String| MultiHopDryRunError::Runtime { chain, error };
+		let Self { code, code_hash, state, executor, journey, .. } = self;
+		let ext = journey.get_or_insert_with(|| BasicExternalities::new(state.clone()));
+		let code_fetcher = WrappedRuntimeCode(code.as_slice().into());
+		let runtime_code =
+			RuntimeCode { code_fetcher: &code_fetcher, heap_pages: None, hash: code_hash.clone() };
+		let result = executor
+			.call(ext, &runtime_code, method, data, CallContext::Offchain)
+			.0
+			.map_err(|e| runtime_error(format!("wasm call error {e}")))?;
+		R::decode(&mut &result[..]).map_err(|e| runtime_error(format!("scale codec error: {e}")))
+	}
+}
+
+impl<Event, EHF> DryRunChain for WasmChain<Event, EHF>
+where
+	Event: Decode + Debug,
+	EHF: HostFunctions,
+{
+	fn name(&self) -> &'static str {
+		self.name
+	}
+
+	fn dry_run_call(
+		&mut self,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<HopEffects, MultiHopDryRunError> {
+		dry_run_call(self, origin, call)
+	}
+
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<HopEffects, MultiHopDryRunError> {
+		dry_run_xcm(self, origin, xcm)
+	}
+
+	fn rollback(&mut self) {
+		self.journey = None;
+	}
+}
+
+struct RoutedChain {
+	/// The location of the chain, as seen by the relay chain.
+	location: Location,
+	chain: Box<dyn DryRunChain>,
+}
+
+/// Dry-runs a call and follows the messages it forwards through the chains of a network.
+///
+/// ```ignore
+/// let effects = MultiHopDryRun::new()
+/// 	.with_parachain::<PenpalA>()
+/// 	.with_parachain::<AssetHubWestend>()
+/// 	.with_parachain::<PenpalB>()
+/// 	.dry_run_call::<PenpalA>(origin, call)?;
+/// assert!(effects.is_success());
+/// ```
+///
+/// Messages are routed between the relay chain and its parachains, and between sibling
+/// parachains. Messages to other destinations, e.g. bridged chains, are reported but not followed.
+pub struct MultiHopDryRun {
+	chains: Vec<RoutedChain>,
+	max_hops: usize,
+}
+
+impl Default for MultiHopDryRun {
+	fn default() -> Self {
+		Self { chains: Vec::new(), max_hops: DEFAULT_MAX_HOPS }
+	}
+}
+
+impl MultiHopDryRun {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the maximum number of hops of the dry-run.
+	pub fn with_max_hops(mut self, max_hops: usize) -> Self {
+		self.max_hops = max_hops;
+		self
+	}
+
+	/// Add the relay chain `C` of the emulated network to the dry-run.
+	pub fn with_relay_chain<C>(self) -> Self
+	where
+		C: RelayChain + 'static,
+		NativeChain<C>: DryRunChain,
+	{
+		self.with_chain(Location::here(), NativeChain::<C>::new())
+	}
+
+	/// Add the parachain `C` of the emulated network to the dry-run.
+	pub fn with_parachain<C>(self) -> Self
+	where
+		C: Parachain + 'static,
+		NativeChain<C>: DryRunChain,
+	{
+		self.with_chain(
+			Location::new(0, [ParachainJunction(C::para_id().into())]),
+			NativeChain::<C>::new(),
+		)
+	}
+
+	/// Add `chain` to the dry-run, at `location` as seen by the relay chain.
+	pub fn with_chain(mut self, location: Location, chain: impl DryRunChain + 'static) -> Self {
+		self.chains.push(RoutedChain { location, chain: Box::new(chain) });
+		self
+	}
+
+	/// Dry-run `call` dispatched by `origin` on the chain `C` of the emulated network, and the
+	/// messages it forwards to the chains of the dry-run.
+	///
+	/// `C` must have been added to the dry-run.
+	pub fn dry_run_call<C: Chain>(
+		&mut self,
+		origin: C::OriginCaller,
+		call: C::RuntimeCall,
+	) -> Result<MultiHopDryRunEffects, MultiHopDryRunError>
+	where
+		C::RuntimeCall: Encode,
+		C::OriginCaller: Encode,
+	{
+		self.dry_run_encoded_call(type_name::<C>(), &origin.encode(), &call.encode())
+	}
+
+	/// Dry-run the SCALE-encoded `call` dispatched by the SCALE-encoded `origin` on the chain
+	/// named `chain`, and the messages it forwards to the chains of the dry-run.
+	///
+	/// The changes made by the journey are discarded once it is complete, whatever its result.
+	pub fn dry_run_encoded_call(
+		&mut self,
+		chain: &'static str,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<MultiHopDryRunEffects, MultiHopDryRunError> {
+		let index = self
+			.chains
+			.iter()
+			.position(|entry| entry.chain.name() == chain)
+			.ok_or(MultiHopDryRunError::UnknownChain(chain))?;
+		let effects = self.chains[index]
+			.chain
+			.dry_run_call(origin, call)
+			.and_then(|first| self.follow(first, index));
+		self.chains.iter_mut().for_each(|entry| entry.chain.rollback());
+		effects
+	}
+
+	/// Dry-run the messages forwarded by `hop`, executed on the chain at `index`, and the
+	/// messages they forward in turn.
+	fn follow(
+		&mut self,
+		mut hop: HopEffects,
+		mut index: usize,
+	) -> Result<MultiHopDryRunEffects, MultiHopDryRunError> {
+		let mut effects = MultiHopDryRunEffects { hops: Vec::new() };
+		let mut pending = VecDeque::new();
+		loop {
+			for forwarded in hop.forwarded_xcms.iter_mut() {
+				let Some((next, origin)) =
+					self.route(&self.chains[index].location, &forwarded.destination)
+				else {
+					continue
+				};
+				let hop_index = effects.hops.len() + 1 + pending.len();
+				if hop_index >= self.max_hops {
+					return Err(MultiHopDryRunError::TooManyHops)
+				}
+				forwarded.hop = Some(hop_index);
+				pending.push_back((next, origin, forwarded.message.clone()));
+			}
+			effects.hops.push(hop);
+
+			let Some((next, origin, message)) = pending.pop_front() else { break };
+			hop = self.chains[next].chain.dry_run_xcm(origin.into(), message)?;
+			index = next;
+		}
+		Ok(effects)
+	}
+
+	/// Find the index of the chain of the dry-run at `destination`, as seen by the chain at
+	/// `from`, along with the location of `from` as seen by it.
+	fn route(&self, from: &Location, destination: &VersionedLocation) -> Option<(usize, Location)> {
+		let destination = Location::try_from(destination.clone()).ok()?;
+		let (to, origin) = match (from.unpack(), destination.unpack()) {
+			((0, []), (0, [ParachainJunction(id)])) =>
+				(Location::new(0, [ParachainJunction(*id)]), Location::parent()),
+			((0, [ParachainJunction(id)]), (1, [])) =>
+				(Location::here(), Location::new(0, [ParachainJunction(*id)])),
+			((0, [ParachainJunction(from_id)]), (1, [ParachainJunction(to_id)])) => (
+				Location::new(0, [ParachainJunction(*to_id)]),
+				Location::new(1, [ParachainJunction(*from_id)]),
+			),
+			_ => return None,
+		};
+		self.chains
+			.iter()
+			.position(|chain| chain.location == to)
+			.map(|index| (index, origin))
+	}
+}
+
+/// The runtime APIs a hop is dry-run with.
+///
+/// API errors are already mapped to [`MultiHopDryRunError`].
+trait XcmRuntimeApis {
+	type Event: Debug;
+
+	fn name(&self) -> &'static str;
+
+	fn dry_run_call(
+		&mut self,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<CallDryRunEffects<Self::Event>, MultiHopDryRunError>;
+
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<XcmDryRunEffects<Self::Event>, MultiHopDryRunError>;
+
+	fn query_acceptable_payment_assets(
+		&mut self,
+	) -> Result<Vec<VersionedAssetId>, MultiHopDryRunError>;
+
+	fn query_xcm_weight(&mut self, xcm: VersionedXcm<()>) -> Result<Weight, MultiHopDryRunError>;
+
+	fn query_weight_to_asset_fee(
+		&mut self,
+		weight: Weight,
+		asset: VersionedAssetId,
+	) -> Result<u128, MultiHopDryRunError>;
+
+	fn query_delivery_fees(
+		&mut self,
+		destination: VersionedLocation,
+		message: VersionedXcm<()>,
+	) -> Result<VersionedAssets, MultiHopDryRunError>;
+}
+
+/// The runtime APIs of the native runtime of `C`, to be called in its externalities.
+struct NativeApis<C>(PhantomData<C>);
+
+impl<C> XcmRuntimeApis for NativeApis<C>
+where
+	C: Chain,
+	C::Runtime: DryRunApiV1<BlockOf<C>, C::RuntimeCall, C::RuntimeEvent, C::OriginCaller>
+		+ XcmPaymentApiV1<BlockOf<C>>,
+	C::RuntimeCall: Encode + Decode,
+	C::RuntimeEvent: Decode + Debug,
+	C::OriginCaller: Encode + Decode,
+{
+	type Event = C::RuntimeEvent;
+
+	fn name(&self) -> &'static str {
+		type_name::<C>()
+	}
+
+	fn dry_run_call(
+		&mut self,
+		mut origin: &[u8],
+		mut call: &[u8],
+	) -> Result<CallDryRunEffects<Self::Event>, MultiHopDryRunError> {
+		let chain = self.name();
+		let codec_error = |e: codec::Error| MultiHopDryRunError::Runtime {
+			chain,
+			error: format!("scale codec error: {e}"),
+		};
+		let origin = C::OriginCaller::decode(&mut origin).map_err(codec_error)?;
+		let call = C::RuntimeCall::decode(&mut call).map_err(codec_error)?;
+		<C::Runtime as DryRunApiV1<_, _, _, _>>::dry_run_call(origin, call)
+			.map_err(|error| MultiHopDryRunError::DryRun { chain, error })
+	}
+
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<XcmDryRunEffects<Self::Event>, MultiHopDryRunError> {
+		let chain = self.name();
+		let dry_run_error = |error| MultiHopDryRunError::DryRun { chain, error };
+		let program = Xcm::<()>::try_from(xcm)
+			.map_err(|()| dry_run_error(XcmDryRunApiError::VersionedConversionFailed))?;
+		let program = VersionedXcm::from(program.into::<C::RuntimeCall>());
+		<C::Runtime as DryRunApiV1<_, _, _, _>>::dry_run_xcm(origin, program).map_err(dry_run_error)
+	}
+
+	fn query_acceptable_payment_assets(
+		&mut self,
+	) -> Result<Vec<VersionedAssetId>, MultiHopDryRunError> {
+		let chain = self.name();
+		C::Runtime::query_acceptable_payment_assets(XCM_VERSION)
+			.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_xcm_weight(&mut self, xcm: VersionedXcm<()>) -> Result<Weight, MultiHopDryRunError> {
+		let chain = self.name();
+		C::Runtime::query_xcm_weight(xcm)
+			.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_weight_to_asset_fee(
+		&mut self,
+		weight: Weight,
+		asset: VersionedAssetId,
+	) -> Result<u128, MultiHopDryRunError> {
+		let chain = self.name();
+		C::Runtime::query_weight_to_asset_fee(weight, asset)
+			.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_delivery_fees(
+		&mut self,
+		destination: VersionedLocation,
+		message: VersionedXcm<()>,
+	) -> Result<VersionedAssets, MultiHopDryRunError> {
+		let chain = self.name();
+		C::Runtime::query_delivery_fees(destination, message)
+			.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+}
+
+impl<Event, EHF> XcmRuntimeApis for WasmChain<Event, EHF>
+where
+	Event: Decode + Debug,
+	EHF: HostFunctions,
+{
+	type Event = Event;
+
+	fn name(&self) -> &'static str {
+		self.name
+	}
+
+	fn dry_run_call(
+		&mut self,
+		origin: &[u8],
+		call: &[u8],
+	) -> Result<CallDryRunEffects<Self::Event>, MultiHopDryRunError> {
+		let chain = self.name;
+		self.call::<Result<_, XcmDryRunApiError>>(
+			"DryRunApi_dry_run_call",
+			&[origin, call].concat(),
+		)?
+		.map_err(|error| MultiHopDryRunError::DryRun { chain, error })
+	}
+
+	fn dry_run_xcm(
+		&mut self,
+		origin: VersionedLocation,
+		xcm: VersionedXcm<()>,
+	) -> Result<XcmDryRunEffects<Self::Event>, MultiHopDryRunError> {
+		let chain = self.name;
+		// `VersionedXcm<()>` and `VersionedXcm<RuntimeCall>` have the same encoding.
+		self.call::<Result<_, XcmDryRunApiError>>("DryRunApi_dry_run_xcm", &(origin, xcm).encode())?
+			.map_err(|error| MultiHopDryRunError::DryRun { chain, error })
+	}
+
+	fn query_acceptable_payment_assets(
+		&mut self,
+	) -> Result<Vec<VersionedAssetId>, MultiHopDryRunError> {
+		let chain = self.name;
+		self.call::<Result<_, XcmPaymentApiError>>(
+			"XcmPaymentApi_query_acceptable_payment_assets",
+			&XCM_VERSION.encode(),
+		)?
+		.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_xcm_weight(&mut self, xcm: VersionedXcm<()>) -> Result<Weight, MultiHopDryRunError> {
+		let chain = self.name;
+		self.call::<Result<_, XcmPaymentApiError>>("XcmPaymentApi_query_xcm_weight", &xcm.encode())?
+			.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_weight_to_asset_fee(
+		&mut self,
+		weight: Weight,
+		asset: VersionedAssetId,
+	) -> Result<u128, MultiHopDryRunError> {
+		let chain = self.name;
+		self.call::<Result<_, XcmPaymentApiError>>(
+			"XcmPaymentApi_query_weight_to_asset_fee",
+			&(weight, asset).encode(),
+		)?
+		.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+
+	fn query_delivery_fees(
+		&mut self,
+		destination: VersionedLocation,
+		message: VersionedXcm<()>,
+	) -> Result<VersionedAssets, MultiHopDryRunError> {
+		let chain = self.name;
+		self.call::<Result<_, XcmPaymentApiError>>(
+			"XcmPaymentApi_query_delivery_fees",
+			&(destination, message).encode(),
+		)?
+		.map_err(|error| MultiHopDryRunError::Fees { chain, error })
+	}
+}
+
+fn dry_run_call(
+	apis: &mut impl XcmRuntimeApis,
+	origin: &[u8],
+	call: &[u8],
+) -> Result<HopEffects, MultiHopDryRunError> {
+	let effects = apis.dry_run_call(origin, call)?;
+	let execution_fees = match &effects.local_xcm {
+		Some(xcm) => execution_fees(apis, xcm)?,
+		None => Vec::new(),
+	};
+	Ok(HopEffects {
+		chain: apis.name(),
+		result: HopResult::Call(effects.execution_result),
+		xcm: effects.local_xcm,
+		execution_fees,
+		events: effects.emitted_events.iter().map(|event| format!("{event:?}")).collect(),
+		forwarded_xcms: forwarded_xcms(apis, effects.forwarded_xcms)?,
+	})
+}
+
+fn dry_run_xcm(
+	apis: &mut impl XcmRuntimeApis,
+	origin: VersionedLocation,
+	xcm: VersionedXcm<()>,
+) -> Result<HopEffects, MultiHopDryRunError> {
+	let effects = apis.dry_run_xcm(origin.clone(), xcm.clone())?;
+	Ok(HopEffects {
+		chain: apis.name(),
+		result: HopResult::Xcm { origin, outcome: effects.execution_result },
+		execution_fees: execution_fees(apis, &xcm)?,
+		xcm: Some(xcm),
+		events: effects.emitted_events.iter().map(|event| format!("{event:?}")).collect(),
+		forwarded_xcms: forwarded_xcms(apis, effects.forwarded_xcms)?,
+	})
+}
+
+/// The fees to execute `xcm`, in each of the acceptable payment assets of the chain.
+fn execution_fees(
+	apis: &mut impl XcmRuntimeApis,
+	xcm: &VersionedXcm<()>,
+) -> Result<Vec<(VersionedAssetId, u128)>, MultiHopDryRunError> {
+	let weight = apis.query_xcm_weight(xcm.clone())?;
+	let mut fees = Vec::new();
+	for asset in apis.query_acceptable_payment_assets()? {
+		match apis.query_weight_to_asset_fee(weight, asset.clone()) {
+			Ok(fee) => fees.push((asset, fee)),
+			// Some acceptable assets may not be convertible at the moment, e.g. for lack of
+			// liquidity.
+			Err(MultiHopDryRunError::Fees { .. }) => {},
+			Err(error) => return Err(error),
+		}
+	}
+	Ok(fees)
+}
+
+/// Attach the delivery fees charged by the chain to the messages it forwarded.
+fn forwarded_xcms(
+	apis: &mut impl XcmRuntimeApis,
+	forwarded: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+) -> Result<Vec<ForwardedXcm>, MultiHopDryRunError> {
+	forwarded
+		.into_iter()
+		.flat_map(|(destination, messages)| {
+			messages.into_iter().map(move |message| (destination.clone(), message))
+		})
+		.map(|(destination, message)| {
+			let delivery_fees = apis.query_delivery_fees(destination.clone(), message.clone())?;
+			Ok(ForwardedXcm { destination, message, delivery_fees, hop: None })
+		})
+		.collect()
+}
@@ -56,6 +56,56 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Export the disputes stored in the parachains database as JSON.
+	ExportDisputes(ExportDisputesCmd),
+
+	/// Summarize disputes previously exported with `export-disputes`.
+	InspectDisputes(InspectDisputesCmd),
+}
+
+/// The `export-disputes` command used to dump the disputes known to a stopped node.
+///
+/// Only disputes of sessions within the dispute window are kept by the node.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportDisputesCmd {
+	/// First session to export the disputes of.
+	#[arg(long, value_name = "SESSION", default_value_t = 0)]
+	pub from_session: u32,
+
+	/// Last session to export the disputes of. Defaults to the latest session.
+	#[arg(long, value_name = "SESSION")]
+	pub to_session: Option<u32>,
+
+	/// File to write the disputes to. Defaults to stdout.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportDisputesCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// The `inspect-disputes` command used to summarize exported disputes without a node database.
+#[derive(Debug, Clone, Parser)]
+pub struct InspectDisputesCmd {
+	/// File written by `export-disputes`.
+	#[arg(value_name = "PATH")]
+	pub input: PathBuf,
 }
 
 #[allow(missing_docs)]
@@ -152,6 +202,14 @@ pub struct RunCmd {
 	/// explicitly advised to.
 	#[arg(long)]
 	pub enable_approval_voting_parallel: bool,
+
+	/// Recover the availability data of large PoVs by requesting systematic and regular chunks
+	/// concurrently, from the validators that served chunks the fastest so far.
+	///
+	/// **Dangerous!** This is an experimental feature and should not be used in production, unless
+	/// explicitly advised to.
+	#[arg(long)]
+	pub enable_adaptive_chunk_recovery: bool,
 }
 
 #[allow(missing_docs)]
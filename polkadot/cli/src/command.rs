@@ -23,6 +23,7 @@ use log::info;
 use polkadot_service::{
 	self,
 	benchmarking::{benchmark_inherent_data, TransferKeepAliveBuilder},
+	dispute_export::{DisputeRecord, DisputeSummary},
 	HeaderBackend, IdentifyVariant,
 };
 #[cfg(feature = "pyroscope")]
@@ -235,6 +236,7 @@ where
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
 				enable_approval_voting_parallel: cli.run.enable_approval_voting_parallel,
+				enable_adaptive_chunk_recovery: cli.run.enable_adaptive_chunk_recovery,
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -461,6 +463,31 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::ExportDisputes(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| {
+				let sessions = cmd.from_session..=cmd.to_session.unwrap_or(u32::MAX);
+				let disputes = polkadot_service::export_disputes(&config.database, sessions)?;
+				let json = serde_json::to_string_pretty(&disputes)
+					.map_err(|err| Error::Other(format!("Failed to encode disputes: {}", err)))?;
+
+				match &cmd.output {
+					Some(path) => std::fs::write(path, json).map_err(sc_cli::Error::Io)?,
+					None => println!("{}", json),
+				}
+				Ok(())
+			})
+		},
+		Some(Subcommand::InspectDisputes(cmd)) => {
+			let file = std::fs::File::open(&cmd.input).map_err(sc_cli::Error::Io)?;
+			let disputes: Vec<DisputeRecord> =
+				serde_json::from_reader(std::io::BufReader::new(file)).map_err(|err| {
+					sc_cli::Error::Input(format!("Invalid disputes file: {}", err))
+				})?;
+
+			print!("{}", DisputeSummary(&disputes));
+			Ok(())
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
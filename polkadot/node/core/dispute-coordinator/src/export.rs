@@ -0,0 +1,344 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export of the disputes stored by the dispute coordinator, for analysis after an incident.
+//!
+//! Only disputes that have not been pruned yet can be exported, which are the ones of the last
+//! `DISPUTE_WINDOW` sessions the node has seen.
+
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive, sync::Arc};
+
+use codec::Encode;
+use serde::{Deserialize, Serialize};
+
+use polkadot_node_primitives::{disputes::Timestamp, DisputeStatus};
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{
+	Hash, InvalidDisputeStatementKind, SessionIndex, ValidDisputeStatementKind, ValidatorIndex,
+	ValidatorSignature,
+};
+
+pub use crate::error::FatalError;
+
+use crate::{
+	backend::Backend,
+	db::v1::{CandidateVotes, DbBackend},
+	error::FatalResult,
+	metrics::Metrics,
+	Config,
+};
+
+/// Outcome of a dispute at the time of the export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeOutcome {
+	/// The dispute is active and unconcluded.
+	Active,
+	/// The dispute is confirmed, but not concluded.
+	Confirmed,
+	/// The dispute concluded in favor of the candidate.
+	ConcludedFor,
+	/// The dispute concluded against the candidate.
+	ConcludedAgainst,
+}
+
+/// The kind of statement a vote originates from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteKind {
+	/// An explicit statement issued as part of the dispute.
+	Explicit,
+	/// A seconded statement from the backing phase.
+	BackingSeconded {
+		/// The relay parent the candidate was backed in the context of.
+		relay_parent: Hash,
+	},
+	/// A valid statement from the backing phase.
+	BackingValid {
+		/// The relay parent the candidate was backed in the context of.
+		relay_parent: Hash,
+	},
+	/// An approval vote from the approval checking phase.
+	ApprovalChecking,
+	/// An approval vote covering several candidates.
+	ApprovalCheckingMultipleCandidates {
+		/// The hashes of the approved candidates.
+		candidates: Vec<Hash>,
+	},
+}
+
+impl From<&ValidDisputeStatementKind> for VoteKind {
+	fn from(kind: &ValidDisputeStatementKind) -> Self {
+		match kind {
+			ValidDisputeStatementKind::Explicit => Self::Explicit,
+			ValidDisputeStatementKind::BackingSeconded(relay_parent) =>
+				Self::BackingSeconded { relay_parent: *relay_parent },
+			ValidDisputeStatementKind::BackingValid(relay_parent) =>
+				Self::BackingValid { relay_parent: *relay_parent },
+			ValidDisputeStatementKind::ApprovalChecking => Self::ApprovalChecking,
+			ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(candidates) =>
+				Self::ApprovalCheckingMultipleCandidates {
+					candidates: candidates.iter().map(|candidate| candidate.0).collect(),
+				},
+		}
+	}
+}
+
+impl From<&InvalidDisputeStatementKind> for VoteKind {
+	fn from(kind: &InvalidDisputeStatementKind) -> Self {
+		match kind {
+			InvalidDisputeStatementKind::Explicit => Self::Explicit,
+		}
+	}
+}
+
+/// A single vote on a disputed candidate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteRecord {
+	/// Index of the voting validator in the session.
+	pub validator_index: u32,
+	/// The kind of statement the vote originates from.
+	pub kind: VoteKind,
+	/// Hex encoded signature of the vote.
+	pub signature: String,
+}
+
+/// A dispute as stored by the dispute coordinator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisputeRecord {
+	/// Session the disputed candidate was included in.
+	pub session: SessionIndex,
+	/// Hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// Para of the disputed candidate, if its receipt is known.
+	pub para_id: Option<u32>,
+	/// Relay parent of the disputed candidate, if its receipt is known.
+	pub relay_parent: Option<Hash>,
+	/// Outcome of the dispute.
+	pub outcome: DisputeOutcome,
+	/// UNIX timestamp in seconds at which the dispute concluded, if it did.
+	pub concluded_at: Option<Timestamp>,
+	/// Votes for the validity of the candidate, sorted by validator index.
+	pub valid_votes: Vec<VoteRecord>,
+	/// Votes against the validity of the candidate, sorted by validator index.
+	pub invalid_votes: Vec<VoteRecord>,
+}
+
+impl DisputeRecord {
+	fn new(
+		session: SessionIndex,
+		candidate_hash: Hash,
+		status: DisputeStatus,
+		votes: Option<CandidateVotes>,
+	) -> Self {
+		let (outcome, concluded_at) = match status {
+			DisputeStatus::Active => (DisputeOutcome::Active, None),
+			DisputeStatus::Confirmed => (DisputeOutcome::Confirmed, None),
+			DisputeStatus::ConcludedFor(at) => (DisputeOutcome::ConcludedFor, Some(at)),
+			DisputeStatus::ConcludedAgainst(at) => (DisputeOutcome::ConcludedAgainst, Some(at)),
+		};
+		let descriptor = votes.as_ref().map(|votes| &votes.candidate_receipt.descriptor);
+
+		Self {
+			session,
+			candidate_hash,
+			para_id: descriptor.map(|descriptor| u32::from(descriptor.para_id())),
+			relay_parent: descriptor.map(|descriptor| descriptor.relay_parent()),
+			outcome,
+			concluded_at,
+			valid_votes: votes
+				.as_ref()
+				.map(|votes| {
+					votes.valid.iter().map(|(kind, index, sig)| vote(kind, *index, sig)).collect()
+				})
+				.unwrap_or_default(),
+			invalid_votes: votes
+				.as_ref()
+				.map(|votes| {
+					votes.invalid.iter().map(|(kind, index, sig)| vote(kind, *index, sig)).collect()
+				})
+				.unwrap_or_default(),
+		}
+	}
+}
+
+fn vote(
+	kind: impl Into<VoteKind>,
+	validator_index: ValidatorIndex,
+	signature: &ValidatorSignature,
+) -> VoteRecord {
+	let signature = signature
+		.encode()
+		.iter()
+		.map(|byte| format!("{:02x}", byte))
+		.collect::<String>();
+	VoteRecord {
+		validator_index: validator_index.0,
+		kind: kind.into(),
+		signature: format!("0x{}", signature),
+	}
+}
+
+/// Read all disputes of the given `sessions` from the dispute coordinator database.
+///
+/// The database must not be in use by a running node.
+pub fn export_disputes(
+	db: Arc<dyn Database>,
+	config: Config,
+	sessions: RangeInclusive<SessionIndex>,
+) -> FatalResult<Vec<DisputeRecord>> {
+	let backend = DbBackend::new(db, config.column_config(), Metrics::default());
+
+	let mut records = Vec::new();
+	for ((session, candidate_hash), status) in
+		backend.load_recent_disputes()?.unwrap_or_default().into_iter()
+	{
+		if !sessions.contains(&session) {
+			continue
+		}
+		let votes = backend.load_candidate_votes(session, &candidate_hash)?;
+		records.push(DisputeRecord::new(session, candidate_hash.0, status, votes));
+	}
+
+	Ok(records)
+}
+
+/// Human readable summary of exported disputes.
+pub struct DisputeSummary<'a>(pub &'a [DisputeRecord]);
+
+impl fmt::Display for DisputeSummary<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "{} disputes", self.0.len())?;
+
+		// Validators that voted against the outcome of concluded disputes, per session.
+		let mut losing_voters = BTreeMap::<SessionIndex, BTreeMap<u32, usize>>::new();
+
+		for record in self.0 {
+			writeln!(
+				f,
+				"session {} candidate {:?} para {} outcome {:?}{} valid {} invalid {}",
+				record.session,
+				record.candidate_hash,
+				record.para_id.map_or_else(|| "unknown".to_string(), |id| id.to_string()),
+				record.outcome,
+				record.concluded_at.map_or_else(String::new, |at| format!(" at {}", at)),
+				record.valid_votes.len(),
+				record.invalid_votes.len(),
+			)?;
+
+			let losing_votes = match record.outcome {
+				DisputeOutcome::ConcludedFor => &record.invalid_votes,
+				DisputeOutcome::ConcludedAgainst => &record.valid_votes,
+				DisputeOutcome::Active | DisputeOutcome::Confirmed => continue,
+			};
+			for vote in losing_votes {
+				*losing_voters
+					.entry(record.session)
+					.or_default()
+					.entry(vote.validator_index)
+					.or_default() += 1;
+			}
+		}
+
+		for (session, voters) in losing_voters {
+			writeln!(f, "session {} validators on the losing side:", session)?;
+			for (validator_index, disputes) in voters {
+				writeln!(f, "  validator {} in {} disputes", validator_index, disputes)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{backend::OverlayedBackend, db::v1::ColumnConfiguration};
+	use polkadot_primitives::{
+		CandidateHash, InvalidDisputeStatementKind, ValidDisputeStatementKind,
+	};
+	use polkadot_primitives_test_helpers::{dummy_candidate_receipt_v2, dummy_hash};
+	use sp_core::sr25519;
+
+	#[test]
+	fn disputes_are_exported_and_summarized() {
+		let db = kvdb_memorydb::create(1);
+		let db: Arc<dyn Database> =
+			Arc::new(polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[0]));
+		let mut backend = DbBackend::new(
+			db.clone(),
+			ColumnConfiguration { col_dispute_data: 0 },
+			Metrics::default(),
+		);
+		let signature = ValidatorSignature::from(sr25519::Signature::default());
+
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_recent_disputes(
+			[
+				((1, CandidateHash(Hash::repeat_byte(1))), DisputeStatus::ConcludedAgainst(42)),
+				((2, CandidateHash(Hash::repeat_byte(2))), DisputeStatus::Active),
+				((5, CandidateHash(Hash::repeat_byte(5))), DisputeStatus::Active),
+			]
+			.into_iter()
+			.collect(),
+		);
+		overlay_db.write_candidate_votes(
+			1,
+			CandidateHash(Hash::repeat_byte(1)),
+			CandidateVotes {
+				candidate_receipt: dummy_candidate_receipt_v2(dummy_hash()),
+				valid: vec![(
+					ValidDisputeStatementKind::Explicit,
+					ValidatorIndex(3),
+					signature.clone(),
+				)],
+				invalid: vec![
+					(InvalidDisputeStatementKind::Explicit, ValidatorIndex(0), signature.clone()),
+					(InvalidDisputeStatementKind::Explicit, ValidatorIndex(1), signature.clone()),
+				],
+			},
+		);
+		backend.write(overlay_db.into_write_ops()).unwrap();
+
+		let records = export_disputes(db, Config { col_dispute_data: 0 }, 0..=2).unwrap();
+		assert_eq!(records.len(), 2);
+
+		assert_eq!(records[0].session, 1);
+		assert_eq!(records[0].outcome, DisputeOutcome::ConcludedAgainst);
+		assert_eq!(records[0].concluded_at, Some(42));
+		assert!(records[0].para_id.is_some());
+		assert_eq!(
+			records[0].valid_votes,
+			vec![VoteRecord {
+				validator_index: 3,
+				kind: VoteKind::Explicit,
+				signature: format!("0x{}", "00".repeat(64)),
+			}]
+		);
+		assert_eq!(records[0].invalid_votes.len(), 2);
+
+		// Votes of the second dispute were not stored.
+		assert_eq!(records[1].session, 2);
+		assert_eq!(records[1].para_id, None);
+		assert!(records[1].valid_votes.is_empty());
+
+		let summary = DisputeSummary(&records).to_string();
+		assert!(summary.starts_with("2 disputes\n"));
+		assert!(summary
+			.contains("session 1 validators on the losing side:\n  validator 3 in 1 disputes\n"));
+	}
+}
@@ -68,6 +68,9 @@ pub(crate) mod backend;
 pub(crate) mod db;
 pub(crate) mod error;
 
+/// Export of the stored disputes for offline analysis.
+pub mod export;
+
 /// Subsystem after receiving the first active leaf.
 mod initialized;
 use initialized::{InitialData, Initialized};
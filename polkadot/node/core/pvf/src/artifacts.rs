@@ -18,7 +18,11 @@
 //!
 //! # Lifecycle of an artifact
 //!
-//! 1. During node start-up, we prune all the cached artifacts, if any.
+//! 1. During node start-up, we load the artifacts cached by previous runs into the table as
+//!    [`ArtifactState::Prepared`], provided they carry the same [version tag][artifact_version] as
+//!    the ones this node would prepare and their contents match the checksum recorded in the file
+//!    name. Any other file that looks like an artifact is removed, so the corresponding PVFs will
+//!    simply be prepared again.
 //!
 //! 2. In order to be executed, a PVF should be prepared first. This means that artifacts should
 //!    have an [`ArtifactState::Prepared`] entry for that artifact in the table. If not, the
@@ -34,8 +38,9 @@
 //!
 //! 3. The pool gets an available worker and instructs it to work on the given PVF. The worker
 //!    starts compilation. When the worker finishes successfully, it writes the serialized artifact
-//!    into a temporary file and notifies the host that it's done. The host atomically moves
-//!    (renames) the temporary file to the destination filename of the artifact.
+//!    into a temporary file and notifies the host that it's done, along with the checksum of the
+//!    artifact. The host atomically moves (renames) the temporary file to the destination filename
+//!    of the artifact, which is derived from the artifact ID, the version tag and the checksum.
 //!
 //! 4. If the worker concluded successfully or returned an error, then the pool notifies the queue.
 //!    In both cases, the queue reports to the host that the result is ready.
@@ -54,8 +59,9 @@
 //!    older by a predefined parameter. This process is run very rarely (say, once a day). Once the
 //!    artifact is expired it is removed from disk eagerly atomically.
 
-use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX};
+use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX, LOG_TARGET};
 use always_assert::always;
+use codec::Decode;
 use polkadot_node_core_pvf_common::{error::PrepareError, pvf::PvfPrepData};
 use polkadot_parachain_primitives::primitives::ValidationCodeHash;
 use polkadot_primitives::ExecutorParamsPrepHash;
@@ -72,19 +78,124 @@ const ARTIFACT_EXTENSION: &str = "pvf";
 /// The prefix that artifacts used to start with under the old naming scheme.
 const ARTIFACT_OLD_PREFIX: &str = "wasmtime_";
 
-pub fn generate_artifact_path(cache_path: &Path) -> PathBuf {
-	let file_name = {
+/// The version tag used for artifacts if the one of this node could not be determined.
+///
+/// Artifacts with this tag are never reused.
+pub const UNVERSIONED_ARTIFACT_TAG: &str = "unversioned";
+
+/// Computes the version tag of the artifacts prepared by this node.
+///
+/// Artifacts are native code, so a cached artifact may only be reused if this node would have
+/// produced exactly the same code. The tag thus commits to the contents of the prepare worker
+/// binary, which pins down the exact node build and the wasmtime version compiled into it, and to
+/// the CPU features of the host, which wasmtime detects and targets when compiling.
+///
+/// Returns `None` if the prepare worker binary could not be read, in which case no cached artifact
+/// should be reused.
+pub fn artifact_version(prepare_worker_path: &Path) -> Option<String> {
+	let mut hasher = blake3::Hasher::new();
+	let hashed = fs::File::open(prepare_worker_path)
+		.and_then(|mut file| std::io::copy(&mut file, &mut hasher));
+	if let Err(err) = hashed {
+		gum::warn!(
+			target: LOG_TARGET,
+			?err,
+			"could not read the prepare worker binary at {}, cached artifacts won't be reused",
+			prepare_worker_path.display(),
+		);
+		return None
+	}
+	hasher.update(host_cpu_features().as_bytes());
+	Some(format!("v{}", &hasher.finalize().to_hex()[..16]))
+}
+
+/// Lists the CPU features of the host that wasmtime may target when compiling.
+fn host_cpu_features() -> String {
+	#[allow(unused_mut)]
+	let mut features: Vec<&str> = Vec::new();
+	#[allow(unused_macros)]
+	macro_rules! detect {
+		($detected:ident, $($feature:literal),*) => {
+			$(if std::arch::$detected!($feature) { features.push($feature) })*
+		};
+	}
+	#[cfg(target_arch = "x86_64")]
+	detect!(
+		is_x86_feature_detected,
+		"sse3",
+		"ssse3",
+		"sse4.1",
+		"sse4.2",
+		"popcnt",
+		"avx",
+		"avx2",
+		"fma",
+		"bmi1",
+		"bmi2",
+		"lzcnt",
+		"avx512f",
+		"avx512vl",
+		"avx512dq",
+		"avx512bitalg",
+		"avx512vbmi"
+	);
+	#[cfg(target_arch = "aarch64")]
+	detect!(is_aarch64_feature_detected, "lse", "paca", "fp16");
+	format!("{}:{}", std::env::consts::ARCH, features.join(","))
+}
+
+/// Computes the checksum of the artifact with the given contents, the same way prepare workers do.
+pub fn compute_checksum(data: &[u8]) -> String {
+	blake3::hash(data).to_hex().to_string()
+}
+
+/// Generates a path in the cache for the artifact with the given ID and checksum.
+///
+/// The file name is of the form `<code hash>_<executor params hash>_<version>_0x<checksum>_<nonce>`
+/// with the `pvf` extension, which allows to recognize the artifact after a restart. The random
+/// nonce makes sure that a repreparation never reuses the path of a removed artifact.
+pub fn generate_artifact_path(
+	cache_path: &Path,
+	artifact_id: &ArtifactId,
+	version: &str,
+	checksum: &str,
+) -> PathBuf {
+	let nonce = {
 		use array_bytes::Hex;
 		use rand::RngCore;
-		let mut bytes = [0u8; 64];
+		let mut bytes = [0u8; 8];
 		rand::thread_rng().fill_bytes(&mut bytes);
-		bytes.hex("0x")
+		bytes.hex("")
 	};
+	let file_name = format!(
+		"{:#x}_{:#x}_{}_0x{}_{}",
+		artifact_id.code_hash, artifact_id.executor_params_prep_hash, version, checksum, nonce,
+	);
 	let mut artifact_path = cache_path.join(file_name);
 	artifact_path.set_extension(ARTIFACT_EXTENSION);
 	artifact_path
 }
 
+/// Parses the stem of an artifact file name produced by [`generate_artifact_path`] into the
+/// artifact ID, the version tag and the checksum.
+fn parse_artifact_file_stem(file_stem: &str) -> Option<(ArtifactId, &str, &str)> {
+	let mut parts = file_stem.split('_');
+	let code_hash = array_bytes::hex2array::<_, 32>(parts.next()?).ok()?;
+	let executor_params_prep_hash = array_bytes::hex2array::<_, 32>(parts.next()?).ok()?;
+	let version = parts.next()?;
+	let checksum = parts.next()?.strip_prefix("0x")?;
+	let _nonce = parts.next()?;
+	if parts.next().is_some() {
+		return None
+	}
+
+	let artifact_id = ArtifactId::new(
+		code_hash.into(),
+		ExecutorParamsPrepHash::decode(&mut &executor_params_prep_hash[..]).ok()?,
+	);
+	Some((artifact_id, version, checksum))
+}
+
 /// Identifier of an artifact. Encodes a code hash of the PVF and a hash of preparation-related
 ///  executor parameter set.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -212,27 +323,83 @@ impl Artifacts {
 		self.inner.keys().cloned().collect()
 	}
 
-	/// Create an empty table and the cache directory on-disk if it doesn't exist.
-	pub async fn new(cache_path: &Path) -> Self {
+	/// Create the table from the artifacts with the given version tag cached by previous runs and
+	/// the cache directory on-disk if it doesn't exist.
+	///
+	/// If `version` is `None`, no cached artifact is reused.
+	pub async fn new(cache_path: &Path, version: Option<&str>) -> Self {
 		// Make sure that the cache path directory and all its parents are created.
 		let _ = tokio::fs::create_dir_all(cache_path).await;
 
-		// Delete any leftover artifacts and worker dirs from previous runs. We don't delete the
-		// entire cache directory in case the user made a mistake and set it to e.g. their home
-		// directory. This is a best-effort to do clean-up, so ignore any errors.
+		// Reading and hashing the cached artifacts takes a while, keep it off the async runtime.
+		let cache_path = cache_path.to_owned();
+		let version = version.map(ToOwned::to_owned);
+		tokio::task::spawn_blocking(move || Self::load(&cache_path, version.as_deref()))
+			.await
+			.unwrap_or_else(|_| Self { inner: HashMap::new() })
+	}
+
+	fn load(cache_path: &Path, version: Option<&str>) -> Self {
+		let mut artifacts = Self { inner: HashMap::new() };
+		let now = SystemTime::now();
+
+		// Delete any leftover worker dirs and artifacts we can't use from previous runs. We don't
+		// delete the entire cache directory in case the user made a mistake and set it to e.g.
+		// their home directory. This is a best-effort to do clean-up, so ignore any errors.
 		for entry in fs::read_dir(cache_path).into_iter().flatten().flatten() {
 			let path = entry.path();
 			let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else { continue };
 			if path.is_dir() && file_name.starts_with(WORKER_DIR_PREFIX) {
 				let _ = fs::remove_dir_all(path);
-			} else if path.extension().map_or(false, |ext| ext == ARTIFACT_EXTENSION) ||
-				file_name.starts_with(ARTIFACT_OLD_PREFIX)
-			{
+			} else if path.extension().map_or(false, |ext| ext == ARTIFACT_EXTENSION) {
+				match version.and_then(|version| Self::load_cached(&path, version)) {
+					Some((artifact_id, size)) if !artifacts.inner.contains_key(&artifact_id) => {
+						gum::debug!(
+							target: LOG_TARGET,
+							?artifact_id,
+							"reusing cached artifact {}",
+							path.display(),
+						);
+						artifacts.insert_prepared(artifact_id, path, now, size);
+					},
+					_ => {
+						gum::debug!(
+							target: LOG_TARGET,
+							"removing stale artifact {}",
+							path.display(),
+						);
+						let _ = fs::remove_file(path);
+					},
+				}
+			} else if file_name.starts_with(ARTIFACT_OLD_PREFIX) {
 				let _ = fs::remove_file(path);
 			}
 		}
 
-		Self { inner: HashMap::new() }
+		artifacts
+	}
+
+	/// Checks whether the artifact at the given path carries the given version tag and is intact,
+	/// and returns its ID and size if so.
+	fn load_cached(path: &Path, version: &str) -> Option<(ArtifactId, u64)> {
+		let file_stem = path.file_stem()?.to_str()?;
+		let (artifact_id, artifact_version, checksum) = parse_artifact_file_stem(file_stem)?;
+		if artifact_version != version {
+			return None
+		}
+
+		let data = fs::read(path).ok()?;
+		if compute_checksum(&data) != checksum {
+			gum::warn!(
+				target: LOG_TARGET,
+				?artifact_id,
+				"cached artifact {} is corrupted, it will be prepared again",
+				path.display(),
+			);
+			return None
+		}
+
+		Some((artifact_id, data.len() as u64))
 	}
 
 	/// Returns the state of the given artifact by its ID.
@@ -260,7 +427,6 @@ impl Artifacts {
 	///
 	/// This function should only be used to build the artifact table at startup with valid
 	/// artifact caches.
-	#[cfg(test)]
 	pub(crate) fn insert_prepared(
 		&mut self,
 		artifact_id: ArtifactId,
@@ -326,11 +492,14 @@ impl Artifacts {
 #[cfg(test)]
 mod tests {
 	use crate::testing::artifact_id;
+	use assert_matches::assert_matches;
 
 	use super::*;
 
+	const TEST_VERSION: &str = "v0123456789abcdef";
+
 	#[tokio::test]
-	async fn cache_cleared_on_startup() {
+	async fn unusable_files_cleared_on_startup() {
 		let tempdir = tempfile::tempdir().unwrap();
 		let cache_path = tempdir.path();
 
@@ -344,7 +513,7 @@ mod tests {
 		fs::write(cache_path.join("polkadot_..."), "test").unwrap();
 		fs::create_dir(cache_path.join("worker-prepare-test")).unwrap();
 
-		let artifacts = Artifacts::new(cache_path).await;
+		let artifacts = Artifacts::new(cache_path, Some(TEST_VERSION)).await;
 
 		let entries: Vec<String> = fs::read_dir(&cache_path)
 			.unwrap()
@@ -357,20 +526,108 @@ mod tests {
 		assert_eq!(artifacts.len(), 0);
 	}
 
+	#[tokio::test]
+	async fn artifacts_persist_across_restarts() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+
+		let artifact_id = artifact_id(1);
+		let path = generate_artifact_path(
+			cache_path,
+			&artifact_id,
+			TEST_VERSION,
+			&compute_checksum(b"artifact"),
+		);
+		fs::write(&path, "artifact").unwrap();
+
+		let mut artifacts = Artifacts::new(cache_path, Some(TEST_VERSION)).await;
+
+		assert!(path.exists());
+		assert_eq!(artifacts.artifact_ids(), vec![artifact_id.clone()]);
+		assert_matches!(
+			artifacts.artifact_state_mut(&artifact_id),
+			Some(ArtifactState::Prepared { path: prepared_path, size: 8, .. }) if *prepared_path == path
+		);
+	}
+
+	#[tokio::test]
+	async fn corrupted_and_outdated_artifacts_removed_on_startup() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+
+		// The contents don't match the checksum.
+		let corrupted = generate_artifact_path(
+			cache_path,
+			&artifact_id(1),
+			TEST_VERSION,
+			&compute_checksum(b"artifact"),
+		);
+		fs::write(&corrupted, "corrupted").unwrap();
+
+		// Prepared by another node build or on another host.
+		let outdated = cache_path.join(format!(
+			"{:#x}_{:#x}_v0000000000000000_0x{}_0000000000000000.pvf",
+			artifact_id(2).code_hash,
+			artifact_id(2).executor_params_prep_hash,
+			compute_checksum(b"artifact"),
+		));
+		fs::write(&outdated, "artifact").unwrap();
+
+		let artifacts = Artifacts::new(cache_path, Some(TEST_VERSION)).await;
+
+		assert!(!corrupted.exists());
+		assert!(!outdated.exists());
+		assert_eq!(artifacts.len(), 0);
+	}
+
+	#[tokio::test]
+	async fn artifacts_not_reused_without_version() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+
+		let path = generate_artifact_path(
+			cache_path,
+			&artifact_id(1),
+			UNVERSIONED_ARTIFACT_TAG,
+			&compute_checksum(b"artifact"),
+		);
+		fs::write(&path, "artifact").unwrap();
+
+		let artifacts = Artifacts::new(cache_path, None).await;
+
+		assert!(!path.exists());
+		assert_eq!(artifacts.len(), 0);
+	}
+
+	#[test]
+	fn artifact_version_depends_on_worker_binary() {
+		let tempdir = tempfile::tempdir().unwrap();
+		let worker1 = tempdir.path().join("worker1");
+		let worker2 = tempdir.path().join("worker2");
+		fs::write(&worker1, "worker 1").unwrap();
+		fs::write(&worker2, "worker 2").unwrap();
+
+		let version1 = artifact_version(&worker1).unwrap();
+		assert_eq!(artifact_version(&worker1), Some(version1.clone()));
+		assert_ne!(artifact_version(&worker2), Some(version1.clone()));
+		assert!(!version1.contains('_'));
+		assert_eq!(artifact_version(&tempdir.path().join("missing")), None);
+	}
+
 	#[tokio::test]
 	async fn test_pruned_by_cache_size() {
 		let mock_now = SystemTime::now();
 		let tempdir = tempfile::tempdir().unwrap();
 		let cache_path = tempdir.path();
 
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
-		let path3 = generate_artifact_path(cache_path);
 		let artifact_id1 = artifact_id(1);
 		let artifact_id2 = artifact_id(2);
 		let artifact_id3 = artifact_id(3);
+		let path1 = generate_artifact_path(cache_path, &artifact_id1, TEST_VERSION, "1");
+		let path2 = generate_artifact_path(cache_path, &artifact_id2, TEST_VERSION, "2");
+		let path3 = generate_artifact_path(cache_path, &artifact_id3, TEST_VERSION, "3");
 
-		let mut artifacts = Artifacts::new(cache_path).await;
+		let mut artifacts = Artifacts::new(cache_path, Some(TEST_VERSION)).await;
 		let cleanup_config = ArtifactsCleanupConfig::new(1500, Duration::from_secs(0));
 
 		artifacts.insert_prepared(
@@ -408,14 +665,14 @@ mod tests {
 		let tempdir = tempfile::tempdir().unwrap();
 		let cache_path = tempdir.path();
 
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
-		let path3 = generate_artifact_path(cache_path);
 		let artifact_id1 = artifact_id(1);
 		let artifact_id2 = artifact_id(2);
 		let artifact_id3 = artifact_id(3);
+		let path1 = generate_artifact_path(cache_path, &artifact_id1, TEST_VERSION, "1");
+		let path2 = generate_artifact_path(cache_path, &artifact_id2, TEST_VERSION, "2");
+		let path3 = generate_artifact_path(cache_path, &artifact_id3, TEST_VERSION, "3");
 
-		let mut artifacts = Artifacts::new(cache_path).await;
+		let mut artifacts = Artifacts::new(cache_path, Some(TEST_VERSION)).await;
 		let cleanup_config = ArtifactsCleanupConfig::new(1500, Duration::from_secs(12));
 
 		artifacts.insert_prepared(
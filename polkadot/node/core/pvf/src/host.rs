@@ -249,8 +249,16 @@ pub async fn start(
 ) -> SubsystemResult<(ValidationHost, impl Future<Output = ()>)> {
 	gum::debug!(target: LOG_TARGET, ?config, "starting PVF validation host");
 
-	// Make sure the cache is initialized before doing anything else.
-	let artifacts = Artifacts::new(&config.cache_path).await;
+	// Make sure the cache is initialized before doing anything else. Hashing the worker binary
+	// takes a while, keep it off the async runtime.
+	let prepare_worker_program_path = config.prepare_worker_program_path.clone();
+	let artifact_version = tokio::task::spawn_blocking(move || {
+		crate::artifacts::artifact_version(&prepare_worker_program_path)
+	})
+	.await
+	.ok()
+	.flatten();
+	let artifacts = Artifacts::new(&config.cache_path, artifact_version.as_deref()).await;
 
 	// Run checks for supported security features once per host startup. If some checks fail, warn
 	// if Secure Validator Mode is disabled and return an error otherwise.
@@ -289,6 +297,7 @@ pub async fn start(
 		metrics.clone(),
 		config.prepare_worker_program_path.clone(),
 		config.cache_path.clone(),
+		artifact_version.unwrap_or_else(|| crate::artifacts::UNVERSIONED_ARTIFACT_TAG.to_owned()),
 		config.prepare_worker_spawn_timeout,
 		config.node_version.clone(),
 		security_status.clone(),
@@ -1237,8 +1246,8 @@ pub(crate) mod tests {
 		let mut builder = Builder::default();
 		builder.cleanup_pulse_interval = Duration::from_millis(100);
 		builder.cleanup_config = ArtifactsCleanupConfig::new(1024, Duration::from_secs(0));
-		let path1 = generate_artifact_path(cache_path);
-		let path2 = generate_artifact_path(cache_path);
+		let path1 = generate_artifact_path(cache_path, &artifact_id(1), "v0", "1");
+		let path2 = generate_artifact_path(cache_path, &artifact_id(2), "v0", "2");
 		builder.artifacts.insert_prepared(artifact_id(1), path1.clone(), mock_now, 1024);
 		builder.artifacts.insert_prepared(artifact_id(2), path2.clone(), mock_now, 1024);
 		let mut test = builder.build();
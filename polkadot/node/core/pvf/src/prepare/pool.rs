@@ -114,6 +114,7 @@ struct Pool {
 	// Some variables related to the current session.
 	program_path: PathBuf,
 	cache_path: PathBuf,
+	artifact_version: String,
 	spawn_timeout: Duration,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
@@ -133,6 +134,7 @@ async fn run(
 	Pool {
 		program_path,
 		cache_path,
+		artifact_version,
 		spawn_timeout,
 		node_version,
 		security_status,
@@ -162,6 +164,7 @@ async fn run(
 					&metrics,
 					&program_path,
 					&cache_path,
+					&artifact_version,
 					spawn_timeout,
 					node_version.clone(),
 					security_status.clone(),
@@ -210,6 +213,7 @@ fn handle_to_pool(
 	metrics: &Metrics,
 	program_path: &Path,
 	cache_path: &Path,
+	artifact_version: &str,
 	spawn_timeout: Duration,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
@@ -243,6 +247,7 @@ fn handle_to_pool(
 							idle,
 							pvf,
 							cache_path,
+							artifact_version.to_owned(),
 							preparation_timer,
 						)
 						.boxed(),
@@ -304,9 +309,11 @@ async fn start_work_task<Timer>(
 	idle: IdleWorker,
 	pvf: PvfPrepData,
 	cache_path: PathBuf,
+	artifact_version: String,
 	_preparation_timer: Option<Timer>,
 ) -> PoolEvent {
-	let outcome = worker_interface::start_work(&metrics, idle, pvf, cache_path).await;
+	let outcome =
+		worker_interface::start_work(&metrics, idle, pvf, cache_path, artifact_version).await;
 	PoolEvent::StartWork(worker, outcome)
 }
 
@@ -496,6 +503,7 @@ pub fn start(
 	metrics: Metrics,
 	program_path: PathBuf,
 	cache_path: PathBuf,
+	artifact_version: String,
 	spawn_timeout: Duration,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
@@ -507,6 +515,7 @@ pub fn start(
 		metrics,
 		program_path,
 		cache_path,
+		artifact_version,
 		spawn_timeout,
 		node_version,
 		security_status,
@@ -17,7 +17,7 @@
 //! Host interface to the prepare worker.
 
 use crate::{
-	artifacts::generate_artifact_path,
+	artifacts::{generate_artifact_path, ArtifactId},
 	metrics::Metrics,
 	worker_interface::{
 		clear_worker_dir_path, framed_recv, framed_send, spawn_with_program_path, IdleWorker,
@@ -115,6 +115,7 @@ pub async fn start_work(
 	worker: IdleWorker,
 	pvf: PvfPrepData,
 	cache_path: PathBuf,
+	artifact_version: String,
 ) -> Outcome {
 	let IdleWorker { stream, pid, worker_dir } = worker;
 
@@ -132,6 +133,7 @@ pub async fn start_work(
 		pid,
 		|tmp_artifact_file, mut stream, worker_dir| async move {
 			let preparation_timeout = pvf.prep_timeout();
+			let artifact_id = ArtifactId::from_pvf_prep_data(&pvf);
 
 			if let Err(err) = send_request(&mut stream, &pvf).await {
 				gum::warn!(
@@ -165,6 +167,8 @@ pub async fn start_work(
 						pid,
 						tmp_artifact_file,
 						&cache_path,
+						&artifact_id,
+						&artifact_version,
 						preparation_timeout,
 					)
 					.await,
@@ -204,12 +208,12 @@ async fn handle_response(
 	worker_pid: u32,
 	tmp_file: PathBuf,
 	cache_path: &Path,
+	artifact_id: &ArtifactId,
+	artifact_version: &str,
 	preparation_timeout: Duration,
 ) -> Outcome {
-	// TODO: Add `checksum` to `ArtifactPathId`. See:
-	//       https://github.com/paritytech/polkadot-sdk/issues/2399
 	let PrepareWorkerSuccess {
-		checksum: _,
+		checksum,
 		stats: PrepareStats { cpu_time_elapsed, memory_stats, observed_wasm_code_len },
 	} = match result.clone() {
 		Ok(result) => result,
@@ -248,11 +252,11 @@ async fn handle_response(
 		},
 	};
 
-	// The file name should uniquely identify the artifact even across restarts. In case the cache
-	// for some reason is not cleared correctly, we cannot
-	// accidentally execute an artifact compiled under a different wasmtime version, host
-	// environment, etc.
-	let artifact_path = generate_artifact_path(cache_path);
+	// The file name identifies the artifact even across restarts. The version tag commits to the
+	// worker binary and the host CPU features, so that the artifact is only reused by a node that
+	// would have compiled the same code, and only after its checksum is verified.
+	let artifact_path =
+		generate_artifact_path(cache_path, artifact_id, artifact_version, &checksum);
 
 	gum::debug!(
 		target: LOG_TARGET,
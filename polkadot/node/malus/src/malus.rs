@@ -42,6 +42,10 @@ enum NemesisVariant {
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
 	/// Spam many request statements instead of sending a single one.
 	SpamStatementRequests(SpamStatementRequestsOptions),
+	/// Back candidates and refuse to serve their availability chunks and data.
+	WithholdAvailabilityChunks(WithholdAvailabilityChunksOptions),
+	/// Second the valid candidates of the backing group beyond the seconding limit.
+	EquivocateStatements(EquivocateStatementsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -105,6 +109,20 @@ impl MalusCli {
 
 				polkadot_cli::run_node(cli, SpamStatementRequests { spam_factor }, finality_delay)?
 			},
+			NemesisVariant::WithholdAvailabilityChunks(opts) => {
+				let WithholdAvailabilityChunksOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdAvailabilityChunks { percentage },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::EquivocateStatements(opts) => {
+				let EquivocateStatementsOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(cli, EquivocateStatements { percentage }, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -244,4 +262,40 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn percentage_works_withhold_availability_chunks() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-availability-chunks",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdAvailabilityChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn equivocate_statements_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"equivocate-statements",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::EquivocateStatements(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 100);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
 }
@@ -0,0 +1,382 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that equivocates in backing.
+//!
+//! This malus variant behaves honestly in everything except that it seconds more candidates than
+//! allowed at a relay parent. Once it has seconded a candidate, it also issues `Seconded`
+//! statements for every other candidate it learns of at the same relay parent from the
+//! `Seconded` statements of its backing group. These candidates were validated and seconded by
+//! honest validators, so the node vouches for valid, distinct candidates beyond its seconding
+//! limit.
+//!
+//! The extra statements are sent straight to the peers of the backing group, bypassing the
+//! local statement distribution which would refuse them.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_network_protocol::{
+	v2 as protocol_v2, v3 as protocol_v3, PeerId, Versioned, VersionedValidationProtocol,
+};
+use polkadot_node_primitives::StatementWithPVD;
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_node_subsystem_util::{
+	request_session_index_for_child, request_validators, signing_key_and_index,
+};
+use polkadot_primitives::{
+	CandidateHash, CompactStatement, Hash, SignedStatement, SigningContext,
+	UncheckedSignedStatement,
+};
+use sp_core::traits::SpawnNamed;
+use sp_keystore::KeystorePtr;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use rand::distributions::{Bernoulli, Distribution};
+use std::{
+	collections::{HashMap, HashSet, VecDeque},
+	sync::{Arc, Mutex},
+};
+
+/// The maximum number of relay parents to keep track of.
+const MAX_TRACKED_RELAY_PARENTS: usize = 64;
+
+/// The protocol version a peer sent us statements with.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum PeerVersion {
+	V2,
+	V3,
+}
+
+/// The candidates seconded at a relay parent.
+#[derive(Default)]
+struct RelayParentState {
+	/// Whether the node seconded a candidate itself.
+	locally_seconded: bool,
+	/// The candidates the node issued a `Seconded` statement for.
+	seconded: HashSet<CandidateHash>,
+	/// The candidates seconded by the other validators of the backing group.
+	seconded_by_group: HashSet<CandidateHash>,
+	/// The peers which sent statements for this relay parent.
+	peers: HashMap<PeerId, PeerVersion>,
+}
+
+#[derive(Default)]
+struct EquivocationState {
+	per_relay_parent: HashMap<Hash, RelayParentState>,
+	/// Relay parents in the order they were first seen, used for pruning.
+	relay_parents: VecDeque<Hash>,
+}
+
+impl EquivocationState {
+	fn relay_parent_mut(&mut self, relay_parent: Hash) -> &mut RelayParentState {
+		if !self.per_relay_parent.contains_key(&relay_parent) {
+			if self.relay_parents.len() >= MAX_TRACKED_RELAY_PARENTS {
+				if let Some(oldest) = self.relay_parents.pop_front() {
+					self.per_relay_parent.remove(&oldest);
+				}
+			}
+			self.relay_parents.push_back(relay_parent);
+		}
+		self.per_relay_parent.entry(relay_parent).or_default()
+	}
+}
+
+/// Wraps around statement distribution and issues `Seconded` statements for the valid candidates
+/// seconded by the backing group, beyond the seconding limit of the node.
+#[derive(Clone)]
+struct StatementEquivocator<Spawner> {
+	spawner: Spawner,
+	keystore: KeystorePtr,
+	distribution: Bernoulli,
+	state: Arc<Mutex<EquivocationState>>,
+}
+
+impl<Spawner> StatementEquivocator<Spawner>
+where
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	/// Returns the candidates to equivocate on at `relay_parent`, marking them as seconded.
+	fn candidates_to_second(
+		&self,
+		relay_parent_state: &mut RelayParentState,
+	) -> Vec<CandidateHash> {
+		if !relay_parent_state.locally_seconded {
+			return Vec::new()
+		}
+
+		let candidates = relay_parent_state
+			.seconded_by_group
+			.difference(&relay_parent_state.seconded)
+			.copied()
+			.filter(|_| self.distribution.sample(&mut rand::thread_rng()))
+			.collect::<Vec<_>>();
+		relay_parent_state.seconded.extend(candidates.iter().copied());
+		candidates
+	}
+
+	/// Sign `Seconded` statements for `candidates` and send them to `peers`.
+	fn second<Sender>(
+		&self,
+		sender: &Sender,
+		relay_parent: Hash,
+		candidates: Vec<CandidateHash>,
+		peers: HashMap<PeerId, PeerVersion>,
+	) where
+		Sender: overseer::StatementDistributionSenderTrait + Clone + Send + 'static,
+	{
+		if candidates.is_empty() || peers.is_empty() {
+			return
+		}
+
+		let keystore = self.keystore.clone();
+		let mut sender = sender.clone();
+		self.spawner.spawn(
+			"malus-equivocate-statements",
+			Some("malus"),
+			Box::pin(async move {
+				let session_index =
+					match request_session_index_for_child(relay_parent, &mut sender).await.await {
+						Ok(Ok(session_index)) => session_index,
+						_ => {
+							gum::error!(
+								target: MALUS,
+								"😈 Failed to fetch session index, not equivocating."
+							);
+							return
+						},
+					};
+				let validators = match request_validators(relay_parent, &mut sender).await.await {
+					Ok(Ok(validators)) => validators,
+					_ => {
+						gum::error!(target: MALUS, "😈 Failed to fetch validators, not equivocating.");
+						return
+					},
+				};
+				let Some((validator_id, validator_index)) =
+					signing_key_and_index(&validators, &keystore)
+				else {
+					gum::error!(target: MALUS, "😈 Not a validator, not equivocating.");
+					return
+				};
+				let context = SigningContext { parent_hash: relay_parent, session_index };
+
+				let mut messages = Vec::new();
+				for candidate_hash in candidates {
+					let statement: UncheckedSignedStatement = match SignedStatement::sign(
+						&keystore,
+						CompactStatement::Seconded(candidate_hash),
+						&context,
+						validator_index,
+						&validator_id,
+					) {
+						Ok(Some(statement)) => statement.into_unchecked(),
+						_ => {
+							gum::error!(
+								target: MALUS,
+								"😈 Failed to sign statement, not equivocating."
+							);
+							continue
+						},
+					};
+
+					gum::info!(
+						target: MALUS,
+						?relay_parent,
+						?candidate_hash,
+						"😈 Seconding candidate beyond the seconding limit.",
+					);
+
+					for (version, peers) in [PeerVersion::V2, PeerVersion::V3].map(|version| {
+						let peers = peers
+							.iter()
+							.filter(|(_, v)| **v == version)
+							.map(|(peer, _)| *peer)
+							.collect::<Vec<_>>();
+						(version, peers)
+					}) {
+						if peers.is_empty() {
+							continue
+						}
+						let message: VersionedValidationProtocol = match version {
+							PeerVersion::V2 => Versioned::V2(
+								protocol_v2::StatementDistributionMessage::Statement(
+									relay_parent,
+									statement.clone(),
+								)
+								.into(),
+							),
+							PeerVersion::V3 => Versioned::V3(
+								protocol_v3::StatementDistributionMessage::Statement(
+									relay_parent,
+									statement.clone(),
+								)
+								.into(),
+							),
+						};
+						messages.push((peers, message));
+					}
+				}
+
+				sender
+					.send_message(NetworkBridgeTxMessage::SendValidationMessages(messages))
+					.await;
+			}),
+		);
+	}
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for StatementEquivocator<Spawner>
+where
+	Sender: overseer::StatementDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = StatementDistributionMessage;
+
+	/// Track the candidates seconded locally and by the backing group, and equivocate once both
+	/// are known for a relay parent. All messages are passed on unchanged.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		let (relay_parent, candidates, peers) = match msg {
+			FromOrchestra::Communication {
+				msg: StatementDistributionMessage::Share(relay_parent, ref statement),
+			} => {
+				let StatementWithPVD::Seconded(candidate, _) = statement.payload() else {
+					return Some(msg)
+				};
+
+				let mut state = self.state.lock().expect("poisoned lock");
+				let relay_parent_state = state.relay_parent_mut(relay_parent);
+				relay_parent_state.locally_seconded = true;
+				relay_parent_state.seconded.insert(candidate.hash());
+
+				(
+					relay_parent,
+					self.candidates_to_second(relay_parent_state),
+					relay_parent_state.peers.clone(),
+				)
+			},
+			FromOrchestra::Communication {
+				msg:
+					StatementDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::PeerMessage(
+						ref peer,
+						ref message,
+					)),
+			} => {
+				let (relay_parent, statement, version) = match message {
+					Versioned::V2(protocol_v2::StatementDistributionMessage::Statement(
+						relay_parent,
+						statement,
+					)) => (*relay_parent, statement, PeerVersion::V2),
+					Versioned::V3(protocol_v3::StatementDistributionMessage::Statement(
+						relay_parent,
+						statement,
+					)) => (*relay_parent, statement, PeerVersion::V3),
+					_ => return Some(msg),
+				};
+
+				let mut state = self.state.lock().expect("poisoned lock");
+				let relay_parent_state = state.relay_parent_mut(relay_parent);
+				relay_parent_state.peers.insert(*peer, version);
+				if let CompactStatement::Seconded(candidate_hash) = statement.unchecked_payload() {
+					relay_parent_state.seconded_by_group.insert(*candidate_hash);
+				}
+
+				(
+					relay_parent,
+					self.candidates_to_second(relay_parent_state),
+					relay_parent_state.peers.clone(),
+				)
+			},
+			_ => return Some(msg),
+		};
+
+		self.second(subsystem_sender, relay_parent, candidates, peers);
+
+		Some(msg)
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct EquivocateStatementsOptions {
+	/// Determines the percentage of the candidates seconded by the backing group which are
+	/// seconded by the node as well, beyond its seconding limit. Defaults to 100%.
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// EquivocateStatements implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct EquivocateStatements {
+	/// The probability of seconding a candidate of the backing group.
+	pub percentage: u8,
+}
+
+impl OverseerGen for EquivocateStatements {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that seconds {}% of the candidates seconded by its backing group.",
+			&self.percentage,
+		);
+
+		let ext_args =
+			ext_args.expect("Extended arguments required to build validator overseer are provided");
+		let statement_equivocator = StatementEquivocator {
+			spawner: SpawnGlue(args.spawner.clone()),
+			keystore: ext_args.keystore.clone(),
+			distribution: Bernoulli::new(f64::from(self.percentage) / 100.0)
+				.expect("Invalid probability! Percentage must be in range [0..=100]."),
+			state: Default::default(),
+		};
+
+		validator_overseer_builder(args, ext_args)?
+			.replace_statement_distribution(move |sd| {
+				InterceptedSubsystem::new(sd, statement_equivocator)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,181 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that backs candidates and then refuses to serve their data.
+//!
+//! This malus variant behaves honestly in backing, bitfield signing and approval voting. The
+//! maliciousness comes from the availability store pretending to have no chunks and no
+//! available data of the candidates the node backed, so neither chunk fetching nor any of the
+//! recovery strategies can get them from this node. Availability queries used for bitfield
+//! signing are answered honestly, the node still claims the data is available.
+//!
+//! The set of withheld candidates is never pruned.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::CandidateHash;
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use rand::distributions::{Bernoulli, Distribution};
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Wraps around the availability store and hides the data of backed candidates.
+#[derive(Clone)]
+struct ChunkWithholder {
+	distribution: Bernoulli,
+	/// Candidates backed by this node whose data is withheld.
+	withheld: Arc<Mutex<HashSet<CandidateHash>>>,
+}
+
+impl ChunkWithholder {
+	fn new(percentage: f64) -> Self {
+		let distribution = Bernoulli::new(percentage / 100.0)
+			.expect("Invalid probability! Percentage must be in range [0..=100].");
+		Self { distribution, withheld: Default::default() }
+	}
+
+	fn is_withheld(&self, candidate_hash: &CandidateHash) -> bool {
+		self.withheld.lock().expect("poisoned lock").contains(candidate_hash)
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ChunkWithholder
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	/// Note the candidates stored by backing and answer data queries about them as if nothing
+	/// was stored.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			// Only backing stores the full available data.
+			msg @ FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::StoreAvailableData { candidate_hash, .. },
+			} => {
+				if self.distribution.sample(&mut rand::thread_rng()) {
+					gum::info!(
+						target: MALUS,
+						?candidate_hash,
+						"😈 Backed candidate, its data will be withheld.",
+					);
+					self.withheld.lock().expect("poisoned lock").insert(candidate_hash);
+				}
+				Some(msg)
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunk(candidate_hash, validator_index, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(
+					target: MALUS,
+					?candidate_hash,
+					?validator_index,
+					"😈 Withholding chunk.",
+				);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAllChunks(candidate_hash, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(target: MALUS, ?candidate_hash, "😈 Withholding all chunks.");
+				let _ = tx.send(Vec::new());
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAvailableData(candidate_hash, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(target: MALUS, ?candidate_hash, "😈 Withholding available data.");
+				let _ = tx.send(None);
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdAvailabilityChunksOptions {
+	/// Determines the percentage of backed candidates whose data is withheld.
+	/// Defaults to 100% of backed candidates.
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdAvailabilityChunks implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdAvailabilityChunks {
+	/// The probability of withholding the data of a backed candidate.
+	pub percentage: u8,
+}
+
+impl OverseerGen for WithholdAvailabilityChunks {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that withholds the data of {}% of the candidates it backs.",
+			&self.percentage,
+		);
+
+		let chunk_withholder = ChunkWithholder::new(f64::from(self.percentage));
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_store(move |av_store| {
+			InterceptedSubsystem::new(av_store, chunk_withholder)
+		})
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
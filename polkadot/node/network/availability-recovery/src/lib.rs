@@ -37,8 +37,8 @@ use futures::{
 use sc_network::ProtocolName;
 use schnellru::{ByLength, LruMap};
 use task::{
-	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
-	FetchSystematicChunksParams,
+	FetchChunks, FetchChunksAdaptive, FetchChunksAdaptiveParams, FetchChunksParams, FetchFull,
+	FetchFullParams, FetchSystematicChunks, FetchSystematicChunksParams, PeerStats,
 };
 
 use polkadot_erasure_coding::{
@@ -102,6 +102,9 @@ pub enum RecoveryStrategyKind {
 	/// We try the backing group first if PoV size is lower than specified, then fallback to
 	/// systematic chunks. Regular chunk recovery as a last resort.
 	BackersFirstIfSizeLowerThenSystematicChunks(usize),
+	/// We try the backing group first if PoV size is lower than specified, then fallback to
+	/// adaptive chunk recovery, which requests systematic and regular chunks concurrently.
+	BackersFirstIfSizeLowerThenAdaptiveChunks(usize),
 
 	/// The following variants are only helpful for integration tests.
 	///
@@ -117,6 +120,9 @@ pub enum RecoveryStrategyKind {
 	/// Always recover using systematic chunks, fall back to regular chunks.
 	#[allow(dead_code)]
 	SystematicChunks,
+	/// Always recover using adaptive chunk recovery.
+	#[allow(dead_code)]
+	AdaptiveChunks,
 }
 
 /// The Availability Recovery Subsystem.
@@ -299,6 +305,9 @@ struct State {
 
 	/// Cached runtime info.
 	runtime_info: RuntimeInfo,
+
+	/// Chunk request statistics of the peers, used to pick the validators to recover from.
+	peer_stats: PeerStats,
 }
 
 impl Default for State {
@@ -308,6 +317,7 @@ impl Default for State {
 			live_block: (0, Hash::default()),
 			availability_lru: LruMap::new(ByLength::new(LRU_SIZE)),
 			runtime_info: RuntimeInfo::new(None),
+			peer_stats: PeerStats::default(),
 		}
 	}
 }
@@ -421,6 +431,9 @@ async fn handle_recover<Context>(
 						RecoveryStrategyKind::BackersFirstIfSizeLower(fetch_chunks_threshold) |
 						RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(
 							fetch_chunks_threshold,
+						) |
+						RecoveryStrategyKind::BackersFirstIfSizeLowerThenAdaptiveChunks(
+							fetch_chunks_threshold,
 						) => {
 							// Get our own chunk size to get an estimate of the PoV size.
 							let chunk_size: Result<Option<usize>> =
@@ -454,6 +467,10 @@ async fn handle_recover<Context>(
 							RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_),
 							true,
 						) |
+						(
+							RecoveryStrategyKind::BackersFirstIfSizeLowerThenAdaptiveChunks(_),
+							true,
+						) |
 						(RecoveryStrategyKind::BackersThenSystematicChunks, _) =>
 							recovery_strategies.push_back(Box::new(FetchFull::new(
 								FetchFullParams { validators: backing_validators.to_vec() },
@@ -474,15 +491,24 @@ async fn handle_recover<Context>(
 				false
 			};
 
+			let adaptive_recovery = matches!(
+				recovery_strategy_kind,
+				RecoveryStrategyKind::AdaptiveChunks |
+					RecoveryStrategyKind::BackersFirstIfSizeLowerThenAdaptiveChunks(_)
+			);
+			// Whether regular chunk recovery is already covered by the adaptive strategy.
+			let mut fetches_regular_chunks = false;
+
 			// We can only attempt systematic recovery if we received the core index of the
 			// candidate and chunk mapping is enabled.
 			if let Some(core_index) = maybe_core_index {
-				if matches!(
-					recovery_strategy_kind,
-					RecoveryStrategyKind::BackersThenSystematicChunks |
-						RecoveryStrategyKind::SystematicChunks |
-						RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_)
-				) && chunk_mapping_enabled
+				if (adaptive_recovery ||
+					matches!(
+						recovery_strategy_kind,
+						RecoveryStrategyKind::BackersThenSystematicChunks |
+							RecoveryStrategyKind::SystematicChunks |
+							RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_)
+					)) && chunk_mapping_enabled
 				{
 					let chunk_indices =
 						availability_chunk_indices(Some(node_features), n_validators, core_index)?;
@@ -512,22 +538,35 @@ async fn handle_recover<Context>(
 						})
 						.collect();
 
-					recovery_strategies.push_back(Box::new(FetchSystematicChunks::new(
-						FetchSystematicChunksParams {
-							validators,
-							backers: backer_group.map(|v| v.to_vec()).unwrap_or_else(|| vec![]),
-						},
-					)));
+					let backers = backer_group.map(|v| v.to_vec()).unwrap_or_else(|| vec![]);
+
+					if adaptive_recovery {
+						recovery_strategies.push_back(Box::new(FetchChunksAdaptive::new(
+							FetchChunksAdaptiveParams {
+								systematic_validators: validators,
+								backers,
+								n_validators,
+							},
+						)));
+						fetches_regular_chunks = true;
+					} else {
+						recovery_strategies.push_back(Box::new(FetchSystematicChunks::new(
+							FetchSystematicChunksParams { validators, backers },
+						)));
+					}
 				}
 			}
 
-			recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
-				n_validators: session_info.validators.len(),
-			})));
+			if !fetches_regular_chunks {
+				recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
+					n_validators: session_info.validators.len(),
+				})));
+			}
 
 			let session_info = session_info.clone();
 
 			let n_validators = session_info.validators.len();
+			let peer_stats = state.peer_stats.clone();
 
 			launch_recovery_task(
 				state,
@@ -549,6 +588,7 @@ async fn handle_recover<Context>(
 					req_v2_protocol_name,
 					chunk_mapping_enabled,
 					erasure_task_tx,
+					peer_stats,
 				},
 			)
 			.await
@@ -645,6 +685,19 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Use adaptive chunk recovery instead of systematic chunk recovery for large POVs: validators
+	/// are requested in the order of their past chunk request latency and failures, and regular
+	/// chunks are requested concurrently with the systematic ones.
+	pub fn with_adaptive_chunk_recovery(mut self) -> Self {
+		if let RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(threshold) =
+			self.recovery_strategy_kind
+		{
+			self.recovery_strategy_kind =
+				RecoveryStrategyKind::BackersFirstIfSizeLowerThenAdaptiveChunks(threshold);
+		}
+		self
+	}
+
 	/// Customise the recovery strategy kind
 	/// Currently only useful for tests.
 	#[cfg(any(test, feature = "subsystem-benchmarks"))]
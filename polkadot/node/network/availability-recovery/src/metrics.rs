@@ -36,6 +36,7 @@ struct MetricsInner {
 	/// Split by chunk type:
 	/// - `regular_chunks`
 	/// - `systematic_chunks`
+	/// - `adaptive_chunks`
 	chunk_requests_issued: CounterVec<U64>,
 
 	/// Total number of bytes recovered
@@ -108,6 +109,11 @@ struct MetricsInner {
 	/// Note: Those are only recoveries which could not get served locally already - so in other
 	/// words: Only real recoveries.
 	full_recoveries_started: Counter<U64>,
+
+	/// Number of successful recoveries of the adaptive chunks strategy.
+	///
+	/// Split by the `path` the data was recovered from (`systematic_chunks` or `regular_chunks`).
+	adaptive_recoveries_succeeded: CounterVec<U64>,
 }
 
 impl Metrics {
@@ -294,6 +300,13 @@ impl Metrics {
 			metrics.full_recoveries_started.inc()
 		}
 	}
+
+	/// The adaptive chunks strategy recovered the data.
+	pub fn on_adaptive_recovery_succeeded(&self, path: &str) {
+		if let Some(metrics) = &self.0 {
+			metrics.adaptive_recoveries_succeeded.with_label_values(&[path]).inc()
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -403,6 +416,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			adaptive_recoveries_succeeded: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_availability_recovery_adaptive_recoveries_succeeded",
+						"Total number of successful adaptive chunk recoveries, by the chunks the data was recovered from.",
+					),
+					&["path"],
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
@@ -18,11 +18,16 @@
 
 #![warn(missing_docs)]
 
+mod peer_stats;
 mod strategy;
 
-pub use self::strategy::{
-	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
-	FetchSystematicChunksParams, RecoveryStrategy, State,
+pub use self::{
+	peer_stats::PeerStats,
+	strategy::{
+		FetchChunks, FetchChunksAdaptive, FetchChunksAdaptiveParams, FetchChunksParams, FetchFull,
+		FetchFullParams, FetchSystematicChunks, FetchSystematicChunksParams, RecoveryStrategy,
+		State,
+	},
 };
 
 #[cfg(test)]
@@ -83,6 +88,9 @@ pub struct RecoveryParams {
 
 	/// Channel to the erasure task handler.
 	pub erasure_task_tx: mpsc::Sender<ErasureTask>,
+
+	/// Chunk request statistics of the peers, shared between recoveries.
+	pub peer_stats: PeerStats,
 }
 
 /// A stateful reconstruction of availability data in reference to
@@ -0,0 +1,161 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Chunk request statistics of the peers we recover from.
+//!
+//! The statistics outlive a single recovery, so that subsequent recoveries can prefer the peers
+//! that answered quickly and reliably in the past.
+
+use polkadot_primitives::{AuthorityDiscoveryId, ValidatorIndex};
+use schnellru::{ByLength, LruMap};
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+/// Number of peers we keep statistics for. Enough to cover the largest validator sets.
+const PEER_STATS_SIZE: u32 = 2048;
+
+/// Latency assumed for peers we never requested a chunk from.
+const UNKNOWN_PEER_LATENCY: Duration = Duration::from_millis(500);
+
+/// Penalty added to the score of a peer for each recent failure.
+const FAILURE_PENALTY: Duration = Duration::from_secs(2);
+
+/// Weight of a new latency sample in the moving average, as `1 / LATENCY_SAMPLE_WEIGHT`.
+const LATENCY_SAMPLE_WEIGHT: u32 = 4;
+
+/// Statistics of a single peer.
+#[derive(Clone, Debug)]
+struct PeerRecord {
+	/// Exponentially weighted moving average of the chunk request latency.
+	avg_latency: Duration,
+	/// Number of recent failed chunk requests. Decremented on each successful request.
+	failures: u32,
+}
+
+impl Default for PeerRecord {
+	fn default() -> Self {
+		Self { avg_latency: UNKNOWN_PEER_LATENCY, failures: 0 }
+	}
+}
+
+impl PeerRecord {
+	fn score(&self) -> Duration {
+		self.avg_latency.saturating_add(FAILURE_PENALTY.saturating_mul(self.failures))
+	}
+}
+
+/// Shared handle to the chunk request statistics of all peers, lower scores are better.
+#[derive(Clone)]
+pub struct PeerStats(Arc<Mutex<LruMap<AuthorityDiscoveryId, PeerRecord>>>);
+
+impl Default for PeerStats {
+	fn default() -> Self {
+		Self(Arc::new(Mutex::new(LruMap::new(ByLength::new(PEER_STATS_SIZE)))))
+	}
+}
+
+impl PeerStats {
+	/// A chunk request to `peer` succeeded after `latency`.
+	pub fn note_success(&self, peer: &AuthorityDiscoveryId, latency: Duration) {
+		let mut records = self.0.lock().expect("poisoned lock");
+		match records.get(peer) {
+			Some(record) => {
+				record.avg_latency = (record.avg_latency * (LATENCY_SAMPLE_WEIGHT - 1) + latency) /
+					LATENCY_SAMPLE_WEIGHT;
+				record.failures = record.failures.saturating_sub(1);
+			},
+			None => {
+				records.insert(peer.clone(), PeerRecord { avg_latency: latency, failures: 0 });
+			},
+		}
+	}
+
+	/// A chunk request to `peer` failed or did not return a valid chunk.
+	pub fn note_failure(&self, peer: &AuthorityDiscoveryId) {
+		let mut records = self.0.lock().expect("poisoned lock");
+		match records.get(peer) {
+			Some(record) => record.failures = record.failures.saturating_add(1),
+			None => {
+				records.insert(peer.clone(), PeerRecord { failures: 1, ..Default::default() });
+			},
+		}
+	}
+
+	/// Sort `validators` from the worst to the best scoring peer.
+	///
+	/// Strategies pop validators from the back of their queue, so the best peers are requested
+	/// first.
+	pub fn sort_worst_first(&self, validators: &mut [(AuthorityDiscoveryId, ValidatorIndex)]) {
+		let records = self.0.lock().expect("poisoned lock");
+		validators.sort_by_cached_key(|(peer, _)| std::cmp::Reverse(score(&records, peer)));
+	}
+
+	#[cfg(test)]
+	fn score(&self, peer: &AuthorityDiscoveryId) -> Duration {
+		score(&self.0.lock().expect("poisoned lock"), peer)
+	}
+}
+
+/// The expected cost of requesting a chunk from `peer`.
+fn score(
+	records: &LruMap<AuthorityDiscoveryId, PeerRecord>,
+	peer: &AuthorityDiscoveryId,
+) -> Duration {
+	records.peek(peer).map(PeerRecord::score).unwrap_or(UNKNOWN_PEER_LATENCY)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_keyring::Sr25519Keyring;
+
+	fn peer(keyring: Sr25519Keyring) -> AuthorityDiscoveryId {
+		keyring.public().into()
+	}
+
+	#[test]
+	fn scores_follow_latency_and_failures() {
+		let stats = PeerStats::default();
+		let (alice, bob, charlie) =
+			(peer(Sr25519Keyring::Alice), peer(Sr25519Keyring::Bob), peer(Sr25519Keyring::Charlie));
+
+		assert_eq!(stats.score(&alice), UNKNOWN_PEER_LATENCY);
+
+		stats.note_success(&alice, Duration::from_millis(100));
+		assert_eq!(stats.score(&alice), Duration::from_millis(100));
+		stats.note_success(&alice, Duration::from_millis(500));
+		assert_eq!(stats.score(&alice), Duration::from_millis(200));
+
+		stats.note_failure(&bob);
+		assert_eq!(stats.score(&bob), UNKNOWN_PEER_LATENCY + FAILURE_PENALTY);
+		stats.note_success(&bob, UNKNOWN_PEER_LATENCY);
+		assert_eq!(stats.score(&bob), UNKNOWN_PEER_LATENCY);
+
+		stats.note_failure(&charlie);
+		let mut validators = vec![
+			(alice.clone(), ValidatorIndex(0)),
+			(charlie.clone(), ValidatorIndex(1)),
+			(peer(Sr25519Keyring::Dave), ValidatorIndex(2)),
+		];
+		stats.sort_worst_first(&mut validators);
+		assert_eq!(
+			validators.into_iter().map(|(_, v_index)| v_index).collect::<Vec<_>>(),
+			vec![ValidatorIndex(1), ValidatorIndex(2), ValidatorIndex(0)]
+		);
+	}
+}
@@ -0,0 +1,419 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	futures_undead::FuturesUndead,
+	task::{
+		strategy::{
+			is_unavailable, recover_from_chunks, recover_from_systematic_chunks, OngoingRequests,
+			N_PARALLEL, REGULAR_CHUNKS_REQ_RETRY_LIMIT, SYSTEMATIC_CHUNKS_REQ_RETRY_LIMIT,
+		},
+		RecoveryParams, RecoveryStrategy, State,
+	},
+	LOG_TARGET,
+};
+
+use polkadot_node_primitives::AvailableData;
+use polkadot_node_subsystem::{overseer, RecoveryError};
+use polkadot_primitives::{AuthorityDiscoveryId, ChunkIndex, ValidatorIndex};
+
+use std::collections::{HashSet, VecDeque};
+
+/// Chunk type label of a recovery that reconstructed the data from systematic chunks.
+const SYSTEMATIC_CHUNKS: &str = "systematic_chunks";
+/// Chunk type label of a recovery that reconstructed the data from regular chunks.
+const REGULAR_CHUNKS: &str = "regular_chunks";
+
+/// Parameters needed for fetching chunks adaptively.
+pub struct FetchChunksAdaptiveParams {
+	/// Validators that hold the systematic chunks.
+	pub systematic_validators: Vec<(ChunkIndex, ValidatorIndex)>,
+	/// Validators in the backing group, to be used as a backup for requesting chunks.
+	pub backers: Vec<ValidatorIndex>,
+	/// Number of validators.
+	pub n_validators: usize,
+}
+
+/// `RecoveryStrategy` that requests the systematic chunks and regular chunks from the other
+/// validators concurrently.
+///
+/// Systematic recovery is preferred since it doesn't need the costly reconstruction: once enough
+/// chunks for regular recovery arrived, the strategy keeps waiting for the systematic chunks still
+/// in flight, until they either arrive, fail or time out.
+///
+/// Validators are requested in the order of their past chunk request latency and failures, see
+/// `PeerStats`.
+pub struct FetchChunksAdaptive {
+	/// Validators that hold the systematic chunks.
+	systematic_validators: Vec<(ChunkIndex, ValidatorIndex)>,
+	/// Backers to be used as a backup.
+	backers: Vec<ValidatorIndex>,
+	/// Number of validators.
+	n_validators: usize,
+	/// How many requests have been unsuccessful so far.
+	error_count: usize,
+	/// Total number of responses that have been received, including failed ones.
+	total_received_responses: usize,
+	/// Collection of in-flight requests.
+	requesting_chunks: OngoingRequests,
+}
+
+impl FetchChunksAdaptive {
+	/// Instantiate a new adaptive chunks strategy.
+	pub fn new(params: FetchChunksAdaptiveParams) -> Self {
+		Self {
+			systematic_validators: params.systematic_validators,
+			backers: params.backers,
+			n_validators: params.n_validators,
+			error_count: 0,
+			total_received_responses: 0,
+			requesting_chunks: FuturesUndead::new(),
+		}
+	}
+
+	/// Desired number of parallel requests.
+	///
+	/// Covers the missing systematic chunks, plus the missing chunks for regular recovery with
+	/// some slack for the error rate. No more requests are needed once regular recovery is
+	/// possible.
+	fn get_desired_request_count(
+		&self,
+		chunk_count: usize,
+		systematic_chunk_count: usize,
+		threshold: usize,
+		systematic_threshold: usize,
+	) -> usize {
+		let remaining_chunks = threshold.saturating_sub(chunk_count);
+		if remaining_chunks == 0 {
+			return 0
+		}
+
+		let remaining_systematic_chunks =
+			systematic_threshold.saturating_sub(systematic_chunk_count);
+		let inv_error_rate =
+			self.total_received_responses.checked_div(self.error_count).unwrap_or(0);
+		std::cmp::min(
+			N_PARALLEL,
+			remaining_systematic_chunks +
+				remaining_chunks +
+				remaining_chunks.checked_div(inv_error_rate).unwrap_or(0),
+		)
+	}
+
+	async fn attempt_recovery(
+		&mut self,
+		chunk_type: &'static str,
+		state: &mut State,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		let res = if chunk_type == SYSTEMATIC_CHUNKS {
+			recover_from_systematic_chunks(
+				chunk_type,
+				common_params.systematic_threshold,
+				state,
+				common_params,
+			)
+			.await
+		} else {
+			recover_from_chunks(chunk_type, state, common_params).await
+		};
+
+		if res.is_ok() {
+			common_params.metrics.on_adaptive_recovery_succeeded(chunk_type);
+		}
+
+		res
+	}
+}
+
+/// Merge the systematic and regular validator queues, both sorted from the worst to the best
+/// peer, so that popping from the back alternates between them.
+fn interleave<T>(systematic: Vec<T>, regular: Vec<T>) -> VecDeque<T> {
+	let mut systematic = systematic.into_iter().rev();
+	let mut regular = regular.into_iter().rev();
+	let mut queue = VecDeque::with_capacity(systematic.len() + regular.len());
+	loop {
+		match (systematic.next(), regular.next()) {
+			(None, None) => break,
+			(s, r) => {
+				queue.extend(s);
+				queue.extend(r);
+			},
+		}
+	}
+	queue.make_contiguous().reverse();
+	queue
+}
+
+#[async_trait::async_trait]
+impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
+	for FetchChunksAdaptive
+{
+	fn display_name(&self) -> &'static str {
+		"Fetch chunks adaptively"
+	}
+
+	fn strategy_type(&self) -> &'static str {
+		"adaptive_chunks"
+	}
+
+	async fn run(
+		mut self: Box<Self>,
+		state: &mut State,
+		sender: &mut Sender,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		let mut local_validators = HashSet::new();
+		let mut invalid_local_systematic_chunk = false;
+
+		// First query the store for any chunks we've got.
+		if !common_params.bypass_availability_store {
+			let local_chunk_indices = state.populate_from_av_store(common_params, sender).await;
+
+			for (v_index, our_c_index) in &local_chunk_indices {
+				local_validators.insert(*v_index);
+				// If we are among the systematic validators but hold an invalid chunk, we cannot
+				// perform the systematic recovery.
+				invalid_local_systematic_chunk |=
+					self.systematic_validators.iter().any(|(c_index, _)| c_index == our_c_index) &&
+						!state.received_chunks.contains_key(our_c_index);
+			}
+		}
+
+		let to_queue = |v_index: ValidatorIndex| {
+			(common_params.validator_authority_keys[v_index.0 as usize].clone(), v_index)
+		};
+		let systematic_holders: HashSet<_> =
+			self.systematic_validators.iter().map(|(_, v_index)| *v_index).collect();
+
+		// No need to query the validators that have the chunks we already received or that we know
+		// don't have the data from previous strategies.
+		let mut systematic_queue: Vec<_> = std::mem::take(&mut self.systematic_validators)
+			.into_iter()
+			.filter(|(c_index, v_index)| {
+				!state.received_chunks.contains_key(c_index) &&
+					!local_validators.contains(v_index) &&
+					state.can_retry_request(
+						&to_queue(*v_index),
+						SYSTEMATIC_CHUNKS_REQ_RETRY_LIMIT,
+					)
+			})
+			.map(|(_, v_index)| to_queue(v_index))
+			.collect();
+		let mut regular_queue: Vec<_> = (0..self.n_validators)
+			.map(|v_index| ValidatorIndex(v_index as u32))
+			.filter(|v_index| {
+				!systematic_holders.contains(v_index) &&
+					!local_validators.contains(v_index) &&
+					!state.received_chunks.values().any(|c| v_index == &c.validator_index) &&
+					state.can_retry_request(&to_queue(*v_index), REGULAR_CHUNKS_REQ_RETRY_LIMIT)
+			})
+			.map(to_queue)
+			.collect();
+
+		common_params.peer_stats.sort_worst_first(&mut systematic_queue);
+		common_params.peer_stats.sort_worst_first(&mut regular_queue);
+
+		let mut validators_queue = interleave(systematic_queue, regular_queue);
+		let mut backers: Vec<_> = std::mem::take(&mut self.backers)
+			.into_iter()
+			.map(|validator_index| {
+				common_params.validator_authority_keys[validator_index.0 as usize].clone()
+			})
+			.collect();
+
+		let strategy_type = RecoveryStrategy::<Sender>::strategy_type(&*self);
+
+		loop {
+			let systematic_chunk_count =
+				state.systematic_chunk_count(common_params.systematic_threshold);
+
+			// Prefer systematic recovery, it doesn't need the costly reconstruction.
+			if systematic_chunk_count >= common_params.systematic_threshold {
+				return self.attempt_recovery(SYSTEMATIC_CHUNKS, state, common_params).await
+			}
+
+			// Only fall back to regular recovery once the missing systematic chunks can't arrive
+			// in time: their requests all concluded or timed out, or they can't be received at all.
+			let queued_systematic_count = validators_queue
+				.iter()
+				.filter(|(_, v_index)| systematic_holders.contains(v_index))
+				.count();
+			let systematic_unavailable = invalid_local_systematic_chunk ||
+				is_unavailable(
+					systematic_chunk_count,
+					self.requesting_chunks.total_len(),
+					queued_systematic_count,
+					common_params.systematic_threshold,
+				);
+			if state.chunk_count() >= common_params.threshold &&
+				(self.requesting_chunks.len() == 0 || systematic_unavailable)
+			{
+				return self.attempt_recovery(REGULAR_CHUNKS, state, common_params).await
+			}
+
+			if is_unavailable(
+				state.chunk_count(),
+				self.requesting_chunks.total_len(),
+				validators_queue.len(),
+				common_params.threshold,
+			) {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					received = %state.chunk_count(),
+					requesting = %self.requesting_chunks.len(),
+					total_requesting = %self.requesting_chunks.total_len(),
+					n_validators = %common_params.n_validators,
+					"Data recovery from chunks is not possible",
+				);
+
+				return Err(RecoveryError::Unavailable)
+			}
+
+			let already_requesting_count = self.requesting_chunks.len();
+			let desired_requests_count = self
+				.get_desired_request_count(
+					state.chunk_count(),
+					systematic_chunk_count,
+					common_params.threshold,
+					common_params.systematic_threshold,
+				)
+				.max(already_requesting_count);
+			gum::debug!(
+				target: LOG_TARGET,
+				?common_params.candidate_hash,
+				?desired_requests_count,
+				error_count= ?self.error_count,
+				total_received = ?self.total_received_responses,
+				?already_requesting_count,
+				"Requesting availability chunks for a candidate",
+			);
+
+			state
+				.launch_parallel_chunk_requests(
+					strategy_type,
+					common_params,
+					sender,
+					desired_requests_count,
+					&mut validators_queue,
+					&mut self.requesting_chunks,
+				)
+				.await;
+
+			let (total_responses, error_count) = state
+				.wait_for_chunks(
+					strategy_type,
+					common_params,
+					REGULAR_CHUNKS_REQ_RETRY_LIMIT,
+					&mut validators_queue,
+					&mut self.requesting_chunks,
+					&mut backers,
+					|unrequested_validators,
+					 in_flight_reqs,
+					 chunk_count,
+					 systematic_chunk_count| {
+						systematic_chunk_count >= common_params.systematic_threshold ||
+							(chunk_count >= common_params.threshold &&
+								(invalid_local_systematic_chunk ||
+									is_unavailable(
+										systematic_chunk_count,
+										in_flight_reqs,
+										unrequested_validators,
+										common_params.systematic_threshold,
+									))) || is_unavailable(
+							chunk_count,
+							in_flight_reqs,
+							unrequested_validators,
+							common_params.threshold,
+						)
+					},
+				)
+				.await;
+
+			self.total_received_responses += total_responses;
+			self.error_count += error_count;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_erasure_coding::{recovery_threshold, systematic_recovery_threshold};
+
+	#[test]
+	fn test_get_desired_request_count() {
+		let n_validators = 100;
+		let threshold = recovery_threshold(n_validators).unwrap();
+		let systematic_threshold = systematic_recovery_threshold(n_validators).unwrap();
+
+		let mut task = FetchChunksAdaptive::new(FetchChunksAdaptiveParams {
+			systematic_validators: vec![(1.into(), 1.into()); systematic_threshold],
+			backers: vec![],
+			n_validators,
+		});
+
+		// Both the systematic and the regular chunks are requested, up to `N_PARALLEL`.
+		assert_eq!(
+			task.get_desired_request_count(0, 0, threshold, systematic_threshold),
+			N_PARALLEL
+		);
+		// 10 missing systematic chunks and 4 missing chunks, without errors.
+		assert_eq!(
+			task.get_desired_request_count(
+				threshold - 4,
+				systematic_threshold - 10,
+				threshold,
+				systematic_threshold
+			),
+			14
+		);
+
+		// With one failed response out of two, half again as many chunks as missing are requested
+		// for regular recovery.
+		task.error_count = 1;
+		task.total_received_responses = 2;
+		assert_eq!(
+			task.get_desired_request_count(
+				threshold - 4,
+				systematic_threshold - 10,
+				threshold,
+				systematic_threshold
+			),
+			16
+		);
+
+		// Nothing more is requested once regular recovery is possible.
+		assert_eq!(
+			task.get_desired_request_count(
+				threshold,
+				systematic_threshold - 10,
+				threshold,
+				systematic_threshold
+			),
+			0
+		);
+	}
+
+	#[test]
+	fn interleave_alternates_between_queues() {
+		let mut queue = interleave(vec![3, 2, 1], vec![30, 20, 10, 0]);
+		let popped: Vec<_> = std::iter::from_fn(|| queue.pop_back()).collect();
+		assert_eq!(popped, vec![1, 10, 2, 20, 3, 30, 0]);
+	}
+}
@@ -18,19 +18,18 @@ use crate::{
 	futures_undead::FuturesUndead,
 	task::{
 		strategy::{
-			do_post_recovery_check, is_unavailable, OngoingRequests, N_PARALLEL,
+			is_unavailable, recover_from_chunks, OngoingRequests, N_PARALLEL,
 			REGULAR_CHUNKS_REQ_RETRY_LIMIT,
 		},
 		RecoveryParams, State,
 	},
-	ErasureTask, RecoveryStrategy, LOG_TARGET,
+	RecoveryStrategy, LOG_TARGET,
 };
 
 use polkadot_node_primitives::AvailableData;
 use polkadot_node_subsystem::{overseer, RecoveryError};
 use polkadot_primitives::ValidatorIndex;
 
-use futures::{channel::oneshot, SinkExt};
 use rand::seq::SliceRandom;
 use std::collections::VecDeque;
 
@@ -101,67 +100,6 @@ impl FetchChunks {
 			remaining_chunks + remaining_chunks.checked_div(inv_error_rate).unwrap_or(0),
 		)
 	}
-
-	async fn attempt_recovery<Sender: overseer::AvailabilityRecoverySenderTrait>(
-		&mut self,
-		state: &mut State,
-		common_params: &RecoveryParams,
-	) -> Result<AvailableData, RecoveryError> {
-		let recovery_duration =
-			common_params
-				.metrics
-				.time_erasure_recovery(RecoveryStrategy::<Sender>::strategy_type(self));
-
-		// Send request to reconstruct available data from chunks.
-		let (avilable_data_tx, available_data_rx) = oneshot::channel();
-
-		let mut erasure_task_tx = common_params.erasure_task_tx.clone();
-		erasure_task_tx
-			.send(ErasureTask::Reconstruct(
-				common_params.n_validators,
-				// Safe to leave an empty vec in place, as we're stopping the recovery process if
-				// this reconstruct fails.
-				std::mem::take(&mut state.received_chunks)
-					.into_iter()
-					.map(|(c_index, chunk)| (c_index, chunk.chunk))
-					.collect(),
-				avilable_data_tx,
-			))
-			.await
-			.map_err(|_| RecoveryError::ChannelClosed)?;
-
-		let available_data_response =
-			available_data_rx.await.map_err(|_| RecoveryError::ChannelClosed)?;
-
-		match available_data_response {
-			// Attempt post-recovery check.
-			Ok(data) => do_post_recovery_check(common_params, data)
-				.await
-				.inspect_err(|_| {
-					recovery_duration.map(|rd| rd.stop_and_discard());
-				})
-				.inspect(|_| {
-					gum::trace!(
-						target: LOG_TARGET,
-						candidate_hash = ?common_params.candidate_hash,
-						erasure_root = ?common_params.erasure_root,
-						"Data recovery from chunks complete",
-					);
-				}),
-			Err(err) => {
-				recovery_duration.map(|rd| rd.stop_and_discard());
-				gum::debug!(
-					target: LOG_TARGET,
-					candidate_hash = ?common_params.candidate_hash,
-					erasure_root = ?common_params.erasure_root,
-					?err,
-					"Data recovery error",
-				);
-
-				Err(RecoveryError::Invalid)
-			},
-		}
-	}
 }
 
 #[async_trait::async_trait]
@@ -217,7 +155,12 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 			// Do this before requesting any chunks because we may have enough of them coming from
 			// past RecoveryStrategies.
 			if state.chunk_count() >= common_params.threshold {
-				return self.attempt_recovery::<Sender>(state, common_params).await
+				return recover_from_chunks(
+					RecoveryStrategy::<Sender>::strategy_type(&*self),
+					state,
+					common_params,
+				)
+				.await
 			}
 
 			if Self::is_unavailable(
@@ -16,11 +16,13 @@
 
 //! Recovery strategies.
 
+mod adaptive;
 mod chunks;
 mod full;
 mod systematic;
 
 pub use self::{
+	adaptive::{FetchChunksAdaptive, FetchChunksAdaptiveParams},
 	chunks::{FetchChunks, FetchChunksParams},
 	full::{FetchFull, FetchFullParams},
 	systematic::{FetchSystematicChunks, FetchSystematicChunksParams},
@@ -46,7 +48,7 @@ use polkadot_primitives::{AuthorityDiscoveryId, BlakeTwo256, ChunkIndex, HashT,
 use sc_network::{IfDisconnected, OutboundFailure, ProtocolName, RequestFailure};
 use std::{
 	collections::{BTreeMap, HashMap, VecDeque},
-	time::Duration,
+	time::{Duration, Instant},
 };
 
 // How many parallel chunk fetching requests should be running at once.
@@ -166,6 +168,128 @@ async fn do_post_recovery_check(
 	}
 }
 
+/// Reconstruct the available data from any `threshold` chunks received so far and check it.
+///
+/// The reconstruction runs on the erasure task pool.
+async fn recover_from_chunks(
+	chunk_type: &str,
+	state: &mut State,
+	common_params: &RecoveryParams,
+) -> Result<AvailableData, RecoveryError> {
+	let recovery_duration = common_params.metrics.time_erasure_recovery(chunk_type);
+
+	// Send request to reconstruct available data from chunks.
+	let (avilable_data_tx, available_data_rx) = oneshot::channel();
+
+	let mut erasure_task_tx = common_params.erasure_task_tx.clone();
+	erasure_task_tx
+		.send(ErasureTask::Reconstruct(
+			common_params.n_validators,
+			// Safe to leave an empty vec in place, as we're stopping the recovery process if
+			// this reconstruct fails.
+			std::mem::take(&mut state.received_chunks)
+				.into_iter()
+				.map(|(c_index, chunk)| (c_index, chunk.chunk))
+				.collect(),
+			avilable_data_tx,
+		))
+		.await
+		.map_err(|_| RecoveryError::ChannelClosed)?;
+
+	let available_data_response =
+		available_data_rx.await.map_err(|_| RecoveryError::ChannelClosed)?;
+
+	match available_data_response {
+		// Attempt post-recovery check.
+		Ok(data) => do_post_recovery_check(common_params, data)
+			.await
+			.inspect_err(|_| {
+				recovery_duration.map(|rd| rd.stop_and_discard());
+			})
+			.inspect(|_| {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					"Data recovery from chunks complete",
+				);
+			}),
+		Err(err) => {
+			recovery_duration.map(|rd| rd.stop_and_discard());
+			gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?common_params.candidate_hash,
+				erasure_root = ?common_params.erasure_root,
+				?err,
+				"Data recovery error",
+			);
+
+			Err(RecoveryError::Invalid)
+		},
+	}
+}
+
+/// Recover the available data from the first `systematic_threshold` chunks received so far and
+/// check it. This bypasses the costly erasure code reconstruction.
+async fn recover_from_systematic_chunks(
+	chunk_type: &str,
+	systematic_threshold: usize,
+	state: &State,
+	common_params: &RecoveryParams,
+) -> Result<AvailableData, RecoveryError> {
+	let recovery_duration = common_params.metrics.time_erasure_recovery(chunk_type);
+	let reconstruct_duration = common_params.metrics.time_erasure_reconstruct(chunk_type);
+	let chunks = state
+		.received_chunks
+		.range(
+			ChunkIndex(0)..
+				ChunkIndex(
+					u32::try_from(systematic_threshold)
+						.expect("validator count should not exceed u32"),
+				),
+		)
+		.map(|(_, chunk)| chunk.chunk.clone())
+		.collect::<Vec<_>>();
+
+	let available_data =
+		polkadot_erasure_coding::reconstruct_from_systematic_v1(common_params.n_validators, chunks);
+
+	match available_data {
+		Ok(data) => {
+			drop(reconstruct_duration);
+
+			// Attempt post-recovery check.
+			do_post_recovery_check(common_params, data)
+				.await
+				.inspect_err(|_| {
+					recovery_duration.map(|rd| rd.stop_and_discard());
+				})
+				.inspect(|_| {
+					gum::trace!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						erasure_root = ?common_params.erasure_root,
+						"Data recovery from systematic chunks complete",
+					);
+				})
+		},
+		Err(err) => {
+			reconstruct_duration.map(|rd| rd.stop_and_discard());
+			recovery_duration.map(|rd| rd.stop_and_discard());
+
+			gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?common_params.candidate_hash,
+				erasure_root = ?common_params.erasure_root,
+				?err,
+				"Systematic data recovery error",
+			);
+
+			Err(RecoveryError::Invalid)
+		},
+	}
+}
+
 #[async_trait::async_trait]
 /// Common trait for runnable recovery strategies.
 pub trait RecoveryStrategy<Sender: overseer::AvailabilityRecoverySenderTrait>: Send {
@@ -378,6 +502,8 @@ impl State {
 
 				let chunk_mapping_enabled = params.chunk_mapping_enabled;
 				let authority_id_clone = authority_id.clone();
+				let peer_stats = params.peer_stats.clone();
+				let started = Instant::now();
 
 				requesting_chunks.push(Box::pin(async move {
 					let _timer = timer;
@@ -424,6 +550,12 @@ impl State {
 						Err(e) => Err(e),
 					};
 
+					match &res {
+						Ok((Some(_), _)) =>
+							peer_stats.note_success(&authority_id, started.elapsed()),
+						_ => peer_stats.note_failure(&authority_id),
+					}
+
 					(authority_id, validator_index, res)
 				}));
 			} else {
@@ -683,6 +815,7 @@ mod tests {
 				req_v2_protocol_name: "/req_chunk/2".into(),
 				chunk_mapping_enabled: true,
 				erasure_task_tx,
+				peer_stats: Default::default(),
 			}
 		}
 	}
@@ -141,6 +141,20 @@ fn with_systematic_chunks(
 	)
 }
 
+/// Create a new instance of `AvailabilityRecoverySubsystem` which requests chunks adaptively.
+fn with_adaptive_chunks(
+	req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+	req_protocol_names: &ReqProtocolNames,
+	metrics: Metrics,
+) -> AvailabilityRecoverySubsystem {
+	AvailabilityRecoverySubsystem::with_recovery_strategy_kind(
+		req_receiver,
+		req_protocol_names,
+		metrics,
+		RecoveryStrategyKind::AdaptiveChunks,
+	)
+}
+
 // Deterministic genesis hash for protocol names
 const GENESIS_HASH: Hash = Hash::repeat_byte(0xff);
 
@@ -3127,3 +3141,79 @@ fn test_systematic_recovery_skipped_if_mapping_disabled() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn adaptive_recovery_requests_systematic_and_regular_chunks_concurrently() {
+	let test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let subsystem = with_adaptive_chunks(
+		request_receiver(&req_protocol_names),
+		&req_protocol_names,
+		Metrics::new_dummy(),
+	);
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				Some(test_state.core_index),
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api_session_info(&mut virtual_overseer).await;
+
+		test_state.test_runtime_api_node_features(&mut virtual_overseer).await;
+
+		let candidate_hash = test_state.candidate.hash();
+
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+
+		// Systematic and regular chunks are requested at once. The systematic chunk requests never
+		// return, so the data is recovered from the regular chunks once they time out.
+		let mut systematic_requests = 0;
+		let mut regular_requests = 0;
+		let _senders = test_state
+			.test_chunk_requests(
+				&req_protocol_names,
+				candidate_hash,
+				&mut virtual_overseer,
+				test_state.systematic_threshold() + test_state.threshold(),
+				|validator_index| {
+					if (test_state.chunks.get(validator_index).unwrap().index.0 as usize) <
+						test_state.systematic_threshold()
+					{
+						systematic_requests += 1;
+						Has::DoesNotReturn
+					} else {
+						regular_requests += 1;
+						Has::Yes
+					}
+				},
+				false,
+			)
+			.await;
+		assert_eq!(systematic_requests, test_state.systematic_threshold());
+		assert_eq!(regular_requests, test_state.threshold());
+
+		// Recovered data should match the original one.
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		virtual_overseer
+	});
+}
@@ -1652,6 +1652,13 @@ async fn handle_incoming_statement<Context>(
 			Ok(Some(s)) => s,
 			Ok(None) => return,
 			Err(rep) => {
+				gum::debug!(
+					target: LOG_TARGET,
+					?peer,
+					?relay_parent,
+					?rep,
+					"Rejected cluster statement",
+				);
 				modify_reputation(reputation, ctx.sender(), peer, rep).await;
 				return
 			},
@@ -66,6 +66,7 @@ use polkadot_overseer::SpawnGlue;
 
 #[cfg(feature = "full-node")]
 pub use {
+	polkadot_node_core_dispute_coordinator::export as dispute_export,
 	polkadot_overseer::{Handle, Overseer, OverseerConnector, OverseerHandle},
 	polkadot_primitives::runtime_api::ParachainHost,
 	relay_chain_selection::SelectRelayChain,
@@ -358,6 +359,29 @@ pub fn open_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Er
 	Ok(parachains_db)
 }
 
+/// Open the existing parachains database, without creating or upgrading it.
+///
+/// Used by offline tools, which must not modify the database of a node.
+#[cfg(feature = "full-node")]
+pub fn open_existing_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Error> {
+	let parachains_db = match db_source {
+		DatabaseSource::RocksDb { path, .. } => parachains_db::open_existing_rocksdb(path.clone())?,
+		DatabaseSource::ParityDb { path, .. } => parachains_db::open_existing_paritydb(
+			path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+		)?,
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } =>
+			if paritydb_path.is_dir() && paritydb_path.exists() {
+				parachains_db::open_existing_paritydb(
+					paritydb_path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+				)?
+			} else {
+				parachains_db::open_existing_rocksdb(rocksdb_path.clone())?
+			},
+		DatabaseSource::Custom { .. } => return Err(Error::DatabasePathRequired),
+	};
+	Ok(parachains_db)
+}
+
 #[cfg(feature = "full-node")]
 type FullSelectChain = relay_chain_selection::SelectRelayChain<FullBackend>;
 #[cfg(feature = "full-node")]
@@ -635,6 +659,8 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub hwbench: Option<sc_sysinfo::HwBench>,
 	/// Enable approval voting processing in parallel.
 	pub enable_approval_voting_parallel: bool,
+	/// Enable adaptive chunk recovery in availability recovery.
+	pub enable_adaptive_chunk_recovery: bool,
 }
 
 #[cfg(feature = "full-node")]
@@ -728,6 +754,7 @@ pub fn new_full<
 		prepare_workers_soft_max_num,
 		prepare_workers_hard_max_num,
 		enable_approval_voting_parallel,
+		enable_adaptive_chunk_recovery,
 	}: NewFullParams<OverseerGenerator>,
 ) -> Result<NewFull, Error> {
 	use polkadot_availability_recovery::FETCH_CHUNKS_THRESHOLD;
@@ -995,6 +1022,7 @@ pub fn new_full<
 			chain_selection_config,
 			fetch_chunks_threshold,
 			enable_approval_voting_parallel,
+			enable_adaptive_chunk_recovery,
 		})
 	};
 
@@ -1531,3 +1559,21 @@ fn revert_approval_voting(
 		.revert_to(hash)
 		.map_err(|err| sp_blockchain::Error::Backend(err.to_string()))
 }
+
+/// Exports the disputes of the given `sessions` stored in the parachains-db.
+///
+/// Only disputes of sessions within the dispute window are kept, the node must not be running.
+#[cfg(feature = "full-node")]
+pub fn export_disputes(
+	database: &DatabaseSource,
+	sessions: std::ops::RangeInclusive<polkadot_primitives::SessionIndex>,
+) -> Result<Vec<dispute_export::DisputeRecord>, Error> {
+	let parachains_db = open_existing_database(database)?;
+
+	let config = DisputeCoordinatorConfig {
+		col_dispute_data: parachains_db::REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+
+	dispute_export::export_disputes(parachains_db, config, sessions)
+		.map_err(|err| sp_blockchain::Error::Backend(err.to_string()).into())
+}
@@ -145,6 +145,8 @@ pub struct ExtendedOverseerGenArgs {
 	/// Enable approval-voting-parallel subsystem and disable the standalone approval-voting and
 	/// approval-distribution subsystems.
 	pub enable_approval_voting_parallel: bool,
+	/// Recover large PoVs by requesting systematic and regular chunks concurrently.
+	pub enable_adaptive_chunk_recovery: bool,
 }
 
 /// Obtain a prepared validator `Overseer`, that is initialized with all default values.
@@ -185,6 +187,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		chain_selection_config,
 		fetch_chunks_threshold,
 		enable_approval_voting_parallel,
+		enable_adaptive_chunk_recovery,
 	}: ExtendedOverseerGenArgs,
 ) -> Result<
 	InitializedOverseerBuilder<
@@ -267,12 +270,19 @@ where
 			req_protocol_names.clone(),
 			Metrics::register(registry)?,
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::for_validator(
-			fetch_chunks_threshold,
-			available_data_req_receiver,
-			&req_protocol_names,
-			Metrics::register(registry)?,
-		))
+		.availability_recovery({
+			let availability_recovery = AvailabilityRecoverySubsystem::for_validator(
+				fetch_chunks_threshold,
+				available_data_req_receiver,
+				&req_protocol_names,
+				Metrics::register(registry)?,
+			);
+			if enable_adaptive_chunk_recovery {
+				availability_recovery.with_adaptive_chunk_recovery()
+			} else {
+				availability_recovery
+			}
+		})
 		.availability_store(AvailabilityStoreSubsystem::new(
 			parachains_db.clone(),
 			availability_config,
@@ -410,6 +420,7 @@ pub fn validator_with_parallel_overseer_builder<Spawner, RuntimeClient>(
 		chain_selection_config,
 		fetch_chunks_threshold,
 		enable_approval_voting_parallel,
+		enable_adaptive_chunk_recovery,
 	}: ExtendedOverseerGenArgs,
 ) -> Result<
 	InitializedOverseerBuilder<
@@ -491,12 +502,19 @@ where
 			req_protocol_names.clone(),
 			Metrics::register(registry)?,
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::for_validator(
-			fetch_chunks_threshold,
-			available_data_req_receiver,
-			&req_protocol_names,
-			Metrics::register(registry)?,
-		))
+		.availability_recovery({
+			let availability_recovery = AvailabilityRecoverySubsystem::for_validator(
+				fetch_chunks_threshold,
+				available_data_req_receiver,
+				&req_protocol_names,
+				Metrics::register(registry)?,
+			);
+			if enable_adaptive_chunk_recovery {
+				availability_recovery.with_adaptive_chunk_recovery()
+			} else {
+				availability_recovery
+			}
+		})
 		.availability_store(AvailabilityStoreSubsystem::new(
 			parachains_db.clone(),
 			availability_config,
@@ -54,6 +54,10 @@ pub enum Error {
 	MigrationFailed,
 	#[error("Parachain DB migration would take forever")]
 	MigrationLoop,
+	#[error("Parachains DB not found at {0:?}")]
+	NotFound(PathBuf),
+	#[error("Parachains DB has an outdated version (expected {current:?}, found {got:?}), start the node to upgrade it")]
+	OutdatedVersion { current: Version, got: Option<Version> },
 }
 
 impl From<Error> for io::Error {
@@ -92,6 +96,22 @@ pub(crate) fn try_upgrade_db(
 	Err(Error::MigrationLoop)
 }
 
+/// Check that the parachain's database exists and is at the current version, without upgrading it.
+pub(crate) fn ensure_current_version(db_path: &Path, db_kind: DatabaseKind) -> Result<(), Error> {
+	if db_path.read_dir().map_or(true, |mut d| d.next().is_none()) {
+		return Err(Error::NotFound(db_path.to_owned()))
+	}
+
+	match get_db_version(db_path)? {
+		Some(CURRENT_VERSION) => Ok(()),
+		// No version file. For `RocksDB` this is the current version.
+		None if db_kind == DatabaseKind::RocksDB => Ok(()),
+		Some(v) if v > CURRENT_VERSION =>
+			Err(Error::FutureVersion { current: CURRENT_VERSION, got: v }),
+		got => Err(Error::OutdatedVersion { current: CURRENT_VERSION, got }),
+	}
+}
+
 /// Try upgrading parachain's database to the next version.
 /// If successful, it returns the current version.
 pub(crate) fn try_upgrade_db_to_next_version(
@@ -465,6 +485,28 @@ mod tests {
 	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
 	use polkadot_primitives_test_helpers::dummy_candidate_receipt_v2;
 
+	#[test]
+	fn ensure_current_version_does_not_create_or_upgrade() {
+		let db_dir = tempfile::tempdir().unwrap();
+		let path = db_dir.path().join("parachains");
+
+		assert!(matches!(
+			ensure_current_version(&path, DatabaseKind::ParityDB),
+			Err(Error::NotFound(_))
+		));
+		assert!(!path.exists());
+
+		update_version(&path, 4).unwrap();
+		assert!(matches!(
+			ensure_current_version(&path, DatabaseKind::ParityDB),
+			Err(Error::OutdatedVersion { current: CURRENT_VERSION, got: Some(4) })
+		));
+		assert_eq!(get_db_version(&path).unwrap(), Some(4));
+
+		update_version(&path, CURRENT_VERSION).unwrap();
+		assert!(ensure_current_version(&path, DatabaseKind::ParityDB).is_ok());
+	}
+
 	#[test]
 	fn test_paritydb_migrate_0_to_1() {
 		use parity_db::Db;
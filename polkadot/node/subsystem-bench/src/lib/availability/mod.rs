@@ -75,6 +75,9 @@ pub enum Strategy {
 	/// Fetch the full availability datafrom backers first. Saves CPU as we don't need to
 	/// re-construct from chunks. Typically this is only faster if nodes have enough bandwidth.
 	FullFromBackers,
+	/// Recovery from systematic chunks, requesting regular chunks as soon as systematic recovery
+	/// stalls. Peers are picked by their past chunk request latency and failures.
+	Adaptive,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
@@ -239,6 +242,12 @@ pub fn prepare_test(
 					Metrics::try_register(&dependencies.registry).unwrap(),
 					RecoveryStrategyKind::SystematicChunks,
 				),
+				Strategy::Adaptive => AvailabilityRecoverySubsystem::with_recovery_strategy_kind(
+					collation_req_receiver,
+					&state.req_protocol_names,
+					Metrics::try_register(&dependencies.registry).unwrap(),
+					RecoveryStrategyKind::AdaptiveChunks,
+				),
 			};
 
 			// Use a mocked av-store.
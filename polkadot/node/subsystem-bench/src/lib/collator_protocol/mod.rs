@@ -0,0 +1,382 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmark of the validator side of the `collator-protocol`, together with the
+//! `prospective-parachains` and `candidate-backing` subsystems.
+//!
+//! Emulated collators advertise a collation at every relay chain block, the node under test
+//! fetches, seconds and backs it with the help of the rest of its backing group. With more than
+//! one core per para the other cores are backed by other groups, exercising the elastic scaling
+//! code paths.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		av_store::MockAvailabilityStore,
+		candidate_validation::MockCandidateValidation,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		statement_distribution::MockStatementDistribution,
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	usage::BenchmarkUsage,
+};
+use colored::Colorize;
+use futures::channel::oneshot;
+use polkadot_collator_protocol::{CollatorProtocolSubsystem, ProtocolSide};
+use polkadot_node_core_backing::CandidateBackingSubsystem;
+use polkadot_node_core_prospective_parachains::ProspectiveParachainsSubsystem;
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	self as net_protocol, peer_set::CollationVersion, v2 as protocol_v2, ObservedRole, OurView,
+	Versioned,
+};
+use polkadot_node_subsystem::messages::{
+	AllMessages, CollatorProtocolMessage, IntroduceSecondedCandidateRequest, NetworkBridgeEvent,
+	ProspectiveParachainsMessage,
+};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, Hash, ValidatorId, DEFAULT_SCHEDULING_LOOKAHEAD};
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_core::Pair;
+use sp_keystore::Keystore;
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::collator-protocol";
+
+/// How long to wait for the collation of a block to be backed before moving on.
+const BACKING_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// Parameters specific to the collator protocol benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CollatorProtocolOptions {
+	#[clap(long, default_value_t = 10)]
+	/// Number of emulated collators advertising each collation.
+	pub n_collators: usize,
+	#[clap(long, default_value_t = 1)]
+	/// Number of cores assigned to the para, more than one enables elastic scaling.
+	pub cores_per_para: usize,
+	#[clap(long, default_value_t = DEFAULT_SCHEDULING_LOOKAHEAD)]
+	/// Number of claims of each core in the claim queue, also used as scheduling lookahead.
+	pub claim_queue_depth: u32,
+}
+
+fn make_keystore() -> Arc<LocalKeystore> {
+	let keystore = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let candidate_receipts = state
+		.collations
+		.iter()
+		.map(|(hash, collation)| (*hash, vec![collation.receipt.clone()]))
+		.collect();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		candidate_receipts,
+		Default::default(),
+		Default::default(),
+		state.session_index,
+		MockRuntimeApiCoreState::Scheduled,
+	)
+	.with_claim_queue(state.claim_queue.clone())
+	.with_scheduling_lookahead(state.scheduling_lookahead)
+	.with_backing_constraints(state.backing_constraints.clone());
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new_with_limited_ancestors(chain_api_state);
+	let keystore = make_keystore();
+	let collator_protocol = CollatorProtocolSubsystem::new(ProtocolSide::Validator {
+		keystore: keystore.clone(),
+		eviction_policy: Default::default(),
+		metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+	});
+	let prospective_parachains =
+		ProspectiveParachainsSubsystem::new(Metrics::try_register(&dependencies.registry).unwrap());
+	let candidate_backing = CandidateBackingSubsystem::new(
+		keystore,
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let mock_statement_distribution = MockStatementDistribution::new(
+		state.test_authorities.clone(),
+		state.session_index,
+		state.own_backing_group.clone(),
+		state.backed_at.clone(),
+	);
+	let mock_availability_store =
+		MockAvailabilityStore::new(Vec::new(), Vec::new(), Default::default(), Default::default());
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, None, false);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_candidate_validation(|_| {
+			MockCandidateValidation::with_commitments(state.commitments.clone())
+		})
+		.replace_availability_store(|_| mock_availability_store)
+		.replace_statement_distribution(|_| mock_statement_distribution)
+		.replace_collator_protocol(|_| collator_protocol)
+		.replace_prospective_parachains(|_| prospective_parachains)
+		.replace_candidate_backing(|_| candidate_backing)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+async fn send_network_event(
+	env: &mut TestEnvironment,
+	event: NetworkBridgeEvent<net_protocol::CollatorProtocolMessage>,
+) {
+	env.send_message(AllMessages::CollatorProtocol(CollatorProtocolMessage::NetworkBridgeUpdate(
+		event,
+	)))
+	.await;
+}
+
+/// Introduces the candidates of the para on its other cores to `prospective-parachains` and
+/// marks them as backed, as if the other backing groups did so.
+async fn back_elastic_candidates(env: &mut TestEnvironment, state: &TestState, block_hash: &Hash) {
+	let Some(candidates) = state.elastic_candidates.get(block_hash) else { return };
+	for (candidate_receipt, persisted_validation_data) in candidates {
+		let candidate_hash = candidate_receipt.hash();
+		let (tx, rx) = oneshot::channel();
+		env.send_message(AllMessages::ProspectiveParachains(
+			ProspectiveParachainsMessage::IntroduceSecondedCandidate(
+				IntroduceSecondedCandidateRequest {
+					candidate_para: state.para_id,
+					candidate_receipt: candidate_receipt.clone(),
+					persisted_validation_data: persisted_validation_data.clone(),
+				},
+				tx,
+			),
+		))
+		.await;
+		if !rx.await.expect("prospective-parachains is alive") {
+			gum::warn!(target: LOG_TARGET, ?candidate_hash, "Candidate not introduced");
+			continue
+		}
+		env.send_message(AllMessages::ProspectiveParachains(
+			ProspectiveParachainsMessage::CandidateBacked(state.para_id, candidate_hash),
+		))
+		.await;
+	}
+}
+
+pub async fn benchmark_collator_protocol(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let connected_collators: Vec<_> = state
+		.collators
+		.iter()
+		.filter(|(validator_index, _)| {
+			env.network().is_peer_connected(
+				&state.test_authorities.validator_authority_id[validator_index.0 as usize],
+			)
+		})
+		.map(|(validator_index, pair)| {
+			(state.test_authorities.peer_ids[validator_index.0 as usize], pair.clone())
+		})
+		.collect();
+	assert!(!connected_collators.is_empty(), "No collator is connected");
+	for (peer_id, _) in connected_collators.iter() {
+		send_network_event(
+			env,
+			NetworkBridgeEvent::PeerConnected(
+				*peer_id,
+				ObservedRole::Full,
+				CollationVersion::V2.into(),
+				None,
+			),
+		)
+		.await;
+	}
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+		send_network_event(
+			env,
+			NetworkBridgeEvent::OurViewChange(OurView::new([block_info.hash], 0)),
+		)
+		.await;
+
+		// Collators can only declare once the para is assigned to the node under test.
+		if block_num == 1 {
+			for (peer_id, pair) in connected_collators.iter() {
+				let signature = pair.sign(&protocol_v2::declare_signature_payload(peer_id));
+				send_network_event(
+					env,
+					NetworkBridgeEvent::PeerMessage(
+						*peer_id,
+						Versioned::V2(protocol_v2::CollatorProtocolMessage::Declare(
+							pair.public(),
+							state.para_id,
+							signature,
+						)),
+					),
+				)
+				.await;
+			}
+		}
+
+		back_elastic_candidates(env, state, &block_info.hash).await;
+
+		let collation = state.collations.get(&block_info.hash).expect("Pregenerated");
+		let candidate_hash = collation.receipt.hash();
+		state.advertised_at.lock().unwrap().insert(candidate_hash, Instant::now());
+		for (peer_id, _) in connected_collators.iter() {
+			send_network_event(
+				env,
+				NetworkBridgeEvent::PeerMessage(
+					*peer_id,
+					Versioned::V2(protocol_v2::CollatorProtocolMessage::AdvertiseCollation {
+						relay_parent: block_info.hash,
+						candidate_hash,
+						parent_head_data_hash: collation.parent_head.hash(),
+					}),
+				),
+			)
+			.await;
+		}
+
+		let block_start = Instant::now();
+		while !state.backed_at.lock().unwrap().contains_key(&candidate_hash) {
+			if block_start.elapsed() > BACKING_TIMEOUT {
+				gum::warn!(target: LOG_TARGET, ?candidate_hash, "Collation not backed in time");
+				break
+			}
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", duration / config.num_blocks as u128).red()
+	);
+
+	let fetch_latencies = state.fetch_latencies.lock().unwrap().clone();
+	if let Some(max) = fetch_latencies.values().max() {
+		let avg = fetch_latencies.values().sum::<Duration>() / fetch_latencies.len() as u32;
+		gum::info!(target: LOG_TARGET,
+			"{} collations fetched, avg fetch latency {}, max {}",
+			fetch_latencies.len(),
+			format!("{} ms", avg.as_millis()).red(),
+			format!("{} ms", max.as_millis()).red(),
+		);
+	}
+
+	let advertised_at = state.advertised_at.lock().unwrap().clone();
+	let time_to_backing: Vec<Duration> = state
+		.backed_at
+		.lock()
+		.unwrap()
+		.iter()
+		.filter_map(|(candidate_hash, backed_at)| {
+			advertised_at
+				.get(candidate_hash)
+				.map(|advertised_at| *backed_at - *advertised_at)
+		})
+		.collect();
+	if let Some(max) = time_to_backing.iter().max() {
+		let avg = time_to_backing.iter().sum::<Duration>() / time_to_backing.len() as u32;
+		gum::info!(target: LOG_TARGET,
+			"avg time-to-backing {}, max {}",
+			format!("{} ms", avg.as_millis()).red(),
+			format!("{} ms", max.as_millis()).red(),
+		);
+	}
+	gum::info!(target: LOG_TARGET,
+		"{}/{} collations backed",
+		time_to_backing.len(),
+		config.num_blocks,
+	);
+
+	env.stop().await;
+	env.collect_resource_usage(
+		&["collator-protocol", "prospective-parachains", "candidate-backing"],
+		false,
+	)
+}
@@ -0,0 +1,360 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	collator_protocol::CollatorProtocolOptions,
+	configuration::{TestAuthorities, TestConfiguration},
+	mock::runtime_api::session_info_for_peers,
+	network::{HandleNetworkMessage, NetworkMessage},
+	NODE_UNDER_TEST,
+};
+use codec::Encode;
+use polkadot_node_network_protocol::request_response::{v2::CollationFetchingResponse, Requests};
+use polkadot_node_primitives::{AvailableData, BlockData, PoV};
+use polkadot_node_subsystem_test_helpers::{
+	derive_erasure_chunks_with_proofs_and_root, mock::new_block_import_info,
+};
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	async_backing::InboundHrmpLimitations,
+	vstaging::{
+		async_backing::Constraints, CandidateReceiptV2 as CandidateReceipt,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, MutateDescriptorV2,
+	},
+	BlockNumber, CandidateCommitments, CandidateHash, CollatorPair, CoreIndex, GroupIndex, Hash,
+	HeadData, Header, Id as ParaId, PersistedValidationData, SessionIndex, ValidationCode,
+	ValidationCodeHash, ValidatorIndex, MAX_CODE_SIZE, MAX_HEAD_DATA_SIZE, MAX_POV_SIZE,
+};
+use polkadot_primitives_test_helpers::{dummy_committed_candidate_receipt_v2, dummy_head_data};
+use sc_network::ProtocolName;
+use sp_core::{Pair, H256};
+use std::{
+	collections::{BTreeMap, HashMap, VecDeque},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+const SESSION_INDEX: SessionIndex = 0;
+
+/// A collation advertised to the node under test.
+#[derive(Clone)]
+pub struct Collation {
+	pub receipt: CandidateReceipt,
+	pub pov: PoV,
+	pub parent_head: HeadData,
+}
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// The session of all relay chain blocks
+	pub session_index: SessionIndex,
+	// The para scheduled on the core of the node under test
+	pub para_id: ParaId,
+	// The claim queue, the same at every relay chain block
+	pub claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+	// The scheduling lookahead, also the depth of the claim queue
+	pub scheduling_lookahead: u32,
+	// Backing constraints of every scheduled para at each relay chain block
+	pub backing_constraints: HashMap<H256, HashMap<ParaId, Constraints>>,
+	// Collations advertised to the node under test, one per relay chain block
+	pub collations: HashMap<H256, Collation>,
+	// Candidates of the para on its other cores, these are backed by other groups
+	pub elastic_candidates:
+		HashMap<H256, Vec<(CommittedCandidateReceipt, PersistedValidationData)>>,
+	// Commitments of all generated candidates, by commitments hash
+	pub commitments: HashMap<Hash, CandidateCommitments>,
+	// Backing group of the node under test
+	pub own_backing_group: Vec<ValidatorIndex>,
+	// Emulated validators acting as collators, with their collator keys
+	pub collators: Vec<(ValidatorIndex, CollatorPair)>,
+	// Time at which each collation was advertised
+	pub advertised_at: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+	// Time it took to fetch each collation once advertised
+	pub fetch_latencies: Arc<Mutex<HashMap<CandidateHash, Duration>>>,
+	// Time at which each candidate was backed
+	pub backed_at: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &CollatorProtocolOptions) -> Self {
+		let test_authorities = config.generate_authorities();
+		let session_info = session_info_for_peers(config, &test_authorities);
+		assert!(
+			config.n_cores <= session_info.validator_groups.len(),
+			"Every core needs a backing group"
+		);
+		assert!(
+			options.cores_per_para >= 1 && options.cores_per_para <= config.n_cores,
+			"The para must be scheduled on at least one and at most all cores"
+		);
+		assert!(options.claim_queue_depth >= 1, "The claim queue can't be empty");
+
+		// The node under test is in the first group, which is assigned to the first core.
+		let own_backing_group = session_info.validator_groups.get(GroupIndex(0)).unwrap().clone();
+		assert!(own_backing_group.contains(&ValidatorIndex(NODE_UNDER_TEST)));
+		assert!(
+			options.n_collators <= config.n_validators - own_backing_group.len(),
+			"Collators are emulated by the validators outside of the own backing group"
+		);
+		let collators = (config.n_validators - options.n_collators..config.n_validators)
+			.map(|index| {
+				let pair = CollatorPair::from_string(&format!("//Collator{}", index), None)
+					.expect("Valid seed");
+				(ValidatorIndex(index as u32), pair)
+			})
+			.collect();
+
+		// The para of the node under test holds the first `cores_per_para` cores.
+		let para_id = ParaId::from(1);
+		let claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>> = (0..config.n_cores)
+			.map(|core_idx| {
+				let para = if core_idx < options.cores_per_para {
+					para_id
+				} else {
+					ParaId::from(core_idx as u32 + 1)
+				};
+				(CoreIndex(core_idx as u32), (0..options.claim_queue_depth).map(|_| para).collect())
+			})
+			.collect();
+
+		let block_infos: Vec<BlockInfo> =
+			(1..=config.num_blocks).map(generate_block_info).collect();
+		let block_headers: HashMap<H256, Header> =
+			block_infos.iter().map(generate_block_header).collect();
+		let validation_code_hash = ValidationCode(Vec::new()).hash();
+
+		let mut backing_constraints = HashMap::new();
+		let mut collations = HashMap::new();
+		let mut elastic_candidates: HashMap<_, Vec<_>> = HashMap::new();
+		let mut commitments = HashMap::new();
+		for block_info in block_infos.iter() {
+			let min_relay_parent_number =
+				block_info.number.saturating_sub(options.claim_queue_depth - 1);
+			let chain_start = (block_info.number - 1) * options.cores_per_para as u32;
+
+			// Candidates with an older relay parent have been included.
+			let constraints: HashMap<ParaId, Constraints> = claim_queue
+				.values()
+				.filter_map(|paras| paras.front())
+				.map(|&para| {
+					let required_parent =
+						if para == para_id { head_data(chain_start) } else { dummy_head_data() };
+					(
+						para,
+						backing_constraints_for(
+							min_relay_parent_number,
+							required_parent,
+							validation_code_hash,
+						),
+					)
+				})
+				.collect();
+
+			// Each relay chain block advances the chain of the para by one candidate per core.
+			// The node under test backs the last one, the ones on the other cores come first.
+			for position in 0..options.cores_per_para {
+				let index = chain_start + position as u32;
+				let is_own = position + 1 == options.cores_per_para;
+				let core_index = if is_own { CoreIndex(0) } else { CoreIndex(position as u32 + 1) };
+				let pov_size = config.pov_sizes()[core_index.0 as usize];
+				let pvd = PersistedValidationData {
+					parent_head: head_data(index),
+					relay_parent_number: block_info.number,
+					relay_parent_storage_root: block_headers[&block_info.hash].state_root,
+					max_pov_size: MAX_POV_SIZE,
+				};
+				let (receipt, pov) = generate_candidate(
+					para_id,
+					core_index,
+					block_info,
+					index,
+					pov_size,
+					&pvd,
+					validation_code_hash,
+					config.n_validators,
+				);
+				commitments.insert(receipt.commitments.hash(), receipt.commitments.clone());
+
+				if is_own {
+					collations.insert(
+						block_info.hash,
+						Collation {
+							receipt: receipt.to_plain(),
+							pov,
+							parent_head: pvd.parent_head,
+						},
+					);
+				} else {
+					elastic_candidates.entry(block_info.hash).or_default().push((receipt, pvd));
+				}
+			}
+
+			backing_constraints.insert(block_info.hash, constraints);
+		}
+
+		Self {
+			config: config.clone(),
+			test_authorities,
+			block_infos,
+			block_headers,
+			session_index: SESSION_INDEX,
+			para_id,
+			claim_queue,
+			scheduling_lookahead: options.claim_queue_depth,
+			backing_constraints,
+			collations,
+			elastic_candidates,
+			commitments,
+			own_backing_group,
+			collators,
+			advertised_at: Default::default(),
+			fetch_latencies: Default::default(),
+			backed_at: Default::default(),
+		}
+	}
+}
+
+fn head_data(index: u32) -> HeadData {
+	HeadData(index.encode())
+}
+
+fn backing_constraints_for(
+	min_relay_parent_number: BlockNumber,
+	required_parent: HeadData,
+	validation_code_hash: ValidationCodeHash,
+) -> Constraints {
+	Constraints {
+		min_relay_parent_number,
+		max_pov_size: MAX_POV_SIZE,
+		max_code_size: MAX_CODE_SIZE,
+		max_head_data_size: MAX_HEAD_DATA_SIZE,
+		ump_remaining: 16,
+		ump_remaining_bytes: 64 * 1024,
+		max_ump_num_per_candidate: 16,
+		dmp_remaining_messages: Vec::new(),
+		hrmp_inbound: InboundHrmpLimitations { valid_watermarks: Vec::new() },
+		hrmp_channels_out: Vec::new(),
+		max_hrmp_num_per_candidate: 0,
+		required_parent,
+		validation_code_hash,
+		upgrade_restriction: None,
+		future_validation_code: None,
+	}
+}
+
+/// Generates the candidate at `index` in the chain of the para, building on the head data of
+/// the previous one.
+fn generate_candidate(
+	para_id: ParaId,
+	core_index: CoreIndex,
+	block_info: &BlockInfo,
+	index: u32,
+	pov_size: usize,
+	pvd: &PersistedValidationData,
+	validation_code_hash: ValidationCodeHash,
+	n_validators: usize,
+) -> (CommittedCandidateReceipt, PoV) {
+	let mut block_data = index.encode();
+	block_data.resize(pov_size.max(block_data.len()), 0);
+	let pov = PoV { block_data: BlockData(block_data) };
+	let (_, erasure_root) = derive_erasure_chunks_with_proofs_and_root(
+		n_validators,
+		&AvailableData { validation_data: pvd.clone(), pov: Arc::new(pov.clone()) },
+		|_, _| {},
+	);
+	let head_data = head_data(index + 1);
+
+	let mut receipt = dummy_committed_candidate_receipt_v2(block_info.hash);
+	receipt.commitments = CandidateCommitments {
+		head_data: head_data.clone(),
+		hrmp_watermark: block_info.number,
+		..Default::default()
+	};
+	receipt.descriptor.set_para_id(para_id);
+	receipt.descriptor.set_core_index(core_index);
+	receipt.descriptor.set_session_index(SESSION_INDEX);
+	receipt.descriptor.set_persisted_validation_data_hash(pvd.hash());
+	receipt.descriptor.set_pov_hash(pov.hash());
+	receipt.descriptor.set_erasure_root(erasure_root);
+	receipt.descriptor.set_validation_code_hash(validation_code_hash);
+	receipt.descriptor.set_para_head(head_data.hash());
+
+	(receipt, pov)
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(_authority_id, Requests::CollationFetchingV2(req)) => {
+				let candidate_hash = req.payload.candidate_hash;
+				if let Some(advertised_at) = self.advertised_at.lock().unwrap().get(&candidate_hash)
+				{
+					self.fetch_latencies
+						.lock()
+						.unwrap()
+						.entry(candidate_hash)
+						.or_insert_with(|| advertised_at.elapsed());
+				}
+
+				let collation = self
+					.collations
+					.values()
+					.find(|collation| collation.receipt.hash() == candidate_hash)
+					.expect("Only advertised collations are fetched")
+					.clone();
+				// Elastic scaling requires the collators to provide the parent head data.
+				let response = CollationFetchingResponse::CollationWithParentHeadData {
+					receipt: collation.receipt,
+					pov: collation.pov,
+					parent_head_data: collation.parent_head,
+				};
+				let _ = req.pending_response.send(Ok((response.encode(), ProtocolName::from(""))));
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
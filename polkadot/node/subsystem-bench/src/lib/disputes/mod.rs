@@ -0,0 +1,328 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmark of the `dispute-coordinator` and `dispute-distribution` subsystems.
+//!
+//! Emulated validators raise disputes on candidates included in the imported blocks and vote
+//! on them, optionally mixed with spam disputes on candidates that were never included. The
+//! benchmark ends once all disputes on included candidates have concluded.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies, GENESIS_HASH},
+	mock::{
+		authority_discovery::MockAuthorityDiscovery,
+		availability_recovery::MockAvailabilityRecovery,
+		candidate_validation::MockCandidateValidation,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	usage::BenchmarkUsage,
+};
+use codec::Encode;
+use colored::Colorize;
+use futures::{channel::oneshot, stream::FuturesUnordered, StreamExt};
+use itertools::Itertools;
+use polkadot_dispute_distribution::{DisputeDistributionSubsystem, SEND_RATE_LIMIT};
+use polkadot_node_core_dispute_coordinator::{Config, DisputeCoordinatorSubsystem};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::request_response::{IncomingRequest, ReqProtocolNames};
+use polkadot_node_subsystem::messages::{AllMessages, DisputeCoordinatorMessage};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{
+	supermajority_threshold, AuthorityDiscoveryId, Block, CandidateHash, Hash, ValidatorId,
+};
+use sc_keystore::LocalKeystore;
+use sc_network::request_responses::IncomingRequest as RawIncomingRequest;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_keystore::Keystore;
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::disputes";
+
+// How long we wait for all disputes on included candidates to conclude, after the first dispute
+// request is sent.
+const CONCLUSION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Parameters specific to the disputes benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DisputesOptions {
+	#[clap(long, default_value_t = 10)]
+	/// Number of concurrent disputes raised on included candidates.
+	pub n_disputes: usize,
+	#[clap(long, default_value_t = 0)]
+	/// Number of disputes raised on candidates that were never included.
+	pub n_spam_disputes: usize,
+	#[clap(long, default_value_t = 2)]
+	/// Number of malicious validators raising the disputes, these are the validators with the
+	/// highest indices.
+	pub n_malicious: usize,
+	#[clap(long, default_value_t = 100)]
+	/// Percentage of the honest validators voting in each dispute.
+	pub honest_vote_percentage: u8,
+}
+
+fn make_keystore() -> Arc<LocalKeystore> {
+	let keystore = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		state.candidate_events.clone(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let (dispute_req_receiver, dispute_req_cfg) = IncomingRequest::get_config_receiver::<
+		Block,
+		sc_network::NetworkWorker<Block, Hash>,
+	>(&ReqProtocolNames::new(GENESIS_HASH, None));
+	let keystore = make_keystore();
+	let db = kvdb_memorydb::create(1);
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[]);
+	let dispute_coordinator = DisputeCoordinatorSubsystem::new(
+		Arc::new(db),
+		Config { col_dispute_data: 0 },
+		keystore.clone(),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+		true,
+	);
+	let dispute_distribution = DisputeDistributionSubsystem::new(
+		keystore,
+		dispute_req_receiver,
+		MockAuthorityDiscovery::new(&state.test_authorities),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, Some(dispute_req_cfg), true);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_availability_recovery(|_| MockAvailabilityRecovery::new())
+		.replace_candidate_validation(|_| MockCandidateValidation::new())
+		.replace_dispute_coordinator(|_| dispute_coordinator)
+		.replace_dispute_distribution(|_| dispute_distribution)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+/// Sends the pregenerated dispute requests from the connected emulated validators, each one
+/// respecting the `dispute-distribution` rate limit, and waits for all responses.
+///
+/// Returns the number of refused requests.
+async fn send_dispute_requests(network: NetworkEmulatorHandle, state: &TestState) -> usize {
+	let mut senders = state
+		.requests
+		.iter()
+		.filter_map(|(validator_index, requests)| {
+			let authority_id =
+				state.test_authorities.validator_authority_id[validator_index.0 as usize].clone();
+			let peer_id = state.test_authorities.peer_ids[validator_index.0 as usize];
+			network.is_peer_connected(&authority_id).then_some((
+				authority_id,
+				peer_id,
+				requests.iter(),
+			))
+		})
+		.collect_vec();
+	let mut pending_responses = FuturesUnordered::new();
+
+	loop {
+		let mut sent = false;
+		for (authority_id, peer_id, requests) in senders.iter_mut() {
+			let Some(request) = requests.next() else { continue };
+			let (pending_response, response_receiver) = oneshot::channel();
+			network
+				.send_request_from_peer(
+					authority_id,
+					RawIncomingRequest {
+						peer: *peer_id,
+						payload: request.encode(),
+						pending_response,
+					},
+				)
+				.expect("Only connected peers send requests");
+			pending_responses.push(response_receiver);
+			sent = true;
+		}
+		if !sent {
+			break
+		}
+		tokio::time::sleep(SEND_RATE_LIMIT).await;
+	}
+
+	let mut refused = 0;
+	while let Some(response) = pending_responses.next().await {
+		if !matches!(response, Ok(response) if response.result.is_ok()) {
+			refused += 1;
+		}
+	}
+	refused
+}
+
+/// Polls the `dispute-coordinator` until all disputes on included candidates have concluded.
+///
+/// Returns the time it took each dispute to conclude, measured from `test_start`. Panics if they
+/// haven't all concluded within [`CONCLUSION_TIMEOUT`].
+async fn wait_for_conclusion(
+	env: &mut TestEnvironment,
+	state: &TestState,
+	test_start: Instant,
+) -> HashMap<CandidateHash, Duration> {
+	let mut concluded = HashMap::new();
+	while concluded.len() < state.disputed_candidates.len() {
+		assert!(
+			test_start.elapsed() < CONCLUSION_TIMEOUT,
+			"Only {}/{} disputes concluded within {:?}",
+			concluded.len(),
+			state.disputed_candidates.len(),
+			CONCLUSION_TIMEOUT,
+		);
+		tokio::time::sleep(Duration::from_millis(50)).await;
+
+		let (tx, rx) = oneshot::channel();
+		env.send_message(AllMessages::DisputeCoordinator(
+			DisputeCoordinatorMessage::RecentDisputes(tx),
+		))
+		.await;
+		let recent_disputes = rx.await.expect("dispute-coordinator is alive");
+		for (_session, candidate_hash, status) in recent_disputes {
+			if status.has_concluded_for() && state.disputed_candidates.contains(&candidate_hash) {
+				concluded.entry(candidate_hash).or_insert_with(|| test_start.elapsed());
+			}
+		}
+		gum::debug!(target: LOG_TARGET, "{}/{} disputes concluded", concluded.len(), state.disputed_candidates.len());
+	}
+	concluded
+}
+
+pub async fn benchmark_disputes(env: &mut TestEnvironment, state: &TestState) -> BenchmarkUsage {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	// All candidates must be included before the disputes are raised.
+	for block_info in state.block_infos.iter() {
+		gum::info!(target: LOG_TARGET, "Importing block {}/{} {:?}", block_info.number, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_info.number as usize);
+		env.import_block(block_info.clone()).await;
+	}
+
+	let connected_voters = state
+		.honest_voters
+		.iter()
+		.filter(|v| {
+			env.network()
+				.is_peer_connected(&state.test_authorities.validator_authority_id[v.0 as usize])
+		})
+		.count();
+	// The node under test votes as well, once it has participated.
+	assert!(
+		state.disputed_candidates.is_empty() ||
+			connected_voters + 1 >= supermajority_threshold(config.n_validators),
+		"Only {} honest voters are connected, disputes can't conclude",
+		connected_voters,
+	);
+
+	let test_start = Instant::now();
+	let network = env.network().clone();
+	let (refused, concluded) = futures::join!(
+		send_dispute_requests(network, state),
+		wait_for_conclusion(env, state, test_start)
+	);
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All dispute requests processed in {}", format!("{:?}ms", duration).cyan());
+	if let Some(max) = concluded.values().max() {
+		let avg = concluded.values().sum::<Duration>() / concluded.len() as u32;
+		gum::info!(target: LOG_TARGET,
+			"{} disputes concluded, avg time-to-conclusion {}, max {}",
+			concluded.len(),
+			format!("{} ms", avg.as_millis()).red(),
+			format!("{} ms", max.as_millis()).red(),
+		);
+	}
+	gum::info!(target: LOG_TARGET, "{} dispute requests refused", refused);
+
+	env.stop().await;
+	env.collect_resource_usage(&["dispute-coordinator", "dispute-distribution"], false)
+}
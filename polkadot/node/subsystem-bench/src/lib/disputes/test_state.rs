@@ -0,0 +1,281 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	disputes::DisputesOptions,
+	network::{HandleNetworkMessage, NetworkMessage},
+	NODE_UNDER_TEST,
+};
+use codec::Encode;
+use itertools::Itertools;
+use polkadot_node_network_protocol::request_response::{
+	v1::{DisputeRequest, DisputeResponse},
+	Requests,
+};
+use polkadot_node_primitives::{InvalidDisputeVote, UncheckedDisputeMessage, ValidDisputeVote};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	vstaging::{CandidateEvent, CandidateReceiptV2 as CandidateReceipt, MutateDescriptorV2},
+	BlockNumber, CandidateHash, CoreIndex, DisputeStatement, GroupIndex, Hash, Header, Id,
+	InvalidDisputeStatementKind, ValidDisputeStatementKind, ValidatorIndex, ValidatorPair,
+	ValidatorSignature,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_committed_candidate_receipt_v2, dummy_hash, dummy_head_data,
+};
+use sc_network::ProtocolName;
+use sp_core::{Pair, H256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const SESSION_INDEX: u32 = 0;
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// Candidates included in each relay chain block
+	pub candidate_receipts: HashMap<H256, Vec<CandidateReceipt>>,
+	// Inclusion events for each relay chain block
+	pub candidate_events: HashMap<H256, Vec<CandidateEvent>>,
+	// Disputed included candidates, these are expected to conclude
+	pub disputed_candidates: HashSet<CandidateHash>,
+	// Honest validators voting in the disputes
+	pub honest_voters: Vec<ValidatorIndex>,
+	// Pregenerated dispute requests of every emulated validator, in sending order
+	pub requests: BTreeMap<ValidatorIndex, Vec<DisputeRequest>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &DisputesOptions) -> Self {
+		let n_validators = config.n_validators;
+		assert!(
+			options.n_malicious >= 2 && options.n_malicious < n_validators,
+			"There must be at least two malicious validators and some honest ones"
+		);
+		assert!(
+			options.n_disputes <= config.num_blocks * config.n_cores,
+			"Can't dispute more candidates than were included"
+		);
+
+		let test_authorities = config.generate_authorities();
+		let block_infos: Vec<BlockInfo> =
+			(1..=config.num_blocks).map(generate_block_info).collect();
+		let block_headers = block_infos.iter().map(generate_block_header).collect();
+
+		let mut candidate_receipts: HashMap<H256, Vec<CandidateReceipt>> = HashMap::new();
+		let mut candidate_events: HashMap<H256, Vec<CandidateEvent>> = HashMap::new();
+		for block_info in block_infos.iter() {
+			for core_idx in 0..config.n_cores {
+				let receipt = generate_candidate(core_idx as u32 + 1, core_idx, block_info.hash);
+				candidate_events.entry(block_info.hash).or_default().push(
+					CandidateEvent::CandidateIncluded(
+						receipt.clone(),
+						dummy_head_data(),
+						CoreIndex(core_idx as u32),
+						GroupIndex(core_idx as u32),
+					),
+				);
+				candidate_receipts.entry(block_info.hash).or_default().push(receipt);
+			}
+		}
+
+		// The malicious validators are the ones with the highest indices.
+		let malicious = (n_validators - options.n_malicious..n_validators)
+			.map(|index| ValidatorIndex(index as u32))
+			.collect_vec();
+		let all_honest = (0..n_validators - options.n_malicious)
+			.map(|index| ValidatorIndex(index as u32))
+			.filter(|index| index.0 != NODE_UNDER_TEST)
+			.collect_vec();
+		let n_honest_voters =
+			(all_honest.len() * options.honest_vote_percentage as usize).div_ceil(100);
+		let honest_voters = all_honest.into_iter().take(n_honest_voters).collect_vec();
+
+		let mut requests: BTreeMap<ValidatorIndex, Vec<DisputeRequest>> = BTreeMap::new();
+
+		// Disputes raised by a malicious validator against included candidates, the honest
+		// validators vote for the candidate.
+		let disputed = block_infos
+			.iter()
+			.flat_map(|block_info| candidate_receipts.get(&block_info.hash).unwrap())
+			.take(options.n_disputes)
+			.cloned()
+			.collect_vec();
+		let mut votes_per_dispute = Vec::new();
+		for (dispute_idx, receipt) in disputed.iter().enumerate() {
+			let disputer = malicious[dispute_idx % malicious.len()];
+			let invalid_vote =
+				invalid_vote(receipt.hash(), disputer, &test_authorities.validator_pairs);
+			votes_per_dispute.push(
+				honest_voters
+					.iter()
+					.map(|&voter| {
+						dispute_request(
+							receipt,
+							valid_vote(receipt.hash(), voter, &test_authorities.validator_pairs),
+							invalid_vote.clone(),
+						)
+					})
+					.collect_vec(),
+			);
+		}
+		// Each voter starts with a different dispute, so all of them progress concurrently.
+		for (voter_idx, voter) in honest_voters.iter().enumerate() {
+			let voter_requests = requests.entry(*voter).or_default();
+			for offset in 0..votes_per_dispute.len() {
+				let dispute_idx = (voter_idx + offset) % votes_per_dispute.len();
+				voter_requests.push(votes_per_dispute[dispute_idx][voter_idx].clone());
+			}
+		}
+
+		// Disputes on candidates that were never included, both votes come from the malicious
+		// validators.
+		for spam_idx in 0..options.n_spam_disputes {
+			let block_info = &block_infos[spam_idx % block_infos.len()];
+			let receipt = generate_candidate(
+				(config.n_cores + spam_idx) as u32 + 1,
+				spam_idx % config.n_cores,
+				block_info.hash,
+			);
+			let disputer = malicious[spam_idx % malicious.len()];
+			let supporter = malicious[(spam_idx + 1) % malicious.len()];
+			let request = dispute_request(
+				&receipt,
+				valid_vote(receipt.hash(), supporter, &test_authorities.validator_pairs),
+				invalid_vote(receipt.hash(), disputer, &test_authorities.validator_pairs),
+			);
+			requests.entry(disputer).or_default().push(request);
+		}
+
+		Self {
+			config: config.clone(),
+			test_authorities,
+			block_infos,
+			block_headers,
+			candidate_receipts,
+			candidate_events,
+			disputed_candidates: disputed.iter().map(|receipt| receipt.hash()).collect(),
+			honest_voters,
+			requests,
+		}
+	}
+}
+
+fn generate_candidate(para_id: u32, core_idx: usize, relay_parent: H256) -> CandidateReceipt {
+	let mut receipt = dummy_committed_candidate_receipt_v2(dummy_hash());
+	receipt.descriptor.set_para_id(Id::new(para_id));
+	receipt.descriptor.set_relay_parent(relay_parent);
+	receipt.descriptor.set_core_index(CoreIndex(core_idx as u32));
+	receipt.descriptor.set_session_index(SESSION_INDEX);
+	receipt.to_plain()
+}
+
+fn sign_dispute_statement(
+	statement: &DisputeStatement,
+	candidate_hash: CandidateHash,
+	pair: &ValidatorPair,
+) -> ValidatorSignature {
+	let payload = statement
+		.payload_data(candidate_hash, SESSION_INDEX)
+		.expect("Explicit statements always have a payload");
+	pair.sign(&payload[..])
+}
+
+fn valid_vote(
+	candidate_hash: CandidateHash,
+	validator_index: ValidatorIndex,
+	pairs: &[ValidatorPair],
+) -> ValidDisputeVote {
+	let kind = ValidDisputeStatementKind::Explicit;
+	let signature = sign_dispute_statement(
+		&DisputeStatement::Valid(kind.clone()),
+		candidate_hash,
+		&pairs[validator_index.0 as usize],
+	);
+	ValidDisputeVote { validator_index, signature, kind }
+}
+
+fn invalid_vote(
+	candidate_hash: CandidateHash,
+	validator_index: ValidatorIndex,
+	pairs: &[ValidatorPair],
+) -> InvalidDisputeVote {
+	let kind = InvalidDisputeStatementKind::Explicit;
+	let signature = sign_dispute_statement(
+		&DisputeStatement::Invalid(kind),
+		candidate_hash,
+		&pairs[validator_index.0 as usize],
+	);
+	InvalidDisputeVote { validator_index, signature, kind }
+}
+
+fn dispute_request(
+	candidate_receipt: &CandidateReceipt,
+	valid_vote: ValidDisputeVote,
+	invalid_vote: InvalidDisputeVote,
+) -> DisputeRequest {
+	DisputeRequest(UncheckedDisputeMessage {
+		candidate_receipt: candidate_receipt.clone(),
+		session_index: SESSION_INDEX,
+		invalid_vote,
+		valid_vote,
+	})
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			// The node under test distributes its own votes, peers just confirm them.
+			NetworkMessage::RequestFromNode(_authority_id, Requests::DisputeSendingV1(req)) => {
+				let _ = req
+					.pending_response
+					.send(Ok((DisputeResponse::Confirmed.encode(), ProtocolName::from(""))));
+				None
+			},
+			message => Some(message),
+		}
+	}
+}
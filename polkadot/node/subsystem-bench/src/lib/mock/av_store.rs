@@ -260,6 +260,14 @@ impl MockAvailabilityStore {
 						);
 						let _ = tx.send(Ok(()));
 					},
+					AvailabilityStoreMessage::StoreAvailableData { candidate_hash, tx, .. } => {
+						gum::debug!(
+							target: LOG_TARGET,
+							candidate_hash = ?candidate_hash,
+							"Responding to StoreAvailableData"
+						);
+						let _ = tx.send(Ok(()));
+					},
 					_ => {
 						unimplemented!("Unexpected av-store message")
 					},
@@ -16,6 +16,9 @@
 
 //! A generic mock candidate validation subsystem suitable for using in benchmarks, it
 //! is responding with candidate valid for every request.
+//!
+//! If the commitments of a candidate are known the mock responds with them and the persisted
+//! validation data it was given, like a real validation would.
 
 use futures::FutureExt;
 use polkadot_node_primitives::ValidationResult;
@@ -24,12 +27,21 @@ use polkadot_node_subsystem::{
 };
 use polkadot_node_subsystem_types::OverseerSignal;
 use polkadot_primitives::{CandidateCommitments, Hash, HeadData, PersistedValidationData};
+use std::collections::HashMap;
 
-pub struct MockCandidateValidation {}
+pub struct MockCandidateValidation {
+	// Known candidate commitments, by commitments hash
+	commitments: HashMap<Hash, CandidateCommitments>,
+}
 
 impl MockCandidateValidation {
 	pub fn new() -> Self {
-		Self {}
+		Self { commitments: HashMap::new() }
+	}
+
+	/// Responds with the given commitments for the candidates they belong to.
+	pub fn with_commitments(commitments: HashMap<Hash, CandidateCommitments>) -> Self {
+		Self { commitments }
 	}
 }
 
@@ -54,18 +66,27 @@ impl MockCandidateValidation {
 					},
 				orchestra::FromOrchestra::Communication { msg } => match msg {
 					CandidateValidationMessage::ValidateFromExhaustive {
-						response_sender, ..
-					} => response_sender
-						.send(Ok(ValidationResult::Valid(
-							CandidateCommitments::default(),
-							PersistedValidationData {
-								parent_head: HeadData(Vec::new()),
-								relay_parent_number: 0,
-								relay_parent_storage_root: Hash::default(),
-								max_pov_size: 2,
-							},
-						)))
-						.unwrap(),
+						validation_data,
+						candidate_receipt,
+						response_sender,
+						..
+					} => {
+						let result = match self.commitments.get(&candidate_receipt.commitments_hash)
+						{
+							Some(commitments) =>
+								ValidationResult::Valid(commitments.clone(), validation_data),
+							None => ValidationResult::Valid(
+								CandidateCommitments::default(),
+								PersistedValidationData {
+									parent_head: HeadData(Vec::new()),
+									relay_parent_number: 0,
+									relay_parent_storage_root: Hash::default(),
+									max_pov_size: 2,
+								},
+							),
+						};
+						response_sender.send(Ok(result)).unwrap()
+					},
 					_ => unimplemented!("Unexpected chain-api message"),
 				},
 			}
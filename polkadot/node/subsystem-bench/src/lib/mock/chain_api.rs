@@ -35,6 +35,9 @@ pub struct ChainApiState {
 
 pub struct MockChainApi {
 	state: ChainApiState,
+	/// Respond to `Ancestors` requests like the real chain api: at most `k` ancestors, starting
+	/// with the parent of the requested block.
+	limit_ancestors: bool,
 }
 
 impl ChainApiState {
@@ -45,7 +48,13 @@ impl ChainApiState {
 
 impl MockChainApi {
 	pub fn new(state: ChainApiState) -> MockChainApi {
-		Self { state }
+		Self { state, limit_ancestors: false }
+	}
+
+	/// Same as [`Self::new`], but `Ancestors` requests are answered with at most `k` ancestors in
+	/// descending order, as the subsystems walking back the relay chain expect.
+	pub fn new_with_limited_ancestors(state: ChainApiState) -> MockChainApi {
+		Self { state, limit_ancestors: true }
 	}
 }
 
@@ -104,7 +113,7 @@ impl MockChainApi {
 								)))
 								.unwrap();
 						},
-						ChainApiMessage::Ancestors { hash, k: _, response_channel } => {
+						ChainApiMessage::Ancestors { hash, k, response_channel } => {
 							let block_number = self
 								.state
 								.block_headers
@@ -115,10 +124,19 @@ impl MockChainApi {
 								.state
 								.block_headers
 								.iter()
-								.filter(|(_, header)| header.number < block_number)
-								.sorted_by(|a, b| a.1.number.cmp(&b.1.number))
-								.map(|(hash, _)| *hash)
-								.collect_vec();
+								.filter(|(_, header)| header.number < block_number);
+							let ancestors = if self.limit_ancestors {
+								ancestors
+									.sorted_by(|a, b| b.1.number.cmp(&a.1.number))
+									.take(k)
+									.map(|(hash, _)| *hash)
+									.collect_vec()
+							} else {
+								ancestors
+									.sorted_by(|a, b| a.1.number.cmp(&b.1.number))
+									.map(|(hash, _)| *hash)
+									.collect_vec()
+							};
 							response_channel.send(Ok(ancestors)).unwrap();
 						},
 						_ => {
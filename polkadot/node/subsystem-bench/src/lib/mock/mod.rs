@@ -18,6 +18,7 @@ use polkadot_node_subsystem::HeadSupportsParachains;
 use polkadot_node_subsystem_types::Hash;
 use sp_consensus::SyncOracle;
 
+pub mod authority_discovery;
 pub mod av_store;
 pub mod availability_recovery;
 pub mod candidate_backing;
@@ -27,6 +28,7 @@ pub mod dummy;
 pub mod network_bridge;
 pub mod prospective_parachains;
 pub mod runtime_api;
+pub mod statement_distribution;
 
 pub struct AlwaysSupportsParachains {}
 
@@ -27,10 +27,13 @@ use polkadot_node_subsystem::{
 use polkadot_node_subsystem_types::OverseerSignal;
 use polkadot_primitives::{
 	node_features,
-	vstaging::{CandidateEvent, CandidateReceiptV2 as CandidateReceipt, CoreState, OccupiedCore},
+	vstaging::{
+		async_backing::Constraints, CandidateEvent, CandidateReceiptV2 as CandidateReceipt,
+		CoreState, OccupiedCore,
+	},
 	ApprovalVotingParams, AsyncBackingParams, CoreIndex, GroupIndex, GroupRotationInfo,
 	Id as ParaId, IndexedVec, NodeFeatures, ScheduledCore, SessionIndex, SessionInfo,
-	ValidationCode, ValidatorIndex,
+	ValidationCode, ValidatorIndex, DEFAULT_SCHEDULING_LOOKAHEAD,
 };
 use sp_consensus_babe::Epoch as BabeEpoch;
 use sp_core::H256;
@@ -54,6 +57,10 @@ pub struct RuntimeApiState {
 	session_index: SessionIndex,
 	// The claim queue
 	claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+	// The scheduling lookahead
+	scheduling_lookahead: u32,
+	// Backing constraints of the paras per block
+	backing_constraints: HashMap<H256, HashMap<ParaId, Constraints>>,
 }
 
 #[derive(Clone)]
@@ -112,12 +119,36 @@ impl MockRuntimeApi {
 				session_index,
 				node_features,
 				claim_queue,
+				scheduling_lookahead: DEFAULT_SCHEDULING_LOOKAHEAD,
+				backing_constraints: Default::default(),
 			},
 			config,
 			core_state,
 		}
 	}
 
+	/// Replaces the default claim queue of one para per core.
+	pub fn with_claim_queue(mut self, claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>) -> Self {
+		self.state.claim_queue = claim_queue;
+		self
+	}
+
+	/// Sets the scheduling lookahead reported by the runtime.
+	pub fn with_scheduling_lookahead(mut self, scheduling_lookahead: u32) -> Self {
+		self.state.scheduling_lookahead = scheduling_lookahead;
+		self
+	}
+
+	/// Sets the backing constraints of the paras per block, paras without constraints are
+	/// reported as not registered.
+	pub fn with_backing_constraints(
+		mut self,
+		backing_constraints: HashMap<H256, HashMap<ParaId, Constraints>>,
+	) -> Self {
+		self.state.backing_constraints = backing_constraints;
+		self
+	}
+
 	fn session_info(&self) -> SessionInfo {
 		session_info_for_peers(&self.config, &self.state.authorities)
 	}
@@ -330,6 +361,45 @@ impl MockRuntimeApi {
 						RuntimeApiMessage::Request(_parent, RuntimeApiRequest::ClaimQueue(tx)) => {
 							tx.send(Ok(self.state.claim_queue.clone())).unwrap();
 						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::FetchOnChainVotes(tx),
+						) => {
+							// No votes are ever imported on chain.
+							tx.send(Ok(None)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::UnappliedSlashes(tx),
+						) => {
+							tx.send(Ok(Vec::new())).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::SchedulingLookahead(_session_index, tx),
+						) => {
+							tx.send(Ok(self.state.scheduling_lookahead)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							block_hash,
+							RuntimeApiRequest::BackingConstraints(para_id, tx),
+						) => {
+							let constraints = self
+								.state
+								.backing_constraints
+								.get(&block_hash)
+								.and_then(|constraints| constraints.get(&para_id))
+								.cloned();
+							tx.send(Ok(constraints)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::CandidatesPendingAvailability(_para_id, tx),
+						) => {
+							// Availability is not emulated, backed candidates are included right
+							// away.
+							tx.send(Ok(Vec::new())).unwrap();
+						},
 						// Long term TODO: implement more as needed.
 						message => {
 							unimplemented!("Unexpected runtime-api message: {:?}", message)
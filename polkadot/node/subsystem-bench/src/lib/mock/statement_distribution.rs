@@ -0,0 +1,141 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A generic statement distribution subsystem mockup suitable to be used in benchmarks.
+//!
+//! Every candidate seconded by the node under test is validated by the rest of its backing
+//! group, the mock delivers their `Valid` statements to `candidate-backing`.
+
+use crate::configuration::TestAuthorities;
+use futures::FutureExt;
+use polkadot_node_primitives::{SignedFullStatementWithPVD, Statement, StatementWithPVD};
+use polkadot_node_subsystem::{
+	messages::{CandidateBackingMessage, StatementDistributionMessage},
+	overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use polkadot_primitives::{CandidateHash, Hash, SessionIndex, SigningContext, ValidatorIndex};
+use sp_core::Pair;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+const LOG_TARGET: &str = "subsystem-bench::statement-distribution-mock";
+
+pub struct MockStatementDistribution {
+	test_authorities: TestAuthorities,
+	// The session the statements are signed in
+	session_index: SessionIndex,
+	own_backing_group: Vec<ValidatorIndex>,
+	// Time at which each candidate was backed
+	backed_candidates: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+}
+
+impl MockStatementDistribution {
+	pub fn new(
+		test_authorities: TestAuthorities,
+		session_index: SessionIndex,
+		own_backing_group: Vec<ValidatorIndex>,
+		backed_candidates: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+	) -> Self {
+		Self { test_authorities, session_index, own_backing_group, backed_candidates }
+	}
+
+	fn group_statements(
+		&self,
+		relay_parent: Hash,
+		candidate_hash: CandidateHash,
+		seconded_by: ValidatorIndex,
+	) -> Vec<SignedFullStatementWithPVD> {
+		let context =
+			SigningContext { parent_hash: relay_parent, session_index: self.session_index };
+		let payload = Statement::Valid(candidate_hash).to_compact().signing_payload(&context);
+
+		self.own_backing_group
+			.iter()
+			.filter(|&&validator_index| validator_index != seconded_by)
+			.map(|&validator_index| {
+				let pair = &self.test_authorities.validator_pairs[validator_index.0 as usize];
+				SignedFullStatementWithPVD::new(
+					StatementWithPVD::Valid(candidate_hash),
+					validator_index,
+					pair.sign(&payload[..]),
+					&context,
+					&pair.public(),
+				)
+				.unwrap()
+			})
+			.collect()
+	}
+}
+
+#[overseer::subsystem(StatementDistribution, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockStatementDistribution {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(StatementDistribution, prefix = self::overseer)]
+impl MockStatementDistribution {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => {
+					gum::trace!(target: LOG_TARGET, msg=?msg, "recv message");
+
+					match msg {
+						StatementDistributionMessage::Share(relay_parent, statement) => {
+							let StatementWithPVD::Seconded(receipt, _) = statement.payload() else {
+								continue
+							};
+							for statement in self.group_statements(
+								relay_parent,
+								receipt.hash(),
+								statement.validator_index(),
+							) {
+								ctx.send_message(CandidateBackingMessage::Statement(
+									relay_parent,
+									statement,
+								))
+								.await;
+							}
+						},
+						StatementDistributionMessage::Backed(candidate_hash) => {
+							self.backed_candidates
+								.lock()
+								.unwrap()
+								.entry(candidate_hash)
+								.or_insert_with(Instant::now);
+						},
+						_ => {
+							// Nothing else is expected from the subsystems under test.
+						},
+					}
+				},
+			}
+		}
+	}
+}
@@ -98,6 +98,7 @@ fn main() -> Result<()> {
 						prepare_workers_hard_max_num: None,
 						prepare_workers_soft_max_num: None,
 						enable_approval_voting_parallel: false,
+						enable_adaptive_chunk_recovery: false,
 					},
 				)
 				.map_err(|e| e.to_string())?;
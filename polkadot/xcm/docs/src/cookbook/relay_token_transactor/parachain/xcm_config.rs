@@ -174,6 +174,7 @@ impl pallet_xcm::Config for Runtime {
 	type TrustedLockers = ();
 	type MaxLockers = frame::traits::ConstU32<0>;
 	type MaxRemoteLockConsumers = frame::traits::ConstU32<0>;
+	type MaxTrappedAssetsIndexed = frame::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
 	// How to turn locations into accounts
 	type SovereignAccountOf = LocationToAccountId;
@@ -597,6 +597,74 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn drop_assets() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ExecuteXcmOrigin::try_origin(RawOrigin::Signed(whitelisted_caller()).into())
+				.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		// The traps are as large as possible, so reading the pruned records and writing the new
+		// one is the most expensive.
+		let assets = |seed: u32| -> Assets {
+			(0..xcm::latest::MAX_ITEMS_IN_ASSETS as u32)
+				.map(|i| Asset {
+					id: AssetId(Location::new(
+						0,
+						[GeneralIndex(seed.into()), GeneralIndex(i.into())],
+					)),
+					fun: Fungible(u128::MAX),
+				})
+				.collect::<Vec<_>>()
+				.into()
+		};
+		// Fill the trapped assets index, so the oldest traps are pruned.
+		let tail = T::MaxTrappedAssetsIndexed::get();
+		for position in tail..tail.saturating_add(MAX_TRAPPED_ASSETS_PRUNED) {
+			let assets = VersionedAssets::from(assets(position));
+			let hash = BlakeTwo256::hash_of(&(&origin, &assets));
+			pallet::AssetTraps::<T>::insert(hash, 1);
+			pallet::TrappedAssetsIndexQueue::<T>::insert(position, (origin.clone(), hash));
+			pallet::TrappedAssetsIndex::<T>::insert(
+				&origin,
+				hash,
+				TrappedAssetsRecord {
+					assets,
+					trapped_at: frame_system::Pallet::<T>::block_number(),
+					position,
+				},
+			);
+		}
+		pallet::TrappedAssetsIndexTail::<T>::put(tail);
+		pallet::TrappedAssetsIndexHead::<T>::put(
+			tail.saturating_add(T::MaxTrappedAssetsIndexed::get())
+				.saturating_add(MAX_TRAPPED_ASSETS_PRUNED),
+		);
+		let dropped: AssetsInHolding = assets(u32::MAX).into();
+
+		#[block]
+		{
+			crate::Pallet::<T>::drop_assets(
+				&origin,
+				dropped,
+				&XcmContext { origin: None, message_id: [0u8; 32], topic: None },
+			);
+		}
+
+		// The oldest traps were pruned from the index, and the dropped assets were indexed.
+		let pruned_tail = tail.saturating_add(MAX_TRAPPED_ASSETS_PRUNED);
+		assert_eq!(pallet::TrappedAssetsIndexTail::<T>::get(), pruned_tail);
+		assert!((tail..pruned_tail)
+			.all(|position| pallet::TrappedAssetsIndexQueue::<T>::get(position).is_none()));
+		let dropped = VersionedAssets::from(assets(u32::MAX));
+		let hash = BlakeTwo256::hash_of(&(&origin, &dropped));
+		assert_eq!(pallet::AssetTraps::<T>::get(hash), 1);
+		assert_eq!(
+			pallet::TrappedAssetsIndex::<T>::contains_key(&origin, hash),
+			T::MaxTrappedAssetsIndexed::get() > 0
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext_with_balances(Vec::new()),
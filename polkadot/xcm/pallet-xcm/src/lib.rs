@@ -46,6 +46,7 @@ use frame_support::{
 use frame_system::pallet_prelude::{BlockNumberFor, *};
 pub use pallet::*;
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::{
 	traits::{
 		AccountIdConversion, BadOrigin, BlakeTwo256, BlockNumberProvider, Dispatchable, Hash,
@@ -63,13 +64,16 @@ use xcm_executor::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
 		DropAssets, MatchesFungible, OnResponse, Properties, QueryHandler, QueryResponseStatus,
 		RecordXcm, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
-		XcmAssetTransfers,
+		XcmAssetTransfers, XcmTraceStep,
 	},
 	AssetsInHolding,
 };
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, Error as XcmDryRunApiError, VersionedXcmTraceStep, XcmDryRunEffects,
+	},
 	fees::Error as XcmPaymentApiError,
+	trapped_assets::{Error as TrappedAssetsApiError, TrappedAssets},
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -98,6 +102,7 @@ pub trait WeightInfo {
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
 	fn claim_assets() -> Weight;
+	fn drop_assets() -> Weight;
 }
 
 /// fallback implementation
@@ -182,6 +187,10 @@ impl WeightInfo for TestWeightInfo {
 	fn claim_assets() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
+
+	fn drop_assets() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[frame_support::pallet]
@@ -292,6 +301,14 @@ pub mod pallet {
 		/// The ID type for local consumers of remote locks.
 		type RemoteLockConsumerIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
 
+		/// The maximum number of asset traps kept in [`TrappedAssetsIndex`], so they can be
+		/// queried by origin. The oldest traps are pruned from the index when it is full.
+		///
+		/// Lowering this value shrinks the index gradually, as at most
+		/// [`MAX_TRAPPED_ASSETS_PRUNED`] traps are pruned for each new trap. Set to zero to
+		/// disable the index.
+		type MaxTrappedAssetsIndexed: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -662,6 +679,50 @@ pub mod pallet {
 	#[pallet::getter(fn asset_trap)]
 	pub(super) type AssetTraps<T: Config> = StorageMap<_, Identity, H256, u32, ValueQuery>;
 
+	/// Details of an asset trap kept in [`TrappedAssetsIndex`].
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct TrappedAssetsRecord<BlockNumber> {
+		/// The trapped assets.
+		pub assets: VersionedAssets,
+		/// The block in which the assets were last trapped.
+		pub trapped_at: BlockNumber,
+		/// The position of the trap in [`TrappedAssetsIndexQueue`].
+		pub position: u32,
+	}
+
+	/// The contents of the most recent asset traps by origin, bounded by
+	/// `Config::MaxTrappedAssetsIndexed`.
+	///
+	/// Keys are the origin and the hash of the trap in [`AssetTraps`]. Entries are removed once
+	/// their trap is fully claimed.
+	#[pallet::storage]
+	pub(super) type TrappedAssetsIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Location,
+		Identity,
+		H256,
+		TrappedAssetsRecord<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Queue of the traps indexed in [`TrappedAssetsIndex`] by position, used to prune the oldest
+	/// entries when the index is full.
+	///
+	/// The queue holds the positions from [`TrappedAssetsIndexTail`] up to, but excluding,
+	/// [`TrappedAssetsIndexHead`].
+	#[pallet::storage]
+	pub(super) type TrappedAssetsIndexQueue<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (Location, H256), OptionQuery>;
+
+	/// The position of the next trap added to [`TrappedAssetsIndexQueue`].
+	#[pallet::storage]
+	pub(super) type TrappedAssetsIndexHead<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The position of the oldest trap in [`TrappedAssetsIndexQueue`].
+	#[pallet::storage]
+	pub(super) type TrappedAssetsIndexTail<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Default version to encode XCM when latest version of destination is unknown. If `None`,
 	/// then the destinations whose XCM version is unknown are considered unreachable.
 	#[pallet::storage]
@@ -797,6 +858,27 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// Whether or not the execution of incoming XCMs (both executed locally and received) should
+	/// be traced.
+	/// Like [`ShouldRecordXcm`], this is meant to be used in runtime APIs, and it's advised it
+	/// stays false for all other use cases.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type ShouldTraceXcm<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// If [`ShouldTraceXcm`] is set to true, the steps of the execution of the XCMs executed
+	/// since it was set.
+	///
+	/// At most [`MAX_XCM_TRACE_STEPS`] steps are kept, the following ones are dropped.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type XcmTrace<T: Config> =
+		StorageValue<_, BoundedVec<XcmTraceStep, ConstU32<MAX_XCM_TRACE_STEPS>>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -1435,6 +1517,15 @@ pub mod pallet {
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
+/// The maximum number of steps kept in the XCM execution trace.
+pub const MAX_XCM_TRACE_STEPS: u32 = 1_000;
+
+/// The maximum number of traps pruned from the trapped assets index for each new trap.
+pub const MAX_TRAPPED_ASSETS_PRUNED: u32 = 2;
+
+/// The maximum number of traps returned by a single [`Pallet::trapped_assets`] query.
+pub const MAX_TRAPPED_ASSETS_PAGE: u32 = 100;
+
 /// Specify how assets used for fees are handled during asset transfers.
 #[derive(Clone, PartialEq)]
 enum FeesHandling<T: Config> {
@@ -2587,6 +2678,70 @@ impl<T: Config> Pallet<T> {
 		Ok(XcmDryRunEffects { forwarded_xcms, emitted_events: events, execution_result: result })
 	}
 
+	/// Like [`Self::dry_run_call`], but also returns the trace of the XCMs executed by the call.
+	///
+	/// The trace is only recorded if this pallet is used as the `XcmRecorder` of the XCM executor.
+	pub fn dry_run_call_with_trace<Runtime, Router, OriginCaller, RuntimeCall>(
+		origin: OriginCaller,
+		call: RuntimeCall,
+	) -> Result<
+		(
+			CallDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>,
+			Vec<VersionedXcmTraceStep>,
+		),
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+		<RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+	{
+		Self::with_xcm_trace::<Runtime, _>(|| {
+			Self::dry_run_call::<Runtime, Router, OriginCaller, RuntimeCall>(origin, call)
+		})
+	}
+
+	/// Like [`Self::dry_run_xcm`], but also returns the trace of the execution of `xcm`.
+	///
+	/// The trace is only recorded if this pallet is used as the `XcmRecorder` of the XCM executor.
+	pub fn dry_run_xcm_with_trace<
+		Runtime,
+		Router,
+		RuntimeCall: Decode + GetDispatchInfo,
+		XcmConfig,
+	>(
+		origin_location: VersionedLocation,
+		xcm: VersionedXcm<RuntimeCall>,
+	) -> Result<
+		(
+			XcmDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>,
+			Vec<VersionedXcmTraceStep>,
+		),
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		XcmConfig: xcm_executor::Config<RuntimeCall = RuntimeCall>,
+	{
+		Self::with_xcm_trace::<Runtime, _>(|| {
+			Self::dry_run_xcm::<Runtime, Router, RuntimeCall, XcmConfig>(origin_location, xcm)
+		})
+	}
+
+	/// Run `dry_run` with XCM tracing enabled and return its effects along with the trace.
+	fn with_xcm_trace<Runtime: crate::Config, Effects>(
+		dry_run: impl FnOnce() -> Result<Effects, XcmDryRunApiError>,
+	) -> Result<(Effects, Vec<VersionedXcmTraceStep>), XcmDryRunApiError> {
+		crate::Pallet::<Runtime>::set_trace_xcm(true);
+		let effects = dry_run();
+		crate::Pallet::<Runtime>::set_trace_xcm(false);
+		let trace = crate::Pallet::<Runtime>::xcm_trace().into_iter().map(Into::into).collect();
+		XcmTrace::<Runtime>::kill();
+		effects.map(|effects| (effects, trace))
+	}
+
 	/// Given a list of asset ids, returns the correct API response for
 	/// `XcmPaymentApi::query_acceptable_payment_assets`.
 	///
@@ -2615,6 +2770,85 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns up to `limit` indexed asset traps of `origin` which haven't been claimed yet,
+	/// starting after the trap `start_after`.
+	///
+	/// The `limit` is capped to [`MAX_TRAPPED_ASSETS_PAGE`].
+	pub fn trapped_assets(
+		origin: VersionedLocation,
+		start_after: Option<H256>,
+		limit: u32,
+	) -> Result<Vec<TrappedAssets<BlockNumberFor<T>>>, TrappedAssetsApiError> {
+		let origin: Location = origin.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::trapped_assets",
+				"Location version conversion failed with error: {:?}",
+				e,
+			);
+			TrappedAssetsApiError::VersionedLocationConversionFailed
+		})?;
+
+		let records = match start_after {
+			Some(hash) => TrappedAssetsIndex::<T>::iter_prefix_from(
+				&origin,
+				TrappedAssetsIndex::<T>::hashed_key_for(&origin, hash),
+			),
+			None => TrappedAssetsIndex::<T>::iter_prefix(&origin),
+		};
+
+		Ok(records
+			.take(limit.min(MAX_TRAPPED_ASSETS_PAGE) as usize)
+			.map(|(hash, record)| TrappedAssets {
+				hash,
+				assets: record.assets,
+				count: AssetTraps::<T>::get(hash),
+				trapped_at: record.trapped_at,
+			})
+			.collect())
+	}
+
+	/// Record the contents of the asset trap `hash` of `origin` in [`TrappedAssetsIndex`],
+	/// pruning the oldest indexed traps if the index is full.
+	fn index_trapped_assets(hash: H256, origin: &Location, assets: &VersionedAssets) {
+		let max = T::MaxTrappedAssetsIndexed::get();
+		let head = TrappedAssetsIndexHead::<T>::get();
+		let mut tail = TrappedAssetsIndexTail::<T>::get();
+
+		// Only a bounded number of traps is pruned at once, so an index above the maximum, e.g.
+		// after lowering it, shrinks over the following traps.
+		for _ in 0..MAX_TRAPPED_ASSETS_PRUNED {
+			if tail == head || head.wrapping_sub(tail) < max {
+				break
+			}
+			if let Some((pruned_origin, pruned)) = TrappedAssetsIndexQueue::<T>::take(tail) {
+				// The pruned trap may have been claimed and trapped again since, in which case it
+				// now lives in a newer position.
+				TrappedAssetsIndex::<T>::mutate_exists(pruned_origin, pruned, |record| {
+					if record.as_ref().map_or(false, |r| r.position == tail) {
+						*record = None;
+					}
+				});
+			}
+			tail = tail.wrapping_add(1);
+		}
+		TrappedAssetsIndexTail::<T>::put(tail);
+
+		if max == 0 {
+			return
+		}
+		TrappedAssetsIndexQueue::<T>::insert(head, (origin.clone(), hash));
+		TrappedAssetsIndex::<T>::insert(
+			origin,
+			hash,
+			TrappedAssetsRecord {
+				assets: assets.clone(),
+				trapped_at: frame_system::Pallet::<T>::block_number(),
+				position: head,
+			},
+		);
+		TrappedAssetsIndexHead::<T>::put(head.wrapping_add(1));
+	}
+
 	/// Given an Asset and a Location, returns if the provided location is a trusted reserve for the
 	/// given asset.
 	pub fn is_trusted_reserve(
@@ -3160,13 +3394,13 @@ impl<T: Config> DropAssets for Pallet<T> {
 		let versioned = VersionedAssets::from(Assets::from(assets));
 		let hash = BlakeTwo256::hash_of(&(&origin, &versioned));
 		AssetTraps::<T>::mutate(hash, |n| *n += 1);
+		Self::index_trapped_assets(hash, origin, &versioned);
 		Self::deposit_event(Event::AssetsTrapped {
 			hash,
 			origin: origin.clone(),
 			assets: versioned,
 		});
-		// TODO #3735: Put the real weight in there.
-		Weight::zero()
+		T::WeightInfo::drop_assets()
 	}
 }
 
@@ -3190,7 +3424,10 @@ impl<T: Config> ClaimAssets for Pallet<T> {
 		let hash = BlakeTwo256::hash_of(&(origin.clone(), versioned.clone()));
 		match AssetTraps::<T>::get(hash) {
 			0 => return false,
-			1 => AssetTraps::<T>::remove(hash),
+			1 => {
+				AssetTraps::<T>::remove(hash);
+				TrappedAssetsIndex::<T>::remove(origin, hash);
+			},
 			n => AssetTraps::<T>::insert(hash, n - 1),
 		}
 		Self::deposit_event(Event::AssetsClaimed {
@@ -3412,6 +3649,26 @@ impl<T: Config> RecordXcm for Pallet<T> {
 	fn record(xcm: Xcm<()>) {
 		RecordedXcm::<T>::put(xcm);
 	}
+
+	fn should_trace() -> bool {
+		ShouldTraceXcm::<T>::get()
+	}
+
+	fn set_trace_xcm(enabled: bool) {
+		if enabled {
+			XcmTrace::<T>::kill();
+		}
+		ShouldTraceXcm::<T>::put(enabled);
+	}
+
+	fn xcm_trace() -> Vec<XcmTraceStep> {
+		XcmTrace::<T>::get().into_inner()
+	}
+
+	fn trace(step: XcmTraceStep) {
+		// The steps beyond the bound are dropped.
+		let _ = XcmTrace::<T>::try_append(step);
+	}
 }
 
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
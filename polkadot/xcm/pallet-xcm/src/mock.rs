@@ -300,6 +300,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type Holder = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
 	type Extra = ();
@@ -522,6 +523,7 @@ pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, A
 
 parameter_types! {
 	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 4;
+	pub static MaxTrappedAssetsIndexed: u32 = 4;
 }
 
 pub struct XcmTeleportFiltered;
@@ -554,6 +556,7 @@ impl pallet_xcm::Config for Test {
 	type CurrencyMatcher = IsConcrete<RelayLocation>;
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
+	type MaxTrappedAssetsIndexed = MaxTrappedAssetsIndexed;
 	type RemoteLockConsumerIdentifier = ();
 	type WeightInfo = TestWeightInfo;
 }
@@ -21,7 +21,10 @@ pub(crate) mod assets_transfer;
 use crate::{
 	migration::data::NeedsMigration,
 	mock::*,
-	pallet::{LockedFungibles, RemoteLockedFungibles, SupportedVersion},
+	pallet::{
+		LockedFungibles, RemoteLockedFungibles, SupportedVersion, TrappedAssetsIndexHead,
+		TrappedAssetsIndexQueue, TrappedAssetsIndexTail,
+	},
 	AssetTraps, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
 	LatestVersionedLocation, Pallet, Queries, QueryStatus, RecordedXcm, RemoteLockedFungibleRecord,
 	ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
@@ -38,9 +41,13 @@ use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{
+		ClaimAssets, DropAssets, FeeReason, Properties, QueryHandler, QueryResponseStatus,
+		RecordXcm, ShouldExecute, XcmTraceStep,
+	},
 	XcmExecutor,
 };
+use xcm_runtime_apis::trapped_assets::TrappedAssets;
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
 const BOB: AccountId = AccountId::new([1u8; 32]);
@@ -523,6 +530,110 @@ fn claim_assets_works() {
 	});
 }
 
+/// Test that asset traps are indexed by origin until they are claimed or pruned from the index.
+#[test]
+fn trapped_assets_are_indexed_and_pruned() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		System::set_block_number(1);
+		let alice: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let bob: Location = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+		let context = XcmContext::with_message_id([0u8; 32]);
+		let trap = |origin: &Location, amount: u128| {
+			<XcmPallet as DropAssets>::drop_assets(
+				origin,
+				Asset::from((Here, amount)).into(),
+				&context,
+			);
+			BlakeTwo256::hash_of(&(origin, VersionedAssets::from(Assets::from((Here, amount)))))
+		};
+		let trapped_assets = |origin: &Location| {
+			XcmPallet::trapped_assets(VersionedLocation::from(origin.clone()), None, u32::MAX)
+				.unwrap()
+		};
+
+		// Trapping the same assets twice keeps a single entry.
+		let alice_hash = trap(&alice, 1);
+		assert_eq!(trap(&alice, 1), alice_hash);
+		let bob_hash = trap(&bob, 2);
+		assert_eq!(
+			trapped_assets(&alice),
+			vec![TrappedAssets {
+				hash: alice_hash,
+				assets: VersionedAssets::from(Assets::from((Here, 1u128))),
+				count: 2,
+				trapped_at: 1,
+			}]
+		);
+		assert_eq!(trapped_assets(&bob).len(), 1);
+
+		// The entry is removed once all the trapped assets are claimed.
+		let assets = Assets::from((Here, 1u128));
+		assert!(<XcmPallet as ClaimAssets>::claim_assets(&alice, &Here.into(), &assets, &context));
+		assert_eq!(trapped_assets(&alice)[0].count, 1);
+		assert!(<XcmPallet as ClaimAssets>::claim_assets(&alice, &Here.into(), &assets, &context));
+		assert_eq!(trapped_assets(&alice), vec![]);
+
+		// The index holds up to 4 traps, the oldest ones are pruned when it is full.
+		System::set_block_number(2);
+		let hashes: Vec<_> = (10..14).map(|amount| trap(&alice, amount)).collect();
+		let mut indexed: Vec<_> = trapped_assets(&alice).into_iter().map(|t| t.hash).collect();
+		indexed.sort();
+		let mut expected = hashes.clone();
+		expected.sort();
+		assert_eq!(indexed, expected);
+		assert!(trapped_assets(&alice).iter().all(|t| t.trapped_at == 2 && t.count == 1));
+		// Pruned traps can still be claimed.
+		assert_eq!(trapped_assets(&bob), vec![]);
+		assert_eq!(AssetTraps::<Test>::get(bob_hash), 1);
+
+		// Lowering the maximum shrinks the index over the following traps.
+		MaxTrappedAssetsIndexed::set(1);
+		trap(&bob, 20);
+		assert_eq!(trapped_assets(&alice).len(), 2);
+		trap(&bob, 21);
+		assert_eq!(trapped_assets(&alice).len(), 0);
+		assert_eq!(trapped_assets(&bob).len(), 2);
+		trap(&bob, 22);
+		assert_eq!(trapped_assets(&bob).len(), 1);
+		assert_eq!(TrappedAssetsIndexQueue::<Test>::iter().count(), 1);
+
+		// Disabling the index prunes the remaining traps.
+		MaxTrappedAssetsIndexed::set(0);
+		trap(&bob, 23);
+		assert_eq!(trapped_assets(&bob), vec![]);
+		assert_eq!(TrappedAssetsIndexQueue::<Test>::iter().count(), 0);
+		assert_eq!(TrappedAssetsIndexHead::<Test>::get(), TrappedAssetsIndexTail::<Test>::get());
+	});
+}
+
+/// Test that the asset traps of an origin are returned in pages.
+#[test]
+fn trapped_assets_are_paginated() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let alice: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let context = XcmContext::with_message_id([0u8; 32]);
+		for amount in 1..4u128 {
+			<XcmPallet as DropAssets>::drop_assets(
+				&alice,
+				Asset::from((Here, amount)).into(),
+				&context,
+			);
+		}
+		let origin = VersionedLocation::from(alice);
+
+		let first_page = XcmPallet::trapped_assets(origin.clone(), None, 2).unwrap();
+		assert_eq!(first_page.len(), 2);
+		let second_page =
+			XcmPallet::trapped_assets(origin.clone(), Some(first_page[1].hash), 2).unwrap();
+		assert_eq!(second_page.len(), 1);
+
+		let mut hashes: Vec<_> = first_page.iter().chain(&second_page).map(|t| t.hash).collect();
+		hashes.sort();
+		hashes.dedup();
+		assert_eq!(hashes.len(), 3);
+	});
+}
+
 /// Test failure to complete execution reverts intermediate side-effects.
 ///
 /// XCM program will withdraw and deposit some assets, then fail execution of a further withdraw.
@@ -1456,3 +1567,64 @@ fn record_xcm_works() {
 		assert_eq!(RecordedXcm::<Test>::get(), Some(message.into()));
 	});
 }
+
+#[test]
+fn trace_xcm_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let message = Xcm::<RuntimeCall>::builder()
+			.withdraw_asset((Here, SEND_AMOUNT))
+			.buy_execution((Here, SEND_AMOUNT), Unlimited)
+			.deposit_asset(AllCounted(1), Junction::AccountId32 { network: None, id: BOB.into() })
+			.build();
+		let execute = || {
+			assert_ok!(XcmPallet::execute(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(message.clone())),
+				BaseXcmWeight::get() * 3,
+			));
+		};
+
+		// By default the execution isn't traced.
+		execute();
+		assert_eq!(XcmPallet::xcm_trace(), vec![]);
+
+		XcmPallet::set_trace_xcm(true);
+		execute();
+		XcmPallet::set_trace_xcm(false);
+		let trace = XcmPallet::xcm_trace();
+		let alice: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		assert_eq!(trace.len(), 4);
+		assert_eq!(trace[0], XcmTraceStep::Barrier { origin: alice, result: Ok(()) });
+		let instructions: Vec<_> = trace[1..]
+			.iter()
+			.map(|step| match step {
+				XcmTraceStep::Instruction(instruction) => instruction.clone(),
+				step => panic!("unexpected trace step {step:?}"),
+			})
+			.collect();
+		let sent: Assets = (Here, SEND_AMOUNT).into();
+		assert_eq!(instructions.iter().map(|i| i.index).collect::<Vec<_>>(), vec![0, 1, 2],);
+		assert_eq!(instructions[0].holding_before, Assets::new());
+		assert_eq!(instructions[0].holding_after, sent);
+		assert_eq!(instructions[2].holding_before, instructions[1].holding_after);
+		assert_eq!(instructions[2].holding_after, Assets::new());
+		assert!(instructions.iter().all(|i| i.error.is_none()));
+
+		// The trace is discarded when tracing is enabled again.
+		XcmPallet::set_trace_xcm(true);
+		assert_eq!(XcmPallet::xcm_trace(), vec![]);
+	});
+}
+
+#[test]
+fn xcm_trace_is_bounded() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		XcmPallet::set_trace_xcm(true);
+		let step = XcmTraceStep::FeesCharged { reason: FeeReason::ChargeFees, fees: Assets::new() };
+		for _ in 0..=crate::MAX_XCM_TRACE_STEPS {
+			XcmPallet::trace(step.clone());
+		}
+		assert_eq!(XcmPallet::xcm_trace().len(), crate::MAX_XCM_TRACE_STEPS as usize);
+	});
+}
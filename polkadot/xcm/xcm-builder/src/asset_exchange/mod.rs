@@ -18,5 +18,5 @@
 //!
 //! E.g. types that implement the [`xcm_executor::traits::AssetExchange`] trait.
 
-mod single_asset_adapter;
+pub(crate) mod single_asset_adapter;
 pub use single_asset_adapter::SingleAssetExchangeAdapter;
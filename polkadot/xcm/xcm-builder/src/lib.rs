@@ -129,6 +129,9 @@ pub use routing::{
 	EnsureDecodableXcm, EnsureDelivery, InspectMessageQueues, WithTopicSource, WithUniqueTopic,
 };
 
+mod swap_trader;
+pub use swap_trader::SwapToTargetTrader;
+
 mod transactional;
 pub use transactional::FrameTransactionalProcessor;
 
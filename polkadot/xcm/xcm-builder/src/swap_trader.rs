@@ -0,0 +1,572 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weight trader paying for execution with any asset that has a liquidity pool against the fee
+//! asset.
+
+extern crate alloc;
+use alloc::vec;
+use core::marker::PhantomData;
+use frame_support::{
+	defensive,
+	traits::{tokens::fungibles, Get, OnUnbalanced as OnUnbalancedT},
+	weights::WeightToFee as WeightToFeeT,
+};
+use pallet_asset_conversion::{QuotePrice, SwapCredit};
+use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	Permill,
+};
+use xcm::latest::{prelude::*, Weight};
+use xcm_executor::{
+	traits::{MatchesFungibles, WeightTrader},
+	AssetsInHolding,
+};
+
+/// Weight trader which accepts any fungible asset that can be swapped into the `Target` fee asset
+/// through `AssetConversion` pools.
+///
+/// On every `buy_weight` the fungible assets of the payment are tried in order and the first one
+/// that has a pool with `Target` and enough balance to cover the fee is used. The amount of it
+/// needed to obtain the fee is quoted from the pool and is then swapped for at least the fee,
+/// everything else is left in the payment. Payments with the `Target` asset itself are rejected
+/// with [`XcmError::FeesNotMet`] so that a plain trader (e.g. [`crate::UsingComponents`]) placed
+/// after this one in a tuple can handle them.
+///
+/// - `MinimumFee` returns the smallest amount of a given asset that is charged per purchase, which
+///   keeps dust swaps out of the pools. Use `()` to not impose any minimums.
+/// - `MaxRefundSlippage` bounds how much worse than the purchase rate a refund may be swapped back
+///   at. A refund is done in the asset used by the last purchase and no refund is given if the pool
+///   has moved beyond the bound in the meantime, e.g. because of a `Transact`.
+///
+/// The accumulated fee is handed to `OnUnbalanced` when the trader is dropped.
+pub struct SwapToTargetTrader<
+	Target: Get<Fungibles::AssetId>,
+	AssetConversion: SwapCredit<
+			AccountId,
+			Balance = u128,
+			AssetKind = Fungibles::AssetId,
+			Credit = fungibles::Credit<AccountId, Fungibles>,
+		> + QuotePrice<Balance = u128, AssetKind = Fungibles::AssetId>,
+	WeightToFee: WeightToFeeT<Balance = u128>,
+	Fungibles: fungibles::Balanced<AccountId, Balance = u128>,
+	Matcher: MatchesFungibles<Fungibles::AssetId, u128>,
+	MinimumFee: Convert<Fungibles::AssetId, u128>,
+	MaxRefundSlippage: Get<Permill>,
+	OnUnbalanced: OnUnbalancedT<fungibles::Credit<AccountId, Fungibles>>,
+	AccountId,
+> {
+	/// Accumulated fee paid for XCM execution, in `Target`.
+	total_fee: fungibles::Credit<AccountId, Fungibles>,
+	/// Asset used by the last purchase, together with the amount paid in it and the amount of
+	/// `Target` obtained for it.
+	last_payment: Option<LastPayment<Fungibles::AssetId>>,
+	_phantom_data: PhantomData<(
+		Target,
+		AssetConversion,
+		WeightToFee,
+		Matcher,
+		MinimumFee,
+		MaxRefundSlippage,
+		OnUnbalanced,
+	)>,
+}
+
+/// What was paid by the last purchase of a [`SwapToTargetTrader`].
+struct LastPayment<FungiblesAssetId> {
+	/// The XCM asset used for the payment.
+	id: AssetId,
+	/// The same asset, as known to the pools.
+	asset: FungiblesAssetId,
+	/// Amount of `asset` swapped.
+	amount_in: u128,
+	/// Amount of the target asset obtained from the swap.
+	amount_out: u128,
+}
+
+impl<
+		Target: Get<Fungibles::AssetId>,
+		AssetConversion: SwapCredit<
+				AccountId,
+				Balance = u128,
+				AssetKind = Fungibles::AssetId,
+				Credit = fungibles::Credit<AccountId, Fungibles>,
+			> + QuotePrice<Balance = u128, AssetKind = Fungibles::AssetId>,
+		WeightToFee: WeightToFeeT<Balance = u128>,
+		Fungibles: fungibles::Balanced<AccountId, Balance = u128>,
+		Matcher: MatchesFungibles<Fungibles::AssetId, u128>,
+		MinimumFee: Convert<Fungibles::AssetId, u128>,
+		MaxRefundSlippage: Get<Permill>,
+		OnUnbalanced: OnUnbalancedT<fungibles::Credit<AccountId, Fungibles>>,
+		AccountId,
+	> WeightTrader
+	for SwapToTargetTrader<
+		Target,
+		AssetConversion,
+		WeightToFee,
+		Fungibles,
+		Matcher,
+		MinimumFee,
+		MaxRefundSlippage,
+		OnUnbalanced,
+		AccountId,
+	>
+{
+	fn new() -> Self {
+		Self {
+			total_fee: fungibles::Credit::<AccountId, Fungibles>::zero(Target::get()),
+			last_payment: None,
+			_phantom_data: PhantomData,
+		}
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: AssetsInHolding,
+		context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		log::trace!(
+			target: "xcm::weight",
+			"SwapToTargetTrader::buy_weight weight: {:?}, payment: {:?}, context: {:?}",
+			weight,
+			payment,
+			context,
+		);
+		let fee = WeightToFee::weight_to_fee(&weight);
+		if fee.is_zero() {
+			return Ok(payment)
+		}
+
+		// `FeesNotMet` lets other traders have a go, `TooExpensive` means we found a pool but
+		// not enough funds.
+		let mut error = XcmError::FeesNotMet;
+		let mut found = None;
+		for (id, balance) in payment.fungible.iter() {
+			let asset: Asset = (id.clone(), *balance).into();
+			let Ok((fungibles_asset, balance)) = Matcher::matches_fungibles(&asset) else {
+				continue
+			};
+			if Target::get() == fungibles_asset {
+				continue
+			}
+			let Some(needed) = <AssetConversion as QuotePrice>::quote_price_tokens_for_exact_tokens(
+				fungibles_asset.clone(),
+				Target::get(),
+				fee,
+				true, // Include fee.
+			) else {
+				log::trace!(
+					target: "xcm::weight",
+					"SwapToTargetTrader::buy_weight no pool for asset {:?}",
+					asset,
+				);
+				continue
+			};
+			let amount_in = needed.max(MinimumFee::convert(fungibles_asset.clone()));
+			if amount_in > balance {
+				log::trace!(
+					target: "xcm::weight",
+					"SwapToTargetTrader::buy_weight asset {:?} is not enough, {:?} needed",
+					asset,
+					amount_in,
+				);
+				error = XcmError::TooExpensive;
+				continue
+			}
+			found = Some((id.clone(), fungibles_asset, amount_in));
+			break
+		}
+		let (id, fungibles_asset, amount_in) = found.ok_or(error)?;
+
+		// The payment was withdrawn from the holding register, so we issue the credit we swap.
+		let credit_in = Fungibles::issue(fungibles_asset.clone(), amount_in);
+		// The quote bounds the amount in, requiring at least `fee` out bounds the price.
+		let credit_out = <AssetConversion as SwapCredit<_>>::swap_exact_tokens_for_tokens(
+			vec![fungibles_asset.clone(), Target::get()],
+			credit_in,
+			Some(fee),
+		)
+		.map_err(|(credit_in, error)| {
+			log::trace!(
+				target: "xcm::weight",
+				"SwapToTargetTrader::buy_weight swap couldn't be done. Error was: {:?}",
+				error,
+			);
+			drop(credit_in);
+			XcmError::FeesNotMet
+		})?;
+		let amount_out = credit_out.peek();
+
+		if let Err(credit_out) = self.total_fee.subsume(credit_out) {
+			// error may occur if `total_fee.asset` differs from `credit_out.asset`, which does
+			// not apply in this context.
+			defensive!(
+				"`total_fee.asset` must be equal to `credit_out.asset`",
+				(self.total_fee.asset(), credit_out.asset())
+			);
+			return Err(XcmError::FeesNotMet)
+		}
+
+		self.last_payment = match self.last_payment.take() {
+			Some(last) if last.asset == fungibles_asset => Some(LastPayment {
+				amount_in: last.amount_in.saturating_add(amount_in),
+				amount_out: last.amount_out.saturating_add(amount_out),
+				..last
+			}),
+			_ =>
+				Some(LastPayment { id: id.clone(), asset: fungibles_asset, amount_in, amount_out }),
+		};
+
+		payment.checked_sub((id, amount_in).into()).map_err(|_| XcmError::TooExpensive)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, context: &XcmContext) -> Option<Asset> {
+		log::trace!(
+			target: "xcm::weight",
+			"SwapToTargetTrader::refund_weight weight: {:?}, context: {:?}, total_fee: {:?}",
+			weight,
+			context,
+			self.total_fee,
+		);
+		let last = self.last_payment.as_mut()?;
+		let refund_amount = WeightToFee::weight_to_fee(&weight)
+			.min(last.amount_out)
+			.min(self.total_fee.peek());
+		if refund_amount.is_zero() {
+			return None
+		}
+
+		// The refund should be swapped at about the rate the fee was bought at.
+		let expected = multiply_by_rational_with_rounding(
+			refund_amount,
+			last.amount_in,
+			last.amount_out,
+			Rounding::Down,
+		)?;
+		let min_out = expected.saturating_sub(MaxRefundSlippage::get() * expected);
+		if min_out.is_zero() {
+			// not worth a swap.
+			return None
+		}
+
+		let refund = self.total_fee.extract(refund_amount);
+		let refund = match <AssetConversion as SwapCredit<_>>::swap_exact_tokens_for_tokens(
+			vec![Target::get(), last.asset.clone()],
+			refund,
+			Some(min_out),
+		) {
+			Ok(refund) => refund,
+			Err((refund, error)) => {
+				log::trace!(
+					target: "xcm::weight",
+					"SwapToTargetTrader::refund_weight swap couldn't be done. Error was: {:?}",
+					error,
+				);
+				// return an attempted refund back to the `total_fee`.
+				let _ = self.total_fee.subsume(refund).map_err(|refund| {
+					// error may occur if `total_fee.asset` differs from `refund.asset`, which does
+					// not apply in this context.
+					defensive!(
+						"`total_fee.asset` must be equal to `refund.asset`",
+						(self.total_fee.asset(), refund.asset())
+					);
+				});
+				return None
+			},
+		};
+
+		let refunded = refund.peek();
+		last.amount_in = last.amount_in.saturating_sub(refunded);
+		last.amount_out = last.amount_out.saturating_sub(refund_amount);
+		// The refund goes back to the holding register, which is not backed by issuance.
+		drop(refund);
+		Some((last.id.clone(), refunded).into())
+	}
+}
+
+impl<
+		Target: Get<Fungibles::AssetId>,
+		AssetConversion: SwapCredit<
+				AccountId,
+				Balance = u128,
+				AssetKind = Fungibles::AssetId,
+				Credit = fungibles::Credit<AccountId, Fungibles>,
+			> + QuotePrice<Balance = u128, AssetKind = Fungibles::AssetId>,
+		WeightToFee: WeightToFeeT<Balance = u128>,
+		Fungibles: fungibles::Balanced<AccountId, Balance = u128>,
+		Matcher: MatchesFungibles<Fungibles::AssetId, u128>,
+		MinimumFee: Convert<Fungibles::AssetId, u128>,
+		MaxRefundSlippage: Get<Permill>,
+		OnUnbalanced: OnUnbalancedT<fungibles::Credit<AccountId, Fungibles>>,
+		AccountId,
+	> Drop
+	for SwapToTargetTrader<
+		Target,
+		AssetConversion,
+		WeightToFee,
+		Fungibles,
+		Matcher,
+		MinimumFee,
+		MaxRefundSlippage,
+		OnUnbalanced,
+		AccountId,
+	>
+{
+	fn drop(&mut self) {
+		if self.total_fee.peek().is_zero() {
+			return
+		}
+		let total_fee = self.total_fee.extract(self.total_fee.peek());
+		OnUnbalanced::on_unbalanced(total_fee);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::asset_exchange::single_asset_adapter::mock::*;
+	use frame_support::{
+		assert_ok, parameter_types,
+		traits::{
+			fungible::NativeOrWithId,
+			fungibles::{Inspect, Mutate},
+			Equals,
+		},
+		weights::IdentityFee,
+	};
+	use sp_runtime::traits::TryConvertInto;
+
+	parameter_types! {
+		pub const MaxRefundSlippage: Permill = Permill::from_percent(1);
+		pub FeeCollector: AccountId = 10;
+	}
+
+	pub struct MinimumFee;
+	impl Convert<NativeOrWithId<u32>, u128> for MinimumFee {
+		fn convert(asset: NativeOrWithId<u32>) -> u128 {
+			match asset {
+				NativeOrWithId::WithId(1) => 1_000,
+				_ => 0,
+			}
+		}
+	}
+
+	pub struct DepositToFeeCollector;
+	impl OnUnbalancedT<fungibles::Credit<AccountId, NativeAndAssets>> for DepositToFeeCollector {
+		fn on_nonzero_unbalanced(credit: fungibles::Credit<AccountId, NativeAndAssets>) {
+			let _ =
+				<NativeAndAssets as fungibles::Balanced<_>>::resolve(&FeeCollector::get(), credit);
+		}
+	}
+
+	type Trader = SwapToTargetTrader<
+		Native,
+		AssetConversion,
+		IdentityFee<u128>,
+		NativeAndAssets,
+		crate::MatchedConvertedConcreteId<
+			NativeOrWithId<u32>,
+			Balance,
+			(crate::StartsWith<TrustBackedAssetsPalletLocation>, Equals<HereLocation>),
+			LocationToAssetId,
+			TryConvertInto,
+		>,
+		MinimumFee,
+		MaxRefundSlippage,
+		DepositToFeeCollector,
+		AccountId,
+	>;
+
+	fn asset_1() -> Location {
+		[PalletInstance(TrustBackedAssetsPalletIndex::get()), GeneralIndex(1)].into()
+	}
+
+	fn asset_2() -> Location {
+		[PalletInstance(TrustBackedAssetsPalletIndex::get()), GeneralIndex(2)].into()
+	}
+
+	fn quote(fee: u128) -> u128 {
+		AssetConversion::quote_price_tokens_for_exact_tokens(
+			NativeOrWithId::WithId(1),
+			NativeOrWithId::Native,
+			fee,
+			true,
+		)
+		.unwrap()
+	}
+
+	fn context() -> XcmContext {
+		XcmContext { origin: None, message_id: XcmHash::default(), topic: None }
+	}
+
+	#[test]
+	fn buys_weight_with_pool_backed_asset() {
+		new_test_ext().execute_with(|| {
+			let fee = 2_000_000;
+			let needed = quote(fee);
+			let payment: AssetsInHolding = Asset::from((asset_1(), 10_000_000)).into();
+
+			let mut trader = Trader::new();
+			let unused = trader
+				.buy_weight(Weight::from_parts(fee as u64, 0), payment, &context())
+				.unwrap();
+			assert_eq!(unused.fungible.get(&AssetId(asset_1())), Some(&(10_000_000 - needed)));
+			drop(trader);
+
+			assert!(
+				<NativeAndAssets as Inspect<_>>::balance(Native::get(), &FeeCollector::get()) >=
+					fee
+			);
+		});
+	}
+
+	#[test]
+	fn skips_assets_without_pool() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetsPallet::force_create(RuntimeOrigin::root(), 2, 0, false, 1));
+			assert_ok!(AssetsPallet::mint_into(2, &0, INITIAL_BALANCE));
+			let fee = 1_000_000;
+			let mut payment: AssetsInHolding = Asset::from((asset_2(), 10_000_000)).into();
+			payment.subsume((asset_1(), 10_000_000).into());
+
+			let mut trader = Trader::new();
+			let unused = trader
+				.buy_weight(Weight::from_parts(fee as u64, 0), payment, &context())
+				.unwrap();
+			assert_eq!(unused.fungible.get(&AssetId(asset_2())), Some(&10_000_000));
+			assert_eq!(unused.fungible.get(&AssetId(asset_1())), Some(&(10_000_000 - quote(fee))));
+		});
+	}
+
+	#[test]
+	fn rejects_target_and_unknown_assets() {
+		new_test_ext().execute_with(|| {
+			let mut trader = Trader::new();
+			assert_eq!(
+				trader.buy_weight(
+					Weight::from_parts(1_000, 0),
+					Asset::from((Here, 10_000_000)).into(),
+					&context()
+				),
+				Err(XcmError::FeesNotMet)
+			);
+			assert_eq!(
+				trader.buy_weight(
+					Weight::from_parts(1_000, 0),
+					Asset::from((asset_2(), 10_000_000)).into(),
+					&context()
+				),
+				Err(XcmError::FeesNotMet)
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_insufficient_payment() {
+		new_test_ext().execute_with(|| {
+			let fee = 2_000_000;
+			let mut trader = Trader::new();
+			assert_eq!(
+				trader.buy_weight(
+					Weight::from_parts(fee as u64, 0),
+					Asset::from((asset_1(), quote(fee) - 1)).into(),
+					&context()
+				),
+				Err(XcmError::TooExpensive)
+			);
+		});
+	}
+
+	#[test]
+	fn charges_minimum_fee() {
+		new_test_ext().execute_with(|| {
+			let mut trader = Trader::new();
+			assert!(quote(10) < MinimumFee::convert(NativeOrWithId::WithId(1)));
+			let unused = trader
+				.buy_weight(
+					Weight::from_parts(10, 0),
+					Asset::from((asset_1(), 10_000)).into(),
+					&context(),
+				)
+				.unwrap();
+			assert_eq!(unused.fungible.get(&AssetId(asset_1())), Some(&9_000));
+		});
+	}
+
+	#[test]
+	fn refunds_in_original_asset() {
+		new_test_ext().execute_with(|| {
+			let fee = 2_000_000;
+			let needed = quote(fee);
+			let mut trader = Trader::new();
+			trader
+				.buy_weight(
+					Weight::from_parts(fee as u64, 0),
+					Asset::from((asset_1(), needed)).into(),
+					&context(),
+				)
+				.unwrap();
+
+			let refund = trader.refund_weight(Weight::from_parts(fee as u64 / 2, 0), &context());
+			let Some(Asset { id, fun: Fungible(amount) }) = refund else {
+				panic!("expected a fungible refund, got {:?}", refund)
+			};
+			assert_eq!(id, AssetId(asset_1()));
+			let expected = needed / 2;
+			assert!(amount >= expected - MaxRefundSlippage::get() * expected);
+
+			// Can't refund more than what was bought.
+			let refund = trader.refund_weight(Weight::from_parts(fee as u64, 0), &context());
+			assert!(matches!(refund, Some(Asset { fun: Fungible(amount), .. }) if amount < needed));
+			assert_eq!(trader.refund_weight(Weight::from_parts(1, 0), &context()), None);
+		});
+	}
+
+	#[test]
+	fn no_refund_beyond_slippage() {
+		new_test_ext().execute_with(|| {
+			let fee = 2_000_000;
+			let mut trader = Trader::new();
+			trader
+				.buy_weight(
+					Weight::from_parts(fee as u64, 0),
+					Asset::from((asset_1(), 10_000_000)).into(),
+					&context(),
+				)
+				.unwrap();
+
+			// Someone buys up asset 1, which makes refunds in it a lot more expensive.
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(1),
+				vec![Box::new(NativeOrWithId::Native), Box::new(NativeOrWithId::WithId(1))],
+				10_000_000,
+				1,
+				1,
+				false,
+			));
+
+			assert_eq!(trader.refund_weight(Weight::from_parts(fee as u64, 0), &context()), None);
+			drop(trader);
+			// Nothing was lost, the whole fee was collected.
+			assert!(
+				<NativeAndAssets as Inspect<_>>::balance(Native::get(), &FeeCollector::get()) >=
+					fee
+			);
+		});
+	}
+}
@@ -40,7 +40,7 @@ use traits::{
 	XcmAssetTransfers,
 };
 
-pub use traits::RecordXcm;
+pub use traits::{InstructionTrace, RecordXcm, XcmTraceStep};
 
 mod assets;
 pub use assets::AssetsInHolding;
@@ -93,6 +93,8 @@ pub struct XcmExecutor<Config: config::Config> {
 	/// Stores the current message's weight.
 	message_weight: Weight,
 	asset_claimer: Option<Location>,
+	/// Whether the execution of the message is traced, read once from `Config::XcmRecorder`.
+	should_trace: bool,
 	_config: PhantomData<Config>,
 }
 
@@ -248,12 +250,21 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			Config::XcmRecorder::record(message.clone().into());
 		}
 
-		if let Err(e) = Config::Barrier::should_execute(
+		let should_trace = Config::XcmRecorder::should_trace();
+
+		let barrier_result = Config::Barrier::should_execute(
 			&origin,
 			message.inner_mut(),
 			xcm_weight,
 			&mut properties,
-		) {
+		);
+		if should_trace {
+			Config::XcmRecorder::trace(XcmTraceStep::Barrier {
+				origin: origin.clone(),
+				result: barrier_result,
+			});
+		}
+		if let Err(e) = barrier_result {
 			tracing::trace!(
 				target: "xcm::execute",
 				?origin,
@@ -269,6 +280,7 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 
 		let mut vm = Self::new(origin, *id);
 		vm.message_weight = xcm_weight;
+		vm.should_trace = should_trace;
 
 		while !message.0.is_empty() {
 			let result = vm.process(message);
@@ -356,6 +368,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			asset_used_in_buy_execution: None,
 			message_weight: Weight::zero(),
 			asset_claimer: None,
+			should_trace: false,
 			_config: PhantomData,
 		}
 	}
@@ -597,6 +610,12 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			// We just use the assets withdrawn or taken from holding.
 			withdrawn_fee_asset.into()
 		};
+		if self.should_trace {
+			Config::XcmRecorder::trace(XcmTraceStep::FeesCharged {
+				reason: reason.clone(),
+				fees: paid.clone(),
+			});
+		}
 		Config::FeeManager::handle_fee(paid, Some(&self.context), reason);
 		Ok(())
 	}
@@ -810,7 +829,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 							});
 						}
 
-						self.process_instruction(instr)
+						self.process_instruction_traced(i as u32, instr)
 					});
 					if let Err(e) = inst_res {
 						tracing::trace!(target: "xcm::execute", "!!! ERROR: {:?}", e);
@@ -830,6 +849,31 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		result
 	}
 
+	/// Process a single XCM instruction, recording its execution if tracing is enabled.
+	fn process_instruction_traced(
+		&mut self,
+		index: u32,
+		instr: Instruction<Config::RuntimeCall>,
+	) -> Result<(), XcmError> {
+		if !self.should_trace {
+			return self.process_instruction(instr)
+		}
+		let instruction = instr.clone().into();
+		let holding_before = self.holding.clone().into();
+		let fees_before = self.fees.clone().into();
+		let result = self.process_instruction(instr);
+		Config::XcmRecorder::trace(XcmTraceStep::Instruction(InstructionTrace {
+			index,
+			instruction,
+			holding_before,
+			holding_after: self.holding.clone().into(),
+			fees_before,
+			fees_after: self.fees.clone().into(),
+			error: result.clone().err(),
+		}));
+		result
+	}
+
 	/// Process a single XCM instruction, mutating the state of the XCM virtual machine.
 	fn process_instruction(
 		&mut self,
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use xcm::prelude::*;
 
 /// Handle stuff to do with taking fees in certain XCM instructions.
@@ -27,7 +29,7 @@ pub trait FeeManager {
 }
 
 /// Context under which a fee is paid.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
 pub enum FeeReason {
 	/// When a reporting instruction is called.
 	Report,
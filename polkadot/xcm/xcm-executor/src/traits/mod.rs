@@ -51,7 +51,7 @@ pub use hrmp::{
 };
 mod record_xcm;
 mod weight;
-pub use record_xcm::RecordXcm;
+pub use record_xcm::{InstructionTrace, RecordXcm, XcmTraceStep};
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
 pub use weight::{WeightBounds, WeightTrader};
@@ -16,7 +16,43 @@
 
 //! Trait for recording XCMs and a dummy implementation.
 
-use xcm::latest::Xcm;
+use crate::traits::FeeReason;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::traits::ProcessMessageError;
+use scale_info::TypeInfo;
+use xcm::latest::{Assets, Error as XcmError, Instruction, Location, Xcm};
+
+/// The execution of a single instruction, as recorded when tracing is enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct InstructionTrace {
+	/// The index of the instruction in the executed program. The instructions of the error
+	/// handler and appendix are indexed from zero.
+	pub index: u32,
+	/// The executed instruction.
+	pub instruction: Instruction<()>,
+	/// The holding register before executing the instruction.
+	pub holding_before: Assets,
+	/// The holding register after executing the instruction.
+	pub holding_after: Assets,
+	/// The fees register before executing the instruction.
+	pub fees_before: Assets,
+	/// The fees register after executing the instruction.
+	pub fees_after: Assets,
+	/// The error returned by the instruction, if it failed.
+	pub error: Option<XcmError>,
+}
+
+/// A step of the execution of an XCM, as recorded when tracing is enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum XcmTraceStep {
+	/// The barrier decided whether the message from `origin` can be executed.
+	Barrier { origin: Location, result: Result<(), ProcessMessageError> },
+	/// An instruction was executed.
+	Instruction(InstructionTrace),
+	/// Fees were charged by the executor, e.g. to deliver a message.
+	FeesCharged { reason: FeeReason, fees: Assets },
+}
 
 /// Trait for recording XCMs.
 pub trait RecordXcm {
@@ -29,6 +65,19 @@ pub trait RecordXcm {
 	fn recorded_xcm() -> Option<Xcm<()>>;
 	/// Record `xcm`.
 	fn record(xcm: Xcm<()>);
+
+	/// Whether or not we should trace the execution of incoming XCMs.
+	fn should_trace() -> bool {
+		false
+	}
+	/// Enable or disable tracing. Enabling it discards the previous trace.
+	fn set_trace_xcm(_enabled: bool) {}
+	/// Get the steps traced since tracing was enabled.
+	fn xcm_trace() -> Vec<XcmTraceStep> {
+		Vec::new()
+	}
+	/// Record `step` of the execution of an XCM.
+	fn trace(_step: XcmTraceStep) {}
 }
 
 impl RecordXcm for () {
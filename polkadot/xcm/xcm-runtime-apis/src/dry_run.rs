@@ -18,10 +18,14 @@
 //! This API can be used to simulate XCMs and, for example, find the fees
 //! that need to be paid.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use codec::{Decode, Encode};
-use frame_support::pallet_prelude::{DispatchResultWithPostInfo, TypeInfo};
+use frame_support::{
+	pallet_prelude::{DispatchResultWithPostInfo, TypeInfo},
+	traits::ProcessMessageError,
+};
 use xcm::prelude::*;
+use xcm_executor::traits::{FeeReason, InstructionTrace, XcmTraceStep};
 
 /// Effects of dry-running an extrinsic.
 #[derive(Encode, Decode, Debug, TypeInfo)]
@@ -47,6 +51,64 @@ pub struct XcmDryRunEffects<Event> {
 	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
 }
 
+/// A step of the execution of an XCM, see [`XcmTraceStep`].
+///
+/// Locations, assets and instructions are versioned so that the trace can be decoded across XCM
+/// version upgrades.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum VersionedXcmTraceStep {
+	/// The barrier decided whether the message from `origin` can be executed.
+	Barrier { origin: VersionedLocation, result: Result<(), ProcessMessageError> },
+	/// An instruction was executed.
+	Instruction {
+		/// The index of the instruction in the executed program. The instructions of the error
+		/// handler and appendix are indexed from zero.
+		index: u32,
+		/// The executed instruction, as a program made of this instruction only.
+		instruction: VersionedXcm<()>,
+		/// The holding register before executing the instruction.
+		holding_before: VersionedAssets,
+		/// The holding register after executing the instruction.
+		holding_after: VersionedAssets,
+		/// The fees register before executing the instruction.
+		fees_before: VersionedAssets,
+		/// The fees register after executing the instruction.
+		fees_after: VersionedAssets,
+		/// The error returned by the instruction, if it failed.
+		error: Option<XcmError>,
+	},
+	/// Fees were charged by the executor, e.g. to deliver a message.
+	FeesCharged { reason: FeeReason, fees: VersionedAssets },
+}
+
+impl From<XcmTraceStep> for VersionedXcmTraceStep {
+	fn from(step: XcmTraceStep) -> Self {
+		match step {
+			XcmTraceStep::Barrier { origin, result } =>
+				Self::Barrier { origin: origin.into(), result },
+			XcmTraceStep::Instruction(InstructionTrace {
+				index,
+				instruction,
+				holding_before,
+				holding_after,
+				fees_before,
+				fees_after,
+				error,
+			}) => Self::Instruction {
+				index,
+				instruction: Xcm(vec![instruction]).into(),
+				holding_before: holding_before.into(),
+				holding_after: holding_after.into(),
+				fees_before: fees_before.into(),
+				fees_after: fees_after.into(),
+				error,
+			},
+			XcmTraceStep::FeesCharged { reason, fees } =>
+				Self::FeesCharged { reason, fees: fees.into() },
+		}
+	}
+}
+
 sp_api::decl_runtime_apis! {
 	/// API for dry-running extrinsics and XCM programs to get the programs that need to be passed to the fees API.
 	///
@@ -68,6 +130,19 @@ sp_api::decl_runtime_apis! {
 
 		/// Dry run XCM program
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<XcmDryRunEffects<Event>, Error>;
+
+		/// Dry run call, also returning the trace of the XCMs it executes locally.
+		///
+		/// The trace records every executed instruction with the holding and fees registers
+		/// before and after it, the fees charged and the barrier decisions.
+		#[api_version(2)]
+		fn dry_run_call_with_trace(origin: OriginCaller, call: Call) -> Result<(CallDryRunEffects<Event>, Vec<VersionedXcmTraceStep>), Error>;
+
+		/// Dry run XCM program, also returning the trace of its execution.
+		///
+		/// See [`DryRunApi::dry_run_call_with_trace`] for what the trace records.
+		#[api_version(2)]
+		fn dry_run_xcm_with_trace(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<(XcmDryRunEffects<Event>, Vec<VersionedXcmTraceStep>), Error>;
 	}
 }
 
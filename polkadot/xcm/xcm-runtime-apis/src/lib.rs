@@ -34,3 +34,7 @@ pub mod fees;
 // Exposes runtime API for querying whether a Location is trusted as a reserve or teleporter for a
 // given Asset.
 pub mod trusted_query;
+
+/// Trapped assets API.
+/// Given a location, it returns the assets trapped for it which can still be claimed.
+pub mod trapped_assets;
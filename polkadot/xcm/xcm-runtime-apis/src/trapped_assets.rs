@@ -0,0 +1,64 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying the assets trapped by the XCM executor.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use sp_core::H256;
+use xcm::{VersionedAssets, VersionedLocation};
+
+/// Assets trapped for an origin, which can be claimed back with the `ClaimAsset` instruction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct TrappedAssets<BlockNumber> {
+	/// The hash of the (origin, assets) pair, as emitted in the `AssetsTrapped` event.
+	pub hash: H256,
+	/// The trapped assets.
+	pub assets: VersionedAssets,
+	/// The number of times these assets were trapped for the origin.
+	pub count: u32,
+	/// The block in which these assets were last trapped.
+	pub trapped_at: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the assets trapped by the XCM executor.
+	pub trait TrappedAssetsApi<BlockNumber> where BlockNumber: Codec {
+		/// Returns a page of the assets trapped for `origin` which haven't been claimed yet.
+		///
+		/// Only the traps kept in the bounded index of the runtime are returned, the oldest
+		/// traps are pruned from it when it is full. The runtime may return fewer traps than
+		/// requested, the next page starts after the hash of the last returned trap.
+		///
+		/// # Arguments
+		/// * `origin`: `VersionedLocation` the assets were trapped for.
+		/// * `start_after`: The hash of the trap after which the page starts, `None` for the first
+		///   page.
+		/// * `limit`: The maximum number of traps to return.
+		fn trapped_assets(
+			origin: VersionedLocation,
+			start_after: Option<H256>,
+			limit: u32,
+		) -> Result<Vec<TrappedAssets<BlockNumber>>, Error>;
+	}
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a versioned Location structure from one version to another failed.
+	VersionedLocationConversionFailed,
+}
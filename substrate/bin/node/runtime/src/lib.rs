@@ -627,6 +627,12 @@ impl pallet_assets_freezer::Config<AssetsFreezerInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+pub type AssetsHolderInstance = pallet_assets_holder::Instance1;
+impl pallet_assets_holder::Config<AssetsHolderInstance> for Runtime {
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = NativeOrWithId<u32>;
@@ -1734,8 +1740,8 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
-	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
-	// highest number of schedules that encodes less than 2^10.
+	// `VestingInfo` encode length is at most 41bytes. 28 schedules gets encoded as at most 1149
+	// bytes. The limit is kept from when schedules were 36bytes, so existing schedules still fit.
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -1804,6 +1810,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
+	type Holder = AssetsHolder;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -1831,6 +1838,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
+	type Holder = ();
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
@@ -2720,6 +2728,9 @@ mod runtime {
 
 	#[runtime::pallet_index(84)]
 	pub type AssetsFreezer = pallet_assets_freezer::Pallet<Runtime, Instance1>;
+
+	#[runtime::pallet_index(85)]
+	pub type AssetsHolder = pallet_assets_holder::Pallet<Runtime, Instance1>;
 }
 
 impl TryFrom<RuntimeCall> for pallet_revive::Call<Runtime> {
@@ -2818,6 +2829,7 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_vesting::migrations::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -3303,6 +3315,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber> for Runtime
 	{
 		fn balance(address: H160) -> U256 {
@@ -3335,6 +3348,27 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			pallet_revive::evm::trace_tx::<Runtime, _>(
+				block,
+				tx_index,
+				config,
+				|header| { Executive::initialize_block(header); },
+				|ext| { let _ = Executive::apply_extrinsic(ext); },
+			)
+		}
+
+		fn trace_call(
+			tx: pallet_revive::evm::GenericTransaction,
+			config: pallet_revive::evm::TracerConfig
+		) -> Result<pallet_revive::evm::Trace, pallet_revive::EthTransactError> {
+			pallet_revive::evm::trace_call::<Runtime, _>(config, || Self::eth_transact(tx))
+		}
+
 		fn call(
 			origin: AccountId,
 			dest: H160,
@@ -3416,6 +3450,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -3433,6 +3468,11 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn get_pool_kind(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<pallet_asset_conversion::PoolKind> {
+			AssetConversion::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+			AssetConversion::pool_kind(&asset1, &asset2).ok()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -19,10 +19,55 @@
 //! Substrate Statement Store RPC API.
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
 use sp_core::Bytes;
 
 pub mod error;
 
+/// Filter of the statements pushed by the `statement_subscribe` subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StatementFilter {
+	/// Match the statements which include all of the topics.
+	MatchAll(Vec<[u8; 32]>),
+	/// Match the statements which include any of the topics.
+	MatchAny(Vec<[u8; 32]>),
+	/// Match the statements whose decryption key is identified as the given key.
+	DecryptionKey([u8; 32]),
+}
+
+/// Number of valid statements of an account which were not added to the store, by reason.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectedStatements {
+	/// The statement data was larger than the account `max_size`.
+	pub data_too_large: u64,
+	/// The channel of the statement was holding a statement with higher or equal priority.
+	pub channel_priority_too_low: u64,
+	/// The account quota was full with statements of higher or equal priority.
+	pub account_full: u64,
+	/// The store was full.
+	pub store_full: u64,
+}
+
+/// Store usage and quota of an account.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountUsage {
+	/// Number of statements of the account in the store.
+	pub statement_count: u32,
+	/// Total data size of the statements of the account in the store.
+	pub data_size: u64,
+	/// Maximum number of statements, as computed by the runtime. `None` if it is not known.
+	pub max_count: Option<u32>,
+	/// Maximum total data size, as computed by the runtime. `None` if it is not known.
+	pub max_size: Option<u32>,
+	/// Number of statements evicted to make room for higher priority statements.
+	pub evicted: u64,
+	/// Number of statements which were not added to the store.
+	pub rejected: RejectedStatements,
+}
+
 /// Substrate statement RPC API
 #[rpc(client, server)]
 pub trait StatementApi {
@@ -57,4 +102,18 @@ pub trait StatementApi {
 	/// Remove a statement from the store.
 	#[method(name = "statement_remove")]
 	fn remove(&self, statement_hash: [u8; 32]) -> RpcResult<()>;
+
+	/// Return the store usage and quota of an account, along with the number of its statements
+	/// that were evicted or rejected.
+	#[method(name = "statement_accountUsage")]
+	fn account_usage(&self, account: [u8; 32]) -> RpcResult<AccountUsage>;
+
+	/// Subscribe to the statements matching `filter` as they enter the store, either submitted
+	/// locally or received from the network. Statements are SCALE-encoded.
+	#[subscription(
+		name = "statement_subscribe" => "statement_newStatement",
+		unsubscribe = "statement_unsubscribe",
+		item = Bytes
+	)]
+	fn subscribe(&self, filter: StatementFilter);
 }
@@ -113,6 +113,9 @@ pub trait ArchiveApi<Hash> {
 
 	/// Returns the storage difference between two blocks.
 	///
+	/// The differences are reported in lexicographic order of the keys. An interrupted query can
+	/// be resumed by providing the last reported key as the `paginationStartKey` of the items.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and can change in minor or patch releases.
@@ -127,4 +130,27 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Option<Hash>,
 	);
+
+	/// Returns the storage differences introduced by each block in the range between two blocks.
+	///
+	/// The range starts after `start_hash` and ends with `hash`, which must be a descendant of
+	/// `start_hash`. The differences of every block are preceded by a `storageDiffBlock` event and
+	/// are reported as by `archive_unstable_storageDiff`. An interrupted query can be resumed by
+	/// providing the parent of the last reported block as `start_hash` and the last reported key
+	/// as the `paginationStartKey` of the items.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	#[subscription(
+		name = "archive_unstable_storageDiffRange" => "archive_unstable_storageDiffRangeEvent",
+		unsubscribe = "archive_unstable_storageDiffRange_stopStorageDiff",
+		item = ArchiveStorageDiffEvent,
+	)]
+	fn archive_unstable_storage_diff_range(
+		&self,
+		hash: Hash,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		start_hash: Hash,
+	);
 }
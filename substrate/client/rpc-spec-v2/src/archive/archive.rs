@@ -48,7 +48,7 @@ use sp_blockchain::{
 };
 use sp_core::{Bytes, U256};
 use sp_runtime::{
-	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+	traits::{Block as BlockT, CheckedSub, Header as HeaderT, NumberFor},
 	SaturatedConversion,
 };
 use std::{collections::HashSet, marker::PhantomData, sync::Arc};
@@ -63,6 +63,9 @@ pub(crate) const LOG_TARGET: &str = "rpc-spec-v2::archive";
 /// its down buffer capacity per connection as well.
 const STORAGE_QUERY_BUF: usize = 16;
 
+/// The maximum number of blocks reported by a single `archive_unstable_storageDiffRange` query.
+const MAX_STORAGE_DIFF_RANGE: u64 = 256;
+
 /// An API for archive RPC calls.
 pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	/// Substrate client.
@@ -306,6 +309,93 @@ where
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
+
+	fn archive_unstable_storage_diff_range(
+		&self,
+		pending: PendingSubscriptionSink,
+		hash: Block::Hash,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		start_hash: Block::Hash,
+	) {
+		let storage_client = ArchiveStorageDiff::new(self.client.clone());
+		let client = self.client.clone();
+
+		log::trace!(target: LOG_TARGET, "Storage diff range subscription started");
+
+		let fut = async move {
+			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			let blocks = match storage_diff_range(&*client, start_hash, hash) {
+				Ok(blocks) => blocks,
+				Err(message) => {
+					let _ = sink.send(&ArchiveStorageDiffEvent::err(message)).await;
+					return
+				},
+			};
+
+			let (tx, mut rx) = tokio::sync::mpsc::channel(STORAGE_QUERY_BUF);
+			let storage_fut = storage_client.handle_trie_queries_range(blocks, items, tx);
+
+			// We don't care about the return value of this join:
+			// - process_events might encounter an error (if the client disconnected)
+			// - storage_fut might encounter an error while processing a trie queries and
+			// the error is propagated via the sink.
+			let _ =
+				futures::future::join(storage_fut, process_storage_diff_events(&mut rx, &mut sink))
+					.await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Returns the `(parent_hash, hash)` pairs of the blocks after `start_hash` up to and including
+/// `end_hash`, in ascending order.
+fn storage_diff_range<Block, Client>(
+	client: &Client,
+	start_hash: Block::Hash,
+	end_hash: Block::Hash,
+) -> Result<Vec<(Block::Hash, Block::Hash)>, String>
+where
+	Block: BlockT,
+	Client: HeaderBackend<Block>,
+{
+	let header = |hash: Block::Hash| match client.header(hash) {
+		Ok(Some(header)) => Ok(header),
+		Ok(None) => Err(format!("Block header is not present: {hash}")),
+		Err(error) => Err(error.to_string()),
+	};
+
+	let start_number = *header(start_hash)?.number();
+	let end_header = header(end_hash)?;
+	let Some(range) = end_header.number().checked_sub(&start_number) else {
+		return Err(format!("Block {end_hash} is not a descendant of {start_hash}"))
+	};
+	let range: u64 = range.saturated_into();
+	if range > MAX_STORAGE_DIFF_RANGE {
+		return Err(format!(
+			"Block range of {range} blocks exceeds the maximum of {MAX_STORAGE_DIFF_RANGE}"
+		))
+	}
+
+	// Walk back from the end block, the block reached after `range` steps must be the start block.
+	let mut blocks = Vec::with_capacity(range as usize);
+	let mut hash = end_hash;
+	let mut parent_hash = *end_header.parent_hash();
+	for step in 0..range {
+		if step > 0 {
+			parent_hash = *header(hash)?.parent_hash();
+		}
+		blocks.push((parent_hash, hash));
+		hash = parent_hash;
+	}
+
+	if hash != start_hash {
+		return Err(format!("Block {end_hash} is not a descendant of {start_hash}"))
+	}
+
+	blocks.reverse();
+	Ok(blocks)
 }
 
 /// Sends all the events of the storage_diff method to the sink.
@@ -32,8 +32,9 @@ use crate::{
 	archive::archive::LOG_TARGET,
 	common::{
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-			ArchiveStorageDiffResult, ArchiveStorageDiffType, StorageResult,
+			ArchiveStorageDiffBlock, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+			ArchiveStorageDiffOperationType, ArchiveStorageDiffResult, ArchiveStorageDiffType,
+			StorageResult,
 		},
 		storage::Storage,
 	},
@@ -58,6 +59,7 @@ pub struct DiffDetails {
 	return_type: ArchiveStorageDiffType,
 	child_trie_key: Option<ChildInfo>,
 	child_trie_key_string: Option<String>,
+	pagination_start_key: Option<StorageKey>,
 }
 
 /// The type of storage query.
@@ -138,7 +140,8 @@ where
 	/// Check if the key belongs to the provided query items.
 	///
 	/// A key belongs to the query items when:
-	/// - the provided key is a prefix of the key in the query items.
+	/// - the provided key is a prefix of the key in the query items and the key is greater than the
+	///   pagination start key of the item, if any.
 	/// - the query items are empty.
 	///
 	/// Returns an optional `FetchStorageType` based on the query items.
@@ -153,6 +156,10 @@ where
 		let mut hash = false;
 
 		for item in items {
+			if item.pagination_start_key.as_ref().is_some_and(|start_key| key <= start_key) {
+				continue
+			}
+
 			if key.as_ref().starts_with(&item.key.as_ref()) {
 				match item.return_type {
 					ArchiveStorageDiffType::Value => value = true,
@@ -211,12 +218,20 @@ where
 		let maybe_child_trie_str =
 			items.first().and_then(|item| item.child_trie_key_string.clone());
 
+		// When all the items are paginated, the keys before the smallest pagination start key
+		// don't belong to any of the items.
+		let start_at = items
+			.iter()
+			.map(|item| item.pagination_start_key.as_ref())
+			.collect::<Option<Vec<_>>>()
+			.and_then(|start_keys| start_keys.into_iter().min());
+
 		// Iterator over the current block and previous block
 		// at the same time to compare the keys. This approach effectively
 		// leverages backpressure to avoid memory consumption.
-		let keys_iter = self.client.raw_keys_iter(hash, maybe_child_trie.clone())?;
+		let keys_iter = self.client.raw_keys_iter(hash, maybe_child_trie.clone(), start_at)?;
 		let previous_keys_iter =
-			self.client.raw_keys_iter(previous_hash, maybe_child_trie.clone())?;
+			self.client.raw_keys_iter(previous_hash, maybe_child_trie.clone(), start_at)?;
 
 		let mut diff_iter = lexicographic_diff(keys_iter, previous_keys_iter);
 
@@ -295,6 +310,32 @@ where
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Block::Hash,
 		tx: mpsc::Sender<ArchiveStorageDiffEvent>,
+	) -> Result<(), tokio::task::JoinError> {
+		self.handle_blocks_trie_queries(vec![(previous_hash, hash)], items, false, tx)
+			.await
+	}
+
+	/// Similar to [`Self::handle_trie_queries`], but reports the storage differences introduced
+	/// by each of the provided `(previous_hash, hash)` pairs, in order.
+	///
+	/// The differences of every block are preceded by a `storageDiffBlock` event. The pagination
+	/// start keys of the items only apply to the first block, such that an interrupted query can
+	/// be resumed from the last reported block and key.
+	pub async fn handle_trie_queries_range(
+		&self,
+		blocks: Vec<(Block::Hash, Block::Hash)>,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		tx: mpsc::Sender<ArchiveStorageDiffEvent>,
+	) -> Result<(), tokio::task::JoinError> {
+		self.handle_blocks_trie_queries(blocks, items, true, tx).await
+	}
+
+	async fn handle_blocks_trie_queries(
+		&self,
+		blocks: Vec<(Block::Hash, Block::Hash)>,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		report_blocks: bool,
+		tx: mpsc::Sender<ArchiveStorageDiffEvent>,
 	) -> Result<(), tokio::task::JoinError> {
 		let this = ArchiveStorageDiff { client: self.client.clone() };
 
@@ -313,32 +354,54 @@ where
 			}
 			log::trace!(target: LOG_TARGET, "Storage diff deduplicated items: {:?}", trie_items);
 
-			for items in trie_items {
-				log::trace!(
-					target: LOG_TARGET,
-					"handle_trie_queries: hash={:?}, previous_hash={:?}, items={:?}",
-					hash,
-					previous_hash,
-					items
-				);
+			for (index, (previous_hash, hash)) in blocks.into_iter().enumerate() {
+				// The pagination only applies to the first block of the range.
+				if index == 1 {
+					trie_items
+						.iter_mut()
+						.flatten()
+						.for_each(|item| item.pagination_start_key = None);
+				}
 
-				let result = this.handle_trie_queries_inner(hash, previous_hash, items, &tx);
+				if report_blocks {
+					let event =
+						ArchiveStorageDiffEvent::StorageDiffBlock(ArchiveStorageDiffBlock {
+							hash: format!("{:?}", hash),
+						});
+					if tx.blocking_send(event).is_err() {
+						return
+					}
+				}
 
-				if let Err(error) = result {
+				for items in trie_items.iter().cloned() {
 					log::trace!(
 						target: LOG_TARGET,
-						"handle_trie_queries: sending error={:?}",
-						error,
+						"handle_trie_queries: hash={:?}, previous_hash={:?}, items={:?}",
+						hash,
+						previous_hash,
+						items
 					);
 
-					let _ = tx.blocking_send(ArchiveStorageDiffEvent::err(error));
-
-					return
-				} else {
-					log::trace!(
-						target: LOG_TARGET,
-						"handle_trie_queries: sending storage diff done",
-					);
+					let result = this.handle_trie_queries_inner(hash, previous_hash, items, &tx);
+
+					if let Err(error) = result {
+						log::trace!(
+							target: LOG_TARGET,
+							"handle_trie_queries: sending error={:?}",
+							error,
+						);
+
+						let _ = tx.blocking_send(ArchiveStorageDiffEvent::err(error));
+
+						return
+					} else if tx.is_closed() {
+						return
+					} else {
+						log::trace!(
+							target: LOG_TARGET,
+							"handle_trie_queries: sending storage diff done",
+						);
+					}
 				}
 			}
 
@@ -419,11 +482,18 @@ fn deduplicate_storage_diff_items(
 			.transpose()?
 			.map(ChildInfo::new_default_from_vec);
 
+		let pagination_start_key = diff_item
+			.pagination_start_key
+			.map(|start_key| parse_hex_param(start_key))
+			.transpose()?
+			.map(StorageKey);
+
 		let diff_item = DiffDetails {
 			key,
 			return_type: diff_item.return_type,
 			child_trie_key: child_trie_key.clone(),
 			child_trie_key_string,
+			pagination_start_key,
 		};
 
 		match deduplicated.entry(child_trie_key.clone()) {
@@ -431,8 +501,10 @@ fn deduplicate_storage_diff_items(
 				let mut should_insert = true;
 
 				for existing in entry.get() {
-					// This points to a different return type.
-					if existing.return_type != diff_item.return_type {
+					// This points to a different return type or resumes from a different key.
+					if existing.return_type != diff_item.return_type ||
+						existing.pagination_start_key != diff_item.pagination_start_key
+					{
 						continue
 					}
 					// Keys and return types are identical.
@@ -479,6 +551,44 @@ fn deduplicate_storage_diff_items(
 mod tests {
 	use super::*;
 
+	#[test]
+	fn dedup_with_different_pagination_start_keys() {
+		let items = vec![
+			ArchiveStorageDiffItem {
+				key: "0x01".into(),
+				return_type: ArchiveStorageDiffType::Value,
+				child_trie_key: None,
+				pagination_start_key: None,
+			},
+			ArchiveStorageDiffItem {
+				key: "0x0102".into(),
+				return_type: ArchiveStorageDiffType::Value,
+				child_trie_key: None,
+				pagination_start_key: Some("0x010203".into()),
+			},
+		];
+		let result = deduplicate_storage_diff_items(items).unwrap();
+		assert_eq!(result.len(), 1);
+
+		let expected = vec![
+			DiffDetails {
+				key: StorageKey(vec![1]),
+				return_type: ArchiveStorageDiffType::Value,
+				child_trie_key: None,
+				child_trie_key_string: None,
+				pagination_start_key: None,
+			},
+			DiffDetails {
+				key: StorageKey(vec![1, 2]),
+				return_type: ArchiveStorageDiffType::Value,
+				child_trie_key: None,
+				child_trie_key_string: None,
+				pagination_start_key: Some(StorageKey(vec![1, 2, 3])),
+			},
+		];
+		assert_eq!(result[0], expected);
+	}
+
 	#[test]
 	fn dedup_empty() {
 		let items = vec![];
@@ -492,6 +602,7 @@ mod tests {
 			key: "0x01".into(),
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
+			pagination_start_key: None,
 		}];
 		let result = deduplicate_storage_diff_items(items).unwrap();
 		assert_eq!(result.len(), 1);
@@ -502,6 +613,7 @@ mod tests {
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
 			child_trie_key_string: None,
+			pagination_start_key: None,
 		};
 		assert_eq!(result[0][0], expected);
 	}
@@ -513,11 +625,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x02".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -530,12 +644,14 @@ mod tests {
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
 				child_trie_key_string: None,
+				pagination_start_key: None,
 			},
 			DiffDetails {
 				key: StorageKey(vec![2]),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
 				child_trie_key_string: None,
+				pagination_start_key: None,
 			},
 		];
 		assert_eq!(result[0], expected);
@@ -549,11 +665,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -565,6 +683,7 @@ mod tests {
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
 			child_trie_key_string: None,
+			pagination_start_key: None,
 		}];
 		assert_eq!(result[0], expected);
 	}
@@ -577,11 +696,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01ff".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -593,6 +714,7 @@ mod tests {
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
 			child_trie_key_string: None,
+			pagination_start_key: None,
 		}];
 		assert_eq!(result[0], expected);
 	}
@@ -604,11 +726,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Hash,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -621,12 +745,14 @@ mod tests {
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
 				child_trie_key_string: None,
+				pagination_start_key: None,
 			},
 			DiffDetails {
 				key: StorageKey(vec![1]),
 				return_type: ArchiveStorageDiffType::Hash,
 				child_trie_key: None,
 				child_trie_key_string: None,
+				pagination_start_key: None,
 			},
 		];
 		assert_eq!(result[0], expected);
@@ -639,11 +765,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x01".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x02".into()),
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -657,12 +785,14 @@ mod tests {
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some(ChildInfo::new_default_from_vec(vec![1])),
 				child_trie_key_string: Some("0x01".into()),
+				pagination_start_key: None,
 			}],
 			vec![DiffDetails {
 				key: StorageKey(vec![1]),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some(ChildInfo::new_default_from_vec(vec![2])),
 				child_trie_key_string: Some("0x02".into()),
+				pagination_start_key: None,
 			}],
 		];
 		assert_eq!(result, expected);
@@ -675,11 +805,13 @@ mod tests {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x01".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x01".into()),
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -691,6 +823,7 @@ mod tests {
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: Some(ChildInfo::new_default_from_vec(vec![1])),
 			child_trie_key_string: Some("0x01".into()),
+			pagination_start_key: None,
 		}];
 		assert_eq!(result[0], expected);
 	}
@@ -702,11 +835,13 @@ mod tests {
 				key: "0x01ff".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 		];
 		let result = deduplicate_storage_diff_items(items).unwrap();
@@ -718,6 +853,7 @@ mod tests {
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
 			child_trie_key_string: None,
+			pagination_start_key: None,
 		}];
 		assert_eq!(result[0], expected);
 	}
@@ -729,31 +865,37 @@ mod tests {
 				key: "0x02".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x01".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x02".into(),
 				return_type: ArchiveStorageDiffType::Hash,
 				child_trie_key: Some("0x01".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x02".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01".into(),
 				return_type: ArchiveStorageDiffType::Hash,
 				child_trie_key: Some("0x02".into()),
+				pagination_start_key: None,
 			},
 			ArchiveStorageDiffItem {
 				key: "0x01ff".into(),
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: Some("0x02".into()),
+				pagination_start_key: None,
 			},
 		];
 
@@ -765,6 +907,7 @@ mod tests {
 				return_type: ArchiveStorageDiffType::Value,
 				child_trie_key: None,
 				child_trie_key_string: None,
+				pagination_start_key: None,
 			}],
 			vec![
 				DiffDetails {
@@ -772,12 +915,14 @@ mod tests {
 					return_type: ArchiveStorageDiffType::Value,
 					child_trie_key: Some(ChildInfo::new_default_from_vec(vec![1])),
 					child_trie_key_string: Some("0x01".into()),
+					pagination_start_key: None,
 				},
 				DiffDetails {
 					key: StorageKey(vec![2]),
 					return_type: ArchiveStorageDiffType::Hash,
 					child_trie_key: Some(ChildInfo::new_default_from_vec(vec![1])),
 					child_trie_key_string: Some("0x01".into()),
+					pagination_start_key: None,
 				},
 			],
 			vec![
@@ -786,12 +931,14 @@ mod tests {
 					return_type: ArchiveStorageDiffType::Value,
 					child_trie_key: Some(ChildInfo::new_default_from_vec(vec![2])),
 					child_trie_key_string: Some("0x02".into()),
+					pagination_start_key: None,
 				},
 				DiffDetails {
 					key: StorageKey(vec![1]),
 					return_type: ArchiveStorageDiffType::Hash,
 					child_trie_key: Some(ChildInfo::new_default_from_vec(vec![2])),
 					child_trie_key_string: Some("0x02".into()),
+					pagination_start_key: None,
 				},
 			],
 		];
@@ -18,9 +18,9 @@
 
 use crate::{
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-		ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery,
-		StorageQueryType, StorageResult, StorageResultType,
+		ArchiveStorageDiffBlock, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+		ArchiveStorageDiffOperationType, ArchiveStorageDiffResult, ArchiveStorageDiffType,
+		ArchiveStorageEvent, StorageQuery, StorageQueryType, StorageResult, StorageResultType,
 	},
 	hex_string, MethodResult,
 };
@@ -782,11 +782,13 @@ async fn archive_storage_diff_main_trie() {
 			key: hex_string(b":A"),
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
+			pagination_start_key: None,
 		},
 		ArchiveStorageDiffItem::<String> {
 			key: hex_string(b":AA"),
 			return_type: ArchiveStorageDiffType::Hash,
 			child_trie_key: None,
+			pagination_start_key: None,
 		},
 	];
 	let mut sub = api
@@ -857,6 +859,74 @@ async fn archive_storage_diff_main_trie() {
 	assert_eq!(ArchiveStorageDiffEvent::StorageDiffDone, event);
 }
 
+#[tokio::test]
+async fn archive_storage_diff_paginated() {
+	let (client, api) = setup_api();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":A".to_vec(), Some(b"B".to_vec())).unwrap();
+	builder.push_storage_change(b":AA".to_vec(), Some(b"BB".to_vec())).unwrap();
+	let prev_block = builder.build().unwrap().block;
+	let prev_hash = format!("{:?}", prev_block.header.hash());
+	client.import(BlockOrigin::Own, prev_block.clone()).await.unwrap();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(prev_block.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":A".to_vec(), Some(b"11".to_vec())).unwrap();
+	builder.push_storage_change(b":AA".to_vec(), Some(b"22".to_vec())).unwrap();
+	builder.push_storage_change(b":AAA".to_vec(), Some(b"222".to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	// Resume the iteration of the keys under ":A" after ":A".
+	let items = vec![ArchiveStorageDiffItem::<String> {
+		key: hex_string(b":A"),
+		return_type: ArchiveStorageDiffType::Value,
+		child_trie_key: None,
+		pagination_start_key: Some(hex_string(b":A")),
+	}];
+	let mut sub = api
+		.subscribe_unbounded(
+			"archive_unstable_storageDiff",
+			rpc_params![&block_hash, items.clone(), &prev_hash],
+		)
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiff(ArchiveStorageDiffResult {
+			key: hex_string(b":AA"),
+			result: StorageResultType::Value(hex_string(b"22")),
+			operation_type: ArchiveStorageDiffOperationType::Modified,
+			child_trie_key: None,
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiff(ArchiveStorageDiffResult {
+			key: hex_string(b":AAA"),
+			result: StorageResultType::Value(hex_string(b"222")),
+			operation_type: ArchiveStorageDiffOperationType::Added,
+			child_trie_key: None,
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(ArchiveStorageDiffEvent::StorageDiffDone, event);
+}
+
 #[tokio::test]
 async fn archive_storage_diff_no_changes() {
 	let (client, api) = setup_api();
@@ -891,6 +961,7 @@ async fn archive_storage_diff_no_changes() {
 		key: hex_string(b":A"),
 		return_type: ArchiveStorageDiffType::Value,
 		child_trie_key: None,
+		pagination_start_key: None,
 	}];
 	let mut sub = api
 		.subscribe_unbounded(
@@ -945,6 +1016,7 @@ async fn archive_storage_diff_deleted_changes() {
 		key: hex_string(b":A"),
 		return_type: ArchiveStorageDiffType::Value,
 		child_trie_key: None,
+		pagination_start_key: None,
 	}];
 
 	let mut sub = api
@@ -970,6 +1042,111 @@ async fn archive_storage_diff_deleted_changes() {
 	assert_eq!(ArchiveStorageDiffEvent::StorageDiffDone, event);
 }
 
+#[tokio::test]
+async fn archive_storage_diff_range() {
+	let (client, api) = setup_api();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":A".to_vec(), Some(b"B".to_vec())).unwrap();
+	let start_block = builder.build().unwrap().block;
+	let start_hash = format!("{:?}", start_block.header.hash());
+	client.import(BlockOrigin::Own, start_block.clone()).await.unwrap();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(start_block.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":A".to_vec(), Some(b"11".to_vec())).unwrap();
+	let middle_block = builder.build().unwrap().block;
+	let middle_hash = format!("{:?}", middle_block.header.hash());
+	client.import(BlockOrigin::Own, middle_block.clone()).await.unwrap();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(middle_block.hash())
+		.with_parent_block_number(2)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":AA".to_vec(), Some(b"22".to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let items = vec![ArchiveStorageDiffItem::<String> {
+		key: hex_string(b":A"),
+		return_type: ArchiveStorageDiffType::Value,
+		child_trie_key: None,
+		pagination_start_key: None,
+	}];
+	let mut sub = api
+		.subscribe_unbounded(
+			"archive_unstable_storageDiffRange",
+			rpc_params![&block_hash, items.clone(), &start_hash],
+		)
+		.await
+		.unwrap();
+
+	// The differences are reported block by block.
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiffBlock(ArchiveStorageDiffBlock {
+			hash: middle_hash.clone()
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiff(ArchiveStorageDiffResult {
+			key: hex_string(b":A"),
+			result: StorageResultType::Value(hex_string(b"11")),
+			operation_type: ArchiveStorageDiffOperationType::Modified,
+			child_trie_key: None,
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiffBlock(ArchiveStorageDiffBlock {
+			hash: block_hash.clone()
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(
+		ArchiveStorageDiffEvent::StorageDiff(ArchiveStorageDiffResult {
+			key: hex_string(b":AA"),
+			result: StorageResultType::Value(hex_string(b"22")),
+			operation_type: ArchiveStorageDiffOperationType::Added,
+			child_trie_key: None,
+		}),
+		event,
+	);
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_eq!(ArchiveStorageDiffEvent::StorageDiffDone, event);
+
+	// The end block must be a descendant of the start block.
+	let mut sub = api
+		.subscribe_unbounded(
+			"archive_unstable_storageDiffRange",
+			rpc_params![&start_hash, items.clone(), &block_hash],
+		)
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveStorageDiffEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveStorageDiffEvent::StorageDiffError(ref err) if err.error.contains("is not a descendant")
+	);
+}
+
 #[tokio::test]
 async fn archive_storage_diff_invalid_params() {
 	let invalid_hash = hex_string(&INVALID_HASH);
@@ -36,12 +36,16 @@ pub trait ChainHeadApi<Hash> {
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
+	///
+	/// When `with_extrinsic_hashes` is set, the `newBlock` events report the hashes of the
+	/// extrinsics of the block. These are the hashes of the transactions submitted to the pool,
+	/// allowing clients to detect the inclusion of their transactions without fetching the body.
 	#[subscription(
 		name = "chainHead_v1_follow" => "chainHead_v1_followEvent",
 		unsubscribe = "chainHead_v1_unfollow",
 		item = FollowEvent<Hash>,
 	)]
-	fn chain_head_unstable_follow(&self, with_runtime: bool);
+	fn chain_head_unstable_follow(&self, with_runtime: bool, with_extrinsic_hashes: Option<bool>);
 
 	/// Retrieves the body (list of transactions) of a pinned block.
 	///
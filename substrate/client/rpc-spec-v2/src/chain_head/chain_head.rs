@@ -197,7 +197,12 @@ where
 		+ StorageProvider<Block, BE>
 		+ 'static,
 {
-	fn chain_head_unstable_follow(&self, pending: PendingSubscriptionSink, with_runtime: bool) {
+	fn chain_head_unstable_follow(
+		&self,
+		pending: PendingSubscriptionSink,
+		with_runtime: bool,
+		with_extrinsic_hashes: Option<bool>,
+	) {
 		let subscriptions = self.subscriptions.clone();
 		let backend = self.backend.clone();
 		let client = self.client.clone();
@@ -236,6 +241,7 @@ where
 				backend,
 				subscriptions,
 				with_runtime,
+				with_extrinsic_hashes.unwrap_or_default(),
 				sub_id.clone(),
 				max_lagging_distance,
 				subscription_buffer_cap,
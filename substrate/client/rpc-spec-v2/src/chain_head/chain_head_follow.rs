@@ -41,7 +41,7 @@ use sp_blockchain::{
 	Backend as BlockChainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata, Info,
 };
 use sp_runtime::{
-	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+	traits::{Block as BlockT, Hash as HashT, HashingFor, Header as HeaderT, NumberFor},
 	SaturatedConversion, Saturating,
 };
 use std::{
@@ -62,6 +62,8 @@ pub struct ChainHeadFollower<BE: Backend<Block>, Block: BlockT, Client> {
 	sub_handle: SubscriptionManagement<Block, BE>,
 	/// Subscription was started with the runtime updates flag.
 	with_runtime: bool,
+	/// Subscription was started with the extrinsic hashes flag.
+	with_extrinsic_hashes: bool,
 	/// Subscription ID.
 	sub_id: String,
 	/// The best reported block by this subscription.
@@ -147,6 +149,7 @@ impl<BE: Backend<Block>, Block: BlockT, Client> ChainHeadFollower<BE, Block, Cli
 		backend: Arc<BE>,
 		sub_handle: SubscriptionManagement<Block, BE>,
 		with_runtime: bool,
+		with_extrinsic_hashes: bool,
 		sub_id: String,
 		max_lagging_distance: usize,
 		subscription_buffer_cap: usize,
@@ -156,6 +159,7 @@ impl<BE: Backend<Block>, Block: BlockT, Client> ChainHeadFollower<BE, Block, Cli
 			backend,
 			sub_handle,
 			with_runtime,
+			with_extrinsic_hashes,
 			sub_id,
 			current_best_block: None,
 			pruned_blocks: LruMap::new(ByLength::new(
@@ -237,6 +241,30 @@ where
 		+ CallApiAt<Block>
 		+ 'static,
 {
+	/// Conditionally generate the hashes of the extrinsics of the given block.
+	fn generate_extrinsic_hashes(&self, block: Block::Hash) -> Option<Vec<Block::Hash>> {
+		// No extrinsic hashes should be reported.
+		if !self.with_extrinsic_hashes {
+			return None
+		}
+
+		match self.client.block_body(block) {
+			Ok(Some(body)) =>
+				Some(body.iter().map(|extrinsic| HashingFor::<Block>::hash_of(extrinsic)).collect()),
+			Ok(None) => None,
+			Err(error) => {
+				debug!(
+					target: LOG_TARGET,
+					"[follow][id={:?}] Failed to fetch the body of block {:?}: {:?}",
+					self.sub_id,
+					block,
+					error
+				);
+				None
+			},
+		}
+	}
+
 	/// Conditionally generate the runtime event of the given block.
 	fn generate_runtime_event(
 		&self,
@@ -415,12 +443,14 @@ where
 			self.announced_blocks.insert(child, false);
 
 			let new_runtime = self.generate_runtime_event(child, Some(parent));
+			let extrinsic_hashes = self.generate_extrinsic_hashes(child);
 
 			let event = FollowEvent::NewBlock(NewBlock {
 				block_hash: child,
 				parent_block_hash: parent,
 				new_runtime,
 				with_runtime: self.with_runtime,
+				extrinsic_hashes,
 			});
 
 			finalized_block_descendants.push(event);
@@ -451,12 +481,14 @@ where
 		is_best_block: bool,
 	) -> Vec<FollowEvent<Block::Hash>> {
 		let new_runtime = self.generate_runtime_event(block_hash, Some(parent_block_hash));
+		let extrinsic_hashes = self.generate_extrinsic_hashes(block_hash);
 
 		let new_block = FollowEvent::NewBlock(NewBlock {
 			block_hash,
 			parent_block_hash,
 			new_runtime,
 			with_runtime: self.with_runtime,
+			extrinsic_hashes,
 		});
 
 		if !is_best_block {
@@ -165,27 +165,36 @@ pub struct NewBlock<Hash> {
 	/// serialized.
 	#[serde(default)]
 	pub(crate) with_runtime: bool,
+	/// The hashes of the extrinsics included in the new block, in the order of the block body.
+	///
+	/// The hashes are the ones reported by the transaction pool for the submitted transactions.
+	///
+	/// # Note
+	///
+	/// This is present only if the `with_extrinsic_hashes` flag is set for
+	/// the `follow` subscription and the body of the block is available.
+	#[serde(default)]
+	pub extrinsic_hashes: Option<Vec<Hash>>,
 }
 
 impl<Hash: Serialize> Serialize for NewBlock<Hash> {
 	/// Custom serialize implementation to include the `RuntimeEvent` depending
-	/// on the internal `with_runtime` flag.
+	/// on the internal `with_runtime` flag, and the extrinsic hashes if provided.
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
+		let len = 2 + self.with_runtime as usize + self.extrinsic_hashes.is_some() as usize;
+		let mut state = serializer.serialize_struct("NewBlock", len)?;
+		state.serialize_field("blockHash", &self.block_hash)?;
+		state.serialize_field("parentBlockHash", &self.parent_block_hash)?;
 		if self.with_runtime {
-			let mut state = serializer.serialize_struct("NewBlock", 3)?;
-			state.serialize_field("blockHash", &self.block_hash)?;
-			state.serialize_field("parentBlockHash", &self.parent_block_hash)?;
 			state.serialize_field("newRuntime", &self.new_runtime)?;
-			state.end()
-		} else {
-			let mut state = serializer.serialize_struct("NewBlock", 2)?;
-			state.serialize_field("blockHash", &self.block_hash)?;
-			state.serialize_field("parentBlockHash", &self.parent_block_hash)?;
-			state.end()
 		}
+		if let Some(extrinsic_hashes) = &self.extrinsic_hashes {
+			state.serialize_field("extrinsicHashes", extrinsic_hashes)?;
+		}
+		state.end()
 	}
 }
 
@@ -403,6 +412,7 @@ mod tests {
 			parent_block_hash: "0x2".into(),
 			new_runtime: None,
 			with_runtime: false,
+			extrinsic_hashes: None,
 		});
 
 		let ser = serde_json::to_string(&event).unwrap();
@@ -413,6 +423,27 @@ mod tests {
 		assert_eq!(event_dec, event);
 	}
 
+	#[test]
+	fn follow_new_block_event_with_extrinsic_hashes() {
+		let event: FollowEvent<String> = FollowEvent::NewBlock(NewBlock {
+			block_hash: "0x1".into(),
+			parent_block_hash: "0x2".into(),
+			new_runtime: None,
+			with_runtime: false,
+			extrinsic_hashes: Some(vec!["0x3".into(), "0x4".into()]),
+		});
+
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = concat!(
+			r#"{"event":"newBlock","blockHash":"0x1","parentBlockHash":"0x2","#,
+			r#""extrinsicHashes":["0x3","0x4"]}"#,
+		);
+		assert_eq!(ser, exp);
+
+		let event_dec: FollowEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+	}
+
 	#[test]
 	fn follow_new_block_event_with_updates() {
 		// Runtime flag is true, block runtime must always be reported for this event.
@@ -430,6 +461,7 @@ mod tests {
 			parent_block_hash: "0x2".into(),
 			new_runtime: Some(runtime_event),
 			with_runtime: true,
+			extrinsic_hashes: None,
 		};
 
 		let event: FollowEvent<String> = FollowEvent::NewBlock(new_block.clone());
@@ -455,6 +487,7 @@ mod tests {
 			parent_block_hash: "0x2".into(),
 			new_runtime: None,
 			with_runtime: true,
+			extrinsic_hashes: None,
 		};
 		let event: FollowEvent<String> = FollowEvent::NewBlock(new_block.clone());
 
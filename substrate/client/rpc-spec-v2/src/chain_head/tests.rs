@@ -229,6 +229,7 @@ macro_rules! check_new_and_best_block_events {
 			parent_block_hash: format!("{:?}", $parent_hash),
 			new_runtime: None,
 			with_runtime: false,
+			extrinsic_hashes: None,
 		});
 		assert_eq!(event, expected);
 
@@ -291,6 +292,7 @@ async fn follow_subscription_produces_blocks() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -310,6 +312,68 @@ async fn follow_subscription_produces_blocks() {
 	assert_eq!(event, expected);
 }
 
+#[tokio::test]
+async fn follow_with_extrinsic_hashes() {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let client = Arc::new(builder.build());
+
+	let api = ChainHead::new(
+		client.clone(),
+		backend,
+		Arc::new(TokioTestExecutor::default()),
+		ChainHeadConfig {
+			global_max_pinned_blocks: MAX_PINNED_BLOCKS,
+			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
+			subscription_max_ongoing_operations: MAX_OPERATIONS,
+			max_lagging_distance: MAX_LAGGING_DISTANCE,
+			max_follow_subscriptions_per_connection: MAX_FOLLOW_SUBSCRIPTIONS_PER_CONNECTION,
+			subscription_buffer_cap: MAX_PINNED_BLOCKS,
+		},
+	)
+	.into_rpc();
+
+	let finalized_hash = client.info().finalized_hash;
+	let mut sub = api.subscribe_unbounded("chainHead_v1_follow", [false, true]).await.unwrap();
+
+	// Initialized must always be reported first.
+	let event: FollowEvent<String> = get_next_event(&mut sub).await;
+	let expected = FollowEvent::Initialized(Initialized {
+		finalized_block_hashes: vec![format!("{:?}", finalized_hash)],
+		finalized_block_runtime: None,
+		with_runtime: false,
+	});
+	assert_eq!(event, expected);
+
+	let mut block_builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	block_builder
+		.push_transfer(Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 41,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = block_builder.build().unwrap().block;
+	let block_hash = block.header.hash();
+	let extrinsic_hash = Blake2Hasher::hash(&block.extrinsics()[0].encode());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let event: FollowEvent<String> = get_next_event(&mut sub).await;
+	let expected = FollowEvent::NewBlock(NewBlock {
+		block_hash: format!("{:?}", block_hash),
+		parent_block_hash: format!("{:?}", finalized_hash),
+		new_runtime: None,
+		with_runtime: false,
+		extrinsic_hashes: Some(vec![format!("{:?}", extrinsic_hash)]),
+	});
+	assert_eq!(event, expected);
+}
+
 #[tokio::test]
 async fn follow_with_runtime() {
 	let builder = TestClientBuilder::new();
@@ -376,6 +440,7 @@ async fn follow_with_runtime() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -431,6 +496,7 @@ async fn follow_with_runtime() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 }
@@ -1451,6 +1517,7 @@ async fn follow_generates_initial_blocks() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -1461,6 +1528,7 @@ async fn follow_generates_initial_blocks() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	// Check block 3.
@@ -1470,6 +1538,7 @@ async fn follow_generates_initial_blocks() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -1497,6 +1566,7 @@ async fn follow_generates_initial_blocks() {
 		parent_block_hash: format!("{:?}", block_2_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2063,6 +2133,7 @@ async fn follow_prune_best_block() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2078,6 +2149,7 @@ async fn follow_prune_best_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2093,6 +2165,7 @@ async fn follow_prune_best_block() {
 		parent_block_hash: format!("{:?}", block_2_f_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2108,6 +2181,7 @@ async fn follow_prune_best_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2289,6 +2363,7 @@ async fn follow_forks_pruned_block() {
 		parent_block_hash: format!("{:?}", block_3_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2423,6 +2498,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2432,6 +2508,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2441,6 +2518,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", block_2_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2451,6 +2529,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2460,6 +2539,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", block_2_f_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2514,6 +2594,7 @@ async fn follow_report_multiple_pruned_block() {
 		parent_block_hash: format!("{:?}", block_3_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -2747,6 +2828,7 @@ async fn follow_finalized_before_new_block() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -2789,6 +2871,7 @@ async fn follow_finalized_before_new_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 
@@ -3445,7 +3528,7 @@ async fn chain_head_single_connection_context() {
 		.unwrap();
 
 	let mut sub: RpcClientSubscription<FollowEvent<String>> =
-		ChainHeadApiClient::<String>::chain_head_unstable_follow(&client, true)
+		ChainHeadApiClient::<String>::chain_head_unstable_follow(&client, true, None)
 			.await
 			.unwrap();
 
@@ -3867,6 +3950,7 @@ async fn follow_report_best_block_of_a_known_block() {
 		parent_block_hash: format!("{:?}", finalized_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -3882,6 +3966,7 @@ async fn follow_report_best_block_of_a_known_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -3897,6 +3982,7 @@ async fn follow_report_best_block_of_a_known_block() {
 		parent_block_hash: format!("{:?}", block_1_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
@@ -3966,6 +4052,7 @@ async fn follow_report_best_block_of_a_known_block() {
 		parent_block_hash: format!("{:?}", block_2_f_hash),
 		new_runtime: None,
 		with_runtime: false,
+		extrinsic_hashes: None,
 	});
 	assert_eq!(event, expected);
 	let event: FollowEvent<String> = get_next_event(&mut sub).await;
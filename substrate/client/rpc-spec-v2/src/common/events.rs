@@ -187,6 +187,12 @@ pub struct ArchiveStorageDiffItem<Key> {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	#[serde(default)]
 	pub child_trie_key: Option<Key>,
+	/// The key after which the iteration should resume.
+	///
+	/// Only the keys lexicographically greater than this key are reported for this item.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub pagination_start_key: Option<Key>,
 }
 
 /// The result of a storage difference call.
@@ -227,10 +233,20 @@ pub struct ArchiveStorageDiffResult {
 	pub child_trie_key: Option<String>,
 }
 
+/// The block whose storage differences are reported next.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageDiffBlock {
+	/// The hex-encoded hash of the block.
+	pub hash: String,
+}
+
 /// The event generated by the `archive_storageDiff` method.
 ///
 /// The `archive_storageDiff` can generate the following events:
 ///  - `storageDiff` event - generated when a `ArchiveStorageDiffResult` is produced.
+///  - `storageDiffBlock` event - generated by `archive_storageDiffRange` before the differences
+///    introduced by a block.
 ///  - `storageDiffError` event - generated when an error is produced.
 ///  - `storageDiffDone` event - generated when the `archive_storageDiff` method completed.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -239,6 +255,8 @@ pub struct ArchiveStorageDiffResult {
 pub enum ArchiveStorageDiffEvent {
 	/// The `storageDiff` event.
 	StorageDiff(ArchiveStorageDiffResult),
+	/// The `storageDiffBlock` event.
+	StorageDiffBlock(ArchiveStorageDiffBlock),
 	/// The `storageDiffError` event.
 	StorageDiffError(ArchiveStorageMethodErr),
 	/// The `storageDiffDone` event.
@@ -273,6 +291,7 @@ mod tests {
 			key: "0x1",
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: None,
+			pagination_start_key: None,
 		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
@@ -287,6 +306,7 @@ mod tests {
 			key: "0x1",
 			return_type: ArchiveStorageDiffType::Hash,
 			child_trie_key: None,
+			pagination_start_key: None,
 		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
@@ -301,6 +321,7 @@ mod tests {
 			key: "0x1",
 			return_type: ArchiveStorageDiffType::Value,
 			child_trie_key: Some("0x2"),
+			pagination_start_key: None,
 		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
@@ -315,6 +336,7 @@ mod tests {
 			key: "0x1",
 			return_type: ArchiveStorageDiffType::Hash,
 			child_trie_key: Some("0x2"),
+			pagination_start_key: None,
 		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
@@ -323,6 +345,21 @@ mod tests {
 		// Decode
 		let dec: ArchiveStorageDiffItem<&str> = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
+
+		// Item with Value and pagination start key.
+		let item = ArchiveStorageDiffItem {
+			key: "0x1",
+			return_type: ArchiveStorageDiffType::Value,
+			child_trie_key: None,
+			pagination_start_key: Some("0x12"),
+		};
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"key":"0x1","returnType":"value","paginationStartKey":"0x12"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveStorageDiffItem<&str> = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
 	}
 
 	#[test]
@@ -199,16 +199,17 @@ where
 		}
 	}
 
-	/// Raw iterator over the keys.
+	/// Raw iterator over the keys, starting after `start_at` if provided.
 	pub fn raw_keys_iter(
 		&self,
 		hash: Block::Hash,
 		child_key: Option<ChildInfo>,
+		start_at: Option<&StorageKey>,
 	) -> Result<impl Iterator<Item = StorageKey>, String> {
 		let keys_iter = if let Some(child_key) = child_key {
-			self.client.child_storage_keys(hash, child_key, None, None)
+			self.client.child_storage_keys(hash, child_key, None, start_at)
 		} else {
-			self.client.storage_keys(hash, None, None)
+			self.client.storage_keys(hash, None, start_at)
 		};
 
 		keys_iter.map_err(|err| err.to_string())
@@ -18,13 +18,20 @@
 
 //! Substrate statement store API.
 
+use crate::{
+	utils::{spawn_subscription_task, BoundedVecDeque, PendingSubscription},
+	SubscriptionTaskExecutor,
+};
 use codec::{Decode, Encode};
+use futures::StreamExt;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
-	Extensions,
+	Extensions, PendingSubscriptionSink,
 };
 /// Re-export the API for backward compatibility.
-pub use sc_rpc_api::statement::{error::Error, StatementApiServer};
+pub use sc_rpc_api::statement::{
+	error::Error, AccountUsage, RejectedStatements, StatementApiServer, StatementFilter,
+};
 use sp_core::Bytes;
 use sp_statement_store::{StatementSource, SubmitResult};
 use std::sync::Arc;
@@ -32,12 +39,23 @@ use std::sync::Arc;
 /// Statement store API
 pub struct StatementStore {
 	store: Arc<dyn sp_statement_store::StatementStore>,
+	executor: Option<SubscriptionTaskExecutor>,
 }
 
 impl StatementStore {
 	/// Create new instance of Offchain API.
+	///
+	/// Subscriptions are not supported, see [`Self::new_with_executor`].
 	pub fn new(store: Arc<dyn sp_statement_store::StatementStore>) -> Self {
-		StatementStore { store }
+		StatementStore { store, executor: None }
+	}
+
+	/// Create new instance of Offchain API, spawning subscriptions with `executor`.
+	pub fn new_with_executor(
+		store: Arc<dyn sp_statement_store::StatementStore>,
+		executor: SubscriptionTaskExecutor,
+	) -> Self {
+		StatementStore { store, executor: Some(executor) }
 	}
 }
 
@@ -103,4 +121,49 @@ impl StatementApiServer for StatementStore {
 	fn remove(&self, hash: [u8; 32]) -> RpcResult<()> {
 		Ok(self.store.remove(&hash).map_err(|e| Error::StatementStore(e.to_string()))?)
 	}
+
+	fn account_usage(&self, account: [u8; 32]) -> RpcResult<AccountUsage> {
+		let usage = self
+			.store
+			.account_usage(&account)
+			.map_err(|e| Error::StatementStore(e.to_string()))?;
+		Ok(AccountUsage {
+			statement_count: usage.statement_count,
+			data_size: usage.data_size,
+			max_count: usage.max_count,
+			max_size: usage.max_size,
+			evicted: usage.evicted,
+			rejected: RejectedStatements {
+				data_too_large: usage.rejected.data_too_large,
+				channel_priority_too_low: usage.rejected.channel_priority_too_low,
+				account_full: usage.rejected.account_full,
+				store_full: usage.rejected.store_full,
+			},
+		})
+	}
+
+	fn subscribe(&self, pending: PendingSubscriptionSink, filter: StatementFilter) {
+		let Some(executor) = &self.executor else {
+			// Dropping the pending subscription rejects it.
+			log::debug!("Statement subscription rejected: no subscription executor");
+			return
+		};
+		let filter = match filter {
+			StatementFilter::MatchAll(topics) =>
+				sp_statement_store::StatementFilter::MatchAll(topics),
+			StatementFilter::MatchAny(topics) =>
+				sp_statement_store::StatementFilter::MatchAny(topics),
+			StatementFilter::DecryptionKey(key) =>
+				sp_statement_store::StatementFilter::DecryptionKey(key),
+		};
+		let stream = self
+			.store
+			.subscribe_statements(filter)
+			.map(|statement| Bytes::from(statement.encode()));
+
+		spawn_subscription_task(
+			executor,
+			PendingSubscription::from(pending).pipe_from_stream(stream, BoundedVecDeque::default()),
+		);
+	}
 }
@@ -37,6 +37,10 @@
 //! statements are deleted and `Ignored` result is returned.
 //! The order in which statements with the same priority are deleted is unspecified.
 //!
+//! The latest quota computed by the runtime for each account is persisted along with the
+//! statements and the eviction and rejection statistics of the account. The usage, quota, eviction
+//! and rejection statistics of an account can be queried with `StatementStore::account_usage`.
+//!
 //! Statement expiration.
 //!
 //! Each time a statement is removed from the store (Either evicted by higher priority statement or
@@ -51,10 +55,12 @@ mod metrics;
 
 pub use sp_statement_store::{Error, StatementStore, MAX_TOPICS};
 
+use futures::StreamExt;
 use metrics::MetricsLink as PrometheusMetrics;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_keystore::LocalKeystore;
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::{crypto::UncheckedFrom, hexdisplay::HexDisplay, traits::SpawnNamed, Decode, Encode};
@@ -63,8 +69,8 @@ use sp_statement_store::{
 	runtime_api::{
 		InvalidStatement, StatementSource, StatementStoreExt, ValidStatement, ValidateStatement,
 	},
-	AccountId, BlockHash, Channel, DecryptionKey, Hash, NetworkPriority, Proof, Result, Statement,
-	SubmitResult, Topic,
+	AccountId, AccountUsage, BlockHash, Channel, DecryptionKey, Hash, NetworkPriority, Proof,
+	RejectedStatements, Result, Statement, StatementFilter, StatementStream, SubmitResult, Topic,
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
@@ -72,6 +78,7 @@ use std::{
 };
 
 const KEY_VERSION: &[u8] = b"version".as_slice();
+const KEY_ACCOUNT_STATS_PREFIX: &[u8] = b"account_stats".as_slice();
 const CURRENT_VERSION: u32 = 1;
 
 const LOG_TARGET: &str = "statement-store";
@@ -82,6 +89,9 @@ const DEFAULT_MAX_TOTAL_SIZE: usize = 64 * 1024 * 1024;
 
 const MAINTENANCE_PERIOD: std::time::Duration = std::time::Duration::from_secs(30);
 
+/// Warning threshold of the queue of statements waiting to be sent to a subscriber.
+const SUBSCRIPTION_QUEUE_WARN_SIZE: usize = 100_000;
+
 mod col {
 	pub const META: u8 = 0;
 	pub const STATEMENTS: u8 = 1;
@@ -127,6 +137,26 @@ struct StatementsForAccount {
 	data_size: usize,
 }
 
+/// Reason for which a valid statement is not added to the store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RejectionReason {
+	DataTooLarge,
+	ChannelPriorityTooLow,
+	AccountFull,
+	StoreFull,
+}
+
+#[derive(Default, Encode, Decode)]
+struct AccountStats {
+	// Quota computed by the runtime for the last statement of the account.
+	quota: Option<ValidStatement>,
+	// Number of statements evicted by higher priority statements.
+	evicted: u64,
+	rejected: RejectedStatements,
+	// Timestamp of the last statement submitted for the account.
+	last_update: u64,
+}
+
 /// Store configuration
 pub struct Options {
 	/// Maximum statement allowed in the store. Once this limit is reached lower-priority
@@ -157,6 +187,10 @@ struct Index {
 	entries: HashMap<Hash, (AccountId, Priority, usize)>,
 	expired: HashMap<Hash, u64>, // Value is expiration timestamp.
 	accounts: HashMap<AccountId, StatementsForAccount>,
+	// Kept for `Options::purge_after_sec` seconds after the account has no statements left.
+	stats: HashMap<AccountId, AccountStats>,
+	// Accounts with rejection statistics which are not persisted yet.
+	dirty_stats: HashSet<AccountId>,
 	options: Options,
 	total_size: usize,
 }
@@ -206,6 +240,7 @@ pub struct Store {
 	// Used for testing
 	time_override: Option<u64>,
 	metrics: PrometheusMetrics,
+	subscribers: Mutex<Vec<(StatementFilter, TracingUnboundedSender<Statement>)>>,
 }
 
 enum IndexQuery {
@@ -216,7 +251,11 @@ enum IndexQuery {
 
 enum MaybeInserted {
 	Inserted(HashSet<Hash>),
-	Ignored,
+	Ignored(RejectionReason),
+}
+
+fn account_stats_key(account: &AccountId) -> Vec<u8> {
+	[KEY_ACCOUNT_STATS_PREFIX, account.as_slice()].concat()
 }
 
 impl Index {
@@ -264,6 +303,51 @@ impl Index {
 		self.expired.insert(hash, timestamp);
 	}
 
+	fn insert_stats(&mut self, account: AccountId, stats: AccountStats) {
+		self.stats.insert(account, stats);
+	}
+
+	// The database entry persisting the statistics of the account.
+	fn stats_commit(&mut self, account: &AccountId) -> (u8, Vec<u8>, Option<Vec<u8>>) {
+		self.dirty_stats.remove(account);
+		let value = self.stats.get(account).map(|stats| (account, stats).encode());
+		(col::META, account_stats_key(account), value)
+	}
+
+	// The database entries persisting the statistics of all the accounts with pending changes.
+	fn dirty_stats_commit(&mut self) -> Vec<(u8, Vec<u8>, Option<Vec<u8>>)> {
+		let dirty = std::mem::take(&mut self.dirty_stats);
+		dirty.iter().map(|account| self.stats_commit(account)).collect()
+	}
+
+	// Rejections are frequent when the store is under pressure, so their statistics are only
+	// persisted with the next inserted statement of the account or the next maintenance.
+	fn record_rejection(&mut self, account: &AccountId, reason: RejectionReason) {
+		self.dirty_stats.insert(*account);
+		let rejected = &mut self.stats.entry(*account).or_default().rejected;
+		match reason {
+			RejectionReason::DataTooLarge => rejected.data_too_large += 1,
+			RejectionReason::ChannelPriorityTooLow => rejected.channel_priority_too_low += 1,
+			RejectionReason::AccountFull => rejected.account_full += 1,
+			RejectionReason::StoreFull => rejected.store_full += 1,
+		}
+	}
+
+	fn account_usage(&self, account: &AccountId) -> AccountUsage {
+		let mut usage = AccountUsage::default();
+		if let Some(account_rec) = self.accounts.get(account) {
+			usage.statement_count = account_rec.by_priority.len() as u32;
+			usage.data_size = account_rec.data_size as u64;
+		}
+		if let Some(stats) = self.stats.get(account) {
+			usage.max_count = stats.quota.as_ref().map(|quota| quota.max_count);
+			usage.max_size = stats.quota.as_ref().map(|quota| quota.max_size);
+			usage.evicted = stats.evicted;
+			usage.rejected = stats.rejected.clone();
+		}
+		usage
+	}
+
 	fn iterate_with(
 		&self,
 		key: Option<DecryptionKey>,
@@ -305,7 +389,7 @@ impl Index {
 		Ok(())
 	}
 
-	fn maintain(&mut self, current_time: u64) -> Vec<Hash> {
+	fn maintain(&mut self, current_time: u64) -> (Vec<Hash>, Vec<AccountId>) {
 		// Purge previously expired messages.
 		let mut purged = Vec::new();
 		self.expired.retain(|hash, timestamp| {
@@ -317,7 +401,22 @@ impl Index {
 				true
 			}
 		});
-		purged
+		// Purge the statistics of accounts that have been inactive for a while.
+		let mut purged_accounts = Vec::new();
+		let accounts = &self.accounts;
+		let dirty_stats = &mut self.dirty_stats;
+		self.stats.retain(|account, stats| {
+			if accounts.contains_key(account) ||
+				stats.last_update + self.options.purge_after_sec > current_time
+			{
+				true
+			} else {
+				dirty_stats.remove(account);
+				purged_accounts.push(*account);
+				false
+			}
+		});
+		(purged, purged_accounts)
 	}
 
 	fn make_expired(&mut self, hash: &Hash, current_time: u64) -> bool {
@@ -373,6 +472,10 @@ impl Index {
 		validation: &ValidStatement,
 		current_time: u64,
 	) -> MaybeInserted {
+		let stats = self.stats.entry(*account).or_default();
+		stats.quota = Some(validation.clone());
+		stats.last_update = current_time;
+
 		let statement_len = statement.data_len();
 		if statement_len > validation.max_size as usize {
 			log::debug!(
@@ -381,7 +484,7 @@ impl Index {
 				HexDisplay::from(&hash),
 				statement_len,
 			);
-			return MaybeInserted::Ignored
+			return MaybeInserted::Ignored(RejectionReason::DataTooLarge)
 		}
 
 		let mut evicted = HashSet::new();
@@ -403,7 +506,7 @@ impl Index {
 							priority,
 							channel_record.priority,
 						);
-						return MaybeInserted::Ignored
+						return MaybeInserted::Ignored(RejectionReason::ChannelPriorityTooLow)
 					} else {
 						// Would replace channel message. Still need to check for size constraints
 						// below.
@@ -446,7 +549,7 @@ impl Index {
 						priority,
 						entry.priority,
 					);
-					return MaybeInserted::Ignored
+					return MaybeInserted::Ignored(RejectionReason::AccountFull)
 				}
 				evicted.insert(entry.hash);
 				would_free_size += len;
@@ -463,12 +566,13 @@ impl Index {
 				self.total_size,
 				self.entries.len(),
 			);
-			return MaybeInserted::Ignored
+			return MaybeInserted::Ignored(RejectionReason::StoreFull)
 		}
 
 		for h in &evicted {
 			self.make_expired(h, current_time);
 		}
+		self.stats.entry(*account).or_default().evicted += evicted.len() as u64;
 		self.insert_new(hash, *account, statement);
 		MaybeInserted::Inserted(evicted)
 	}
@@ -573,6 +677,7 @@ impl Store {
 			keystore,
 			time_override: None,
 			metrics: PrometheusMetrics::new(prometheus),
+			subscribers: Mutex::new(Vec::new()),
 		};
 		store.populate()?;
 		Ok(store)
@@ -624,6 +729,24 @@ impl Store {
 					true
 				})
 				.map_err(|e| Error::Db(e.to_string()))?;
+			self.db
+				.iter_column_while(col::META, |item| {
+					// Other meta values, such as the version, don't decode as account statistics.
+					let mut value = item.value.as_slice();
+					let stats = <(AccountId, AccountStats)>::decode(&mut value).ok();
+					if let Some((account, stats)) = stats.filter(|_| value.is_empty()) {
+						log::trace!(
+							target: LOG_TARGET,
+							"Account statistics loaded: {:?} {:?}",
+							HexDisplay::from(&account),
+							stats.quota,
+						);
+						index.insert_stats(account, stats);
+					}
+					true
+				})
+				.map_err(|e| Error::Db(e.to_string()))?;
+			self.report_usage(&index);
 		}
 
 		self.maintain();
@@ -671,11 +794,23 @@ impl Store {
 	/// Perform periodic store maintenance
 	pub fn maintain(&self) {
 		log::trace!(target: LOG_TARGET, "Started store maintenance");
-		let deleted = self.index.write().maintain(self.timestamp());
-		let deleted: Vec<_> =
-			deleted.into_iter().map(|hash| (col::EXPIRED, hash.to_vec(), None)).collect();
+		let (deleted, inactive_accounts, dirty_stats) = {
+			let mut index = self.index.write();
+			let (deleted, inactive_accounts) = index.maintain(self.timestamp());
+			(deleted, inactive_accounts, index.dirty_stats_commit())
+		};
 		let count = deleted.len() as u64;
-		if let Err(e) = self.db.commit(deleted) {
+		let commit: Vec<_> = deleted
+			.into_iter()
+			.map(|hash| (col::EXPIRED, hash.to_vec(), None))
+			.chain(
+				inactive_accounts
+					.iter()
+					.map(|account| (col::META, account_stats_key(account), None)),
+			)
+			.chain(dirty_stats)
+			.collect();
+		if let Err(e) = self.db.commit(commit) {
 			log::warn!(target: LOG_TARGET, "Error writing to the statement database: {:?}", e);
 		} else {
 			self.metrics.report(|metrics| metrics.statements_pruned.inc_by(count));
@@ -707,6 +842,25 @@ impl Store {
 	pub fn as_statement_store_ext(self: Arc<Self>) -> StatementStoreExt {
 		StatementStoreExt::new(self)
 	}
+
+	fn report_usage(&self, index: &Index) {
+		self.metrics.report(|metrics| {
+			metrics.statements_total.set(index.entries.len() as u64);
+			metrics.statements_size.set(index.total_size as u64);
+			metrics.accounts_total.set(index.accounts.len() as u64);
+		});
+	}
+
+	/// Send a new statement to the subscribers whose filter it matches, and drop the closed
+	/// subscriptions.
+	fn notify_subscribers(&self, statement: &Statement) {
+		self.subscribers.lock().retain(|(filter, sink)| {
+			if !filter.matches(statement) {
+				return !sink.is_closed()
+			}
+			sink.unbounded_send(statement.clone()).is_ok()
+		});
+	}
 }
 
 impl StatementStore for Store {
@@ -877,11 +1031,21 @@ impl StatementStore for Store {
 
 			let evicted =
 				match index.insert(hash, &statement, &account_id, &validation, current_time) {
-					MaybeInserted::Ignored => return SubmitResult::Ignored,
+					MaybeInserted::Ignored(reason) => {
+						index.record_rejection(&account_id, reason);
+						self.metrics.report(|metrics| metrics.report_rejection(reason));
+						return SubmitResult::Ignored
+					},
 					MaybeInserted::Inserted(evicted) => evicted,
 				};
+			self.metrics.report(|metrics| {
+				metrics.statements_evicted.inc_by(evicted.len() as u64);
+			});
+			self.report_usage(&index);
 
 			commit.push((col::STATEMENTS, hash.to_vec(), Some(statement.encode())));
+			commit.push(index.stats_commit(&account_id));
+			commit.extend(index.dirty_stats_commit());
 			for hash in evicted {
 				commit.push((col::STATEMENTS, hash.to_vec(), None));
 				commit.push((col::EXPIRED, hash.to_vec(), Some((hash, current_time).encode())));
@@ -897,6 +1061,7 @@ impl StatementStore for Store {
 			}
 		} // Release index lock
 		self.metrics.report(|metrics| metrics.submitted_statements.inc());
+		self.notify_subscribers(&statement);
 		let network_priority = NetworkPriority::High;
 		log::trace!(target: LOG_TARGET, "Statement submitted: {:?}", HexDisplay::from(&hash));
 		SubmitResult::New(network_priority)
@@ -922,9 +1087,21 @@ impl StatementStore for Store {
 					return Err(Error::Db(e.to_string()))
 				}
 			}
+			self.report_usage(&index);
 		}
 		Ok(())
 	}
+
+	fn subscribe_statements(&self, filter: StatementFilter) -> StatementStream {
+		let (sink, stream) =
+			tracing_unbounded("mpsc_statement_subscription", SUBSCRIPTION_QUEUE_WARN_SIZE);
+		self.subscribers.lock().push((filter, sink));
+		stream.boxed()
+	}
+
+	fn account_usage(&self, account: &AccountId) -> Result<AccountUsage> {
+		Ok(self.index.read().account_usage(account))
+	}
 }
 
 #[cfg(test)]
@@ -934,8 +1111,9 @@ mod tests {
 	use sp_core::Pair;
 	use sp_statement_store::{
 		runtime_api::{InvalidStatement, ValidStatement, ValidateStatement},
-		AccountId, Channel, DecryptionKey, NetworkPriority, Proof, SignatureVerificationResult,
-		Statement, StatementSource, StatementStore, SubmitResult, Topic,
+		AccountId, AccountUsage, Channel, DecryptionKey, NetworkPriority, Proof,
+		RejectedStatements, SignatureVerificationResult, Statement, StatementFilter,
+		StatementSource, StatementStore, StatementStream, SubmitResult, Topic,
 	};
 
 	type Extrinsic = sp_runtime::OpaqueExtrinsic;
@@ -1111,6 +1289,48 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn subscribe_statements_by_topic_and_key() {
+		let (store, _temp) = test_store();
+		let mut match_all =
+			store.subscribe_statements(StatementFilter::MatchAll(vec![topic(0), topic(1)]));
+		let mut match_any =
+			store.subscribe_statements(StatementFilter::MatchAny(vec![topic(1), topic(2)]));
+		let mut by_key = store.subscribe_statements(StatementFilter::DecryptionKey(dec_key(1)));
+
+		let statement0 = signed_statement_with_topics(0, &[topic(0)], None);
+		let statement1 = signed_statement_with_topics(1, &[topic(0), topic(1)], None);
+		let statement2 = signed_statement_with_topics(2, &[topic(2)], Some(dec_key(1)));
+		for statement in [&statement0, &statement1, &statement2] {
+			assert_eq!(
+				store.submit(statement.clone(), StatementSource::Network),
+				SubmitResult::New(NetworkPriority::High)
+			);
+		}
+		// Known statements are not notified again.
+		assert_eq!(store.submit(statement1.clone(), StatementSource::Network), SubmitResult::Known);
+		drop(store);
+
+		let collect = |stream: &mut StatementStream| {
+			futures::executor::block_on_stream(stream).map(|s| s.hash()).collect::<Vec<_>>()
+		};
+		assert_eq!(collect(&mut match_all), vec![statement1.hash()]);
+		assert_eq!(collect(&mut match_any), vec![statement1.hash(), statement2.hash()]);
+		assert_eq!(collect(&mut by_key), vec![statement2.hash()]);
+	}
+
+	#[test]
+	fn dropped_subscriptions_are_removed() {
+		let (store, _temp) = test_store();
+		let subscription = store.subscribe_statements(StatementFilter::MatchAll(vec![]));
+		let _other = store.subscribe_statements(StatementFilter::MatchAny(vec![topic(0)]));
+		assert_eq!(store.subscribers.lock().len(), 2);
+
+		drop(subscription);
+		store.submit(signed_statement(0), StatementSource::Network);
+		assert_eq!(store.subscribers.lock().len(), 1);
+	}
+
 	#[test]
 	fn save_and_load_statements() {
 		let (store, temp) = test_store();
@@ -1251,6 +1471,71 @@ mod tests {
 		assert_eq!(expected_statements, statements);
 	}
 
+	#[test]
+	fn account_usage_is_tracked_and_persisted() {
+		let (store, temp) = test_store();
+		let source = StatementSource::Network;
+		let ok = SubmitResult::New(NetworkPriority::High);
+		let ignored = SubmitResult::Ignored;
+
+		// Account 2 (limit = 2 msg, 1000 bytes)
+		assert_eq!(store.submit(statement(2, 1, None, 500), source), ok);
+		assert_eq!(store.submit(statement(2, 2, None, 100), source), ok);
+		// Evicts priority 1
+		assert_eq!(store.submit(statement(2, 3, None, 500), source), ok);
+		assert_eq!(store.submit(statement(2, 4, None, 2000), source), ignored);
+		assert_eq!(store.submit(statement(2, 1, None, 100), source), ignored);
+
+		// Account 3 (limit = 3 msg, 1000 bytes)
+		store.index.write().options.max_total_statements = 2;
+		assert_eq!(store.submit(statement(3, 1, None, 100), source), ignored);
+
+		let expected_usage = AccountUsage {
+			statement_count: 2,
+			data_size: 600,
+			max_count: Some(2),
+			max_size: Some(1000),
+			evicted: 1,
+			rejected: RejectedStatements {
+				data_too_large: 1,
+				account_full: 1,
+				..Default::default()
+			},
+		};
+		assert_eq!(store.account_usage(&account(2)).unwrap(), expected_usage);
+		assert_eq!(
+			store.account_usage(&account(3)).unwrap(),
+			AccountUsage {
+				max_count: Some(3),
+				max_size: Some(1000),
+				rejected: RejectedStatements { store_full: 1, ..Default::default() },
+				..Default::default()
+			}
+		);
+		assert_eq!(store.account_usage(&account(4)).unwrap(), AccountUsage::default());
+
+		// The rejection statistics of account 3 are persisted by the maintenance.
+		store.maintain();
+		let keystore = store.keystore.clone();
+		drop(store);
+
+		let client = std::sync::Arc::new(TestClient);
+		let mut path: std::path::PathBuf = temp.path().into();
+		path.push("db");
+		let store = Store::new(&path, Default::default(), client, keystore, None).unwrap();
+		// The quota and the eviction and rejection statistics are restored.
+		assert_eq!(store.account_usage(&account(2)).unwrap(), expected_usage);
+		assert_eq!(
+			store.account_usage(&account(3)).unwrap(),
+			AccountUsage {
+				max_count: Some(3),
+				max_size: Some(1000),
+				rejected: RejectedStatements { store_full: 1, ..Default::default() },
+				..Default::default()
+			}
+		);
+	}
+
 	#[test]
 	fn expired_statements_are_purged() {
 		use super::DEFAULT_PURGE_AFTER_SEC;
@@ -20,7 +20,11 @@
 
 use std::sync::Arc;
 
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	register, Counter, CounterVec, Gauge, Opts, PrometheusError, Registry, U64,
+};
+
+use crate::RejectionReason;
 
 #[derive(Clone, Default)]
 pub struct MetricsLink(Arc<Option<Metrics>>);
@@ -48,6 +52,11 @@ pub struct Metrics {
 	pub submitted_statements: Counter<U64>,
 	pub validations_invalid: Counter<U64>,
 	pub statements_pruned: Counter<U64>,
+	pub statements_evicted: Counter<U64>,
+	pub statements_rejected: CounterVec<U64>,
+	pub statements_total: Gauge<U64>,
+	pub statements_size: Gauge<U64>,
+	pub accounts_total: Gauge<U64>,
 }
 
 impl Metrics {
@@ -74,6 +83,54 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			statements_evicted: register(
+				Counter::new(
+					"substrate_sub_statement_store_evicted_statements",
+					"Total number of statements evicted by higher priority statements",
+				)?,
+				registry,
+			)?,
+			statements_rejected: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_sub_statement_store_rejected_statements",
+						"Total number of valid statements that were not added to the store",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			statements_total: register(
+				Gauge::new(
+					"substrate_sub_statement_store_statements",
+					"Number of statements in the store",
+				)?,
+				registry,
+			)?,
+			statements_size: register(
+				Gauge::new(
+					"substrate_sub_statement_store_statements_size",
+					"Total data size of the statements in the store",
+				)?,
+				registry,
+			)?,
+			accounts_total: register(
+				Gauge::new(
+					"substrate_sub_statement_store_accounts",
+					"Number of accounts with statements in the store",
+				)?,
+				registry,
+			)?,
 		})
 	}
+
+	pub fn report_rejection(&self, reason: RejectionReason) {
+		let label = match reason {
+			RejectionReason::DataTooLarge => "data_too_large",
+			RejectionReason::ChannelPriorityTooLow => "channel_priority_too_low",
+			RejectionReason::AccountFull => "account_full",
+			RejectionReason::StoreFull => "store_full",
+		};
+		self.statements_rejected.with_label_values(&[label]).inc();
+	}
 }
@@ -68,6 +68,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Freezer = ();
+	type Holder = ();
 }
 
 #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
@@ -77,6 +78,7 @@ impl pallet_assets::Config<Instance2> for Test {
 		AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Freezer = ();
+	type Holder = ();
 }
 
 parameter_types! {
@@ -31,6 +31,7 @@ use frame_support::{
 };
 use frame_system::RawOrigin as SystemOrigin;
 use sp_core::Get;
+use sp_runtime::traits::Bounded;
 
 /// Benchmark Helper
 pub trait BenchmarkHelper<AssetKind> {
@@ -144,6 +145,21 @@ fn create_asset_and_pool<T: Config>(
 	asset1: &T::AssetKind,
 	asset2: &T::AssetKind,
 ) -> (T::PoolAssetId, T::Balance, T::Balance)
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	create_asset_and_pool_of_kind::<T>(caller, asset1, asset2, PoolKind::ConstantProduct)
+}
+
+/// Creates a pool of the given `kind` for a given asset pair.
+///
+/// See [`create_asset_and_pool`].
+fn create_asset_and_pool_of_kind<T: Config>(
+	caller: &T::AccountId,
+	asset1: &T::AssetKind,
+	asset2: &T::AssetKind,
+	kind: PoolKind,
+) -> (T::PoolAssetId, T::Balance, T::Balance)
 where
 	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
 {
@@ -157,13 +173,55 @@ where
 
 	mint_setup_fee_asset::<T>(caller, asset1, asset2, &lp_token);
 
-	assert_ok!(AssetConversion::<T>::create_pool(
+	assert_ok!(AssetConversion::<T>::do_create_pool(
+		caller.clone(),
+		asset1.clone(),
+		asset2.clone(),
+		kind
+	));
+
+	(lp_token, liquidity1, liquidity2)
+}
+
+/// The ratio between the reserves of the stable pools swapped through in the benchmarks.
+///
+/// The further the reserves of a stable pool are from being balanced, the more iterations of
+/// Newton's method are needed to find the swap amounts.
+const STABLE_POOL_IMBALANCE: u32 = 10;
+
+/// Creates a stable pool with the maximum amplification for a given asset pair, and provides it
+/// with unbalanced liquidity, the worst case for a hop of a swap path.
+///
+/// Returns the smaller reserve of the pool.
+fn create_unbalanced_stable_pool<T: Config>(
+	caller: &T::AccountId,
+	asset1: &T::AssetKind,
+	asset2: &T::AssetKind,
+) -> T::Balance
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let (_, liquidity1, liquidity2) = create_asset_and_pool_of_kind::<T>(
+		caller,
+		asset1,
+		asset2,
+		PoolKind::StableSwap { amplification: MAX_AMPLIFICATION },
+	);
+	let liquidity2 = liquidity2 * STABLE_POOL_IMBALANCE.into();
+	assert_ok!(T::Assets::mint_into(asset2.clone(), caller, liquidity2));
+
+	assert_ok!(AssetConversion::<T>::add_liquidity(
 		SystemOrigin::Signed(caller.clone()).into(),
 		Box::new(asset1.clone()),
-		Box::new(asset2.clone())
+		Box::new(asset2.clone()),
+		liquidity1,
+		liquidity2,
+		T::Balance::one(),
+		T::Balance::zero(),
+		caller.clone(),
 	));
 
-	(lp_token, liquidity1, liquidity2)
+	liquidity1
 }
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -195,7 +253,47 @@ mod benchmarks {
 		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
 		let pool_account = T::PoolLocator::address(&pool_id).unwrap();
 		assert_last_event::<T>(
-			Event::PoolCreated { creator: caller, pool_account, pool_id, lp_token }.into(),
+			Event::PoolCreated {
+				creator: caller,
+				pool_account,
+				pool_id,
+				lp_token,
+				kind: PoolKind::ConstantProduct,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn create_stable_pool() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+		create_asset::<T>(&caller, &asset1, T::Assets::minimum_balance(asset1.clone()), true);
+		create_asset::<T>(&caller, &asset2, T::Assets::minimum_balance(asset2.clone()), true);
+
+		let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+		create_fee_asset::<T>(&caller);
+		mint_setup_fee_asset::<T>(&caller, &asset1, &asset2, &lp_token);
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			MAX_AMPLIFICATION,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		let pool_account = T::PoolLocator::address(&pool_id).unwrap();
+		assert_last_event::<T>(
+			Event::PoolCreated {
+				creator: caller,
+				pool_account,
+				pool_id,
+				lp_token,
+				kind: PoolKind::StableSwap { amplification: MAX_AMPLIFICATION },
+			}
+			.into(),
 		);
 	}
 
@@ -269,33 +367,26 @@ mod benchmarks {
 
 	#[benchmark]
 	fn swap_exact_tokens_for_tokens(n: Linear<2, { T::MaxSwapPathLength::get() }>) {
-		let mut swap_amount = T::Balance::one();
+		let mut min_reserve = T::Balance::max_value();
 		let mut path = vec![];
 
 		let caller: T::AccountId = whitelisted_caller();
 		create_fee_asset::<T>(&caller);
 		for n in 1..n {
 			let (asset1, asset2) = T::BenchmarkHelper::create_pair(n - 1, n);
-			swap_amount = swap_amount + T::Balance::one();
 			if path.len() == 0 {
 				path = vec![Box::new(asset1.clone()), Box::new(asset2.clone())];
 			} else {
 				path.push(Box::new(asset2.clone()));
 			}
 
-			let (_, liquidity1, liquidity2) = create_asset_and_pool::<T>(&caller, &asset1, &asset2);
-
-			assert_ok!(AssetConversion::<T>::add_liquidity(
-				SystemOrigin::Signed(caller.clone()).into(),
-				Box::new(asset1.clone()),
-				Box::new(asset2.clone()),
-				liquidity1,
-				liquidity2,
-				T::Balance::one(),
-				T::Balance::zero(),
-				caller.clone(),
-			));
+			// Stable pools are the worst case for a hop: their kind is read from storage and the
+			// swap amounts are found by Newton's method.
+			let reserve = create_unbalanced_stable_pool::<T>(&caller, &asset1, &asset2);
+			min_reserve = min_reserve.min(reserve);
 		}
+		// A tenth of the smallest reserve is swapped, so that every hop has a non-zero output.
+		let swap_amount = min_reserve / 10u32.into();
 
 		let asset_in = *path.first().unwrap().clone();
 		assert_ok!(T::Assets::mint_into(
@@ -321,51 +412,44 @@ mod benchmarks {
 
 	#[benchmark]
 	fn swap_tokens_for_exact_tokens(n: Linear<2, { T::MaxSwapPathLength::get() }>) {
-		let mut max_swap_amount = T::Balance::one();
+		let mut min_reserve = T::Balance::max_value();
 		let mut path = vec![];
 
 		let caller: T::AccountId = whitelisted_caller();
 		create_fee_asset::<T>(&caller);
 		for n in 1..n {
 			let (asset1, asset2) = T::BenchmarkHelper::create_pair(n - 1, n);
-			max_swap_amount = max_swap_amount + T::Balance::one() + T::Balance::one();
 			if path.len() == 0 {
 				path = vec![Box::new(asset1.clone()), Box::new(asset2.clone())];
 			} else {
 				path.push(Box::new(asset2.clone()));
 			}
 
-			let (_, liquidity1, liquidity2) = create_asset_and_pool::<T>(&caller, &asset1, &asset2);
-
-			assert_ok!(AssetConversion::<T>::add_liquidity(
-				SystemOrigin::Signed(caller.clone()).into(),
-				Box::new(asset1.clone()),
-				Box::new(asset2.clone()),
-				liquidity1,
-				liquidity2,
-				T::Balance::one(),
-				T::Balance::zero(),
-				caller.clone(),
-			));
+			// Stable pools are the worst case for a hop: their kind is read from storage and the
+			// swap amounts are found by Newton's method.
+			let reserve = create_unbalanced_stable_pool::<T>(&caller, &asset1, &asset2);
+			min_reserve = min_reserve.min(reserve);
 		}
+		// A tenth of the smallest reserve is swapped, so that every hop has a non-zero output.
+		let swap_amount = min_reserve / 10u32.into();
 
 		let asset_in = *path.first().unwrap().clone();
 		let asset_out = *path.last().unwrap().clone();
-		assert_ok!(T::Assets::mint_into(asset_in, &caller, max_swap_amount));
+		assert_ok!(T::Assets::mint_into(asset_in, &caller, min_reserve));
 		let init_caller_balance = T::Assets::balance(asset_out.clone(), &caller);
 
 		#[extrinsic_call]
 		_(
 			SystemOrigin::Signed(caller.clone()),
 			path,
-			T::Balance::one(),
-			max_swap_amount,
+			swap_amount,
+			min_reserve,
 			caller.clone(),
 			true,
 		);
 
 		let actual_balance = T::Assets::balance(asset_out, &caller);
-		assert_eq!(actual_balance, init_caller_balance + T::Balance::one());
+		assert_eq!(actual_balance, init_caller_balance + swap_amount);
 	}
 
 	#[benchmark]
@@ -18,12 +18,16 @@
 //! # Substrate Asset Conversion pallet
 //!
 //! Substrate Asset Conversion pallet based on the [Uniswap V2](https://github.com/Uniswap/v2-core) logic.
+//! Pools can alternatively price their swaps with the
+//! [Curve](https://curve.fi/files/stableswap-paper.pdf) stable-swap invariant, which offers a much
+//! lower slippage for pairs of assets trading close to parity.
 //!
 //! ## Overview
 //!
 //! This pallet allows you to:
 //!
 //!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a stable-swap liquidity pool](`Pallet::create_stable_pool()`) for 2 assets
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -123,6 +127,7 @@ pub mod pallet {
 			+ One
 			+ Ensure
 			+ Unsigned
+			+ Clone
 			+ From<u32>
 			+ From<Self::Balance>
 			+ TryInto<Self::Balance>;
@@ -203,6 +208,12 @@ pub mod pallet {
 	pub type Pools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, PoolInfo<T::PoolAssetId>, OptionQuery>;
 
+	/// Map from `PoolId` to the `PoolKind` of the pool. Pools without an entry are
+	/// [`PoolKind::ConstantProduct`] pools.
+	#[pallet::storage]
+	pub type PoolKinds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolKind, ValueQuery>;
+
 	/// Stores the `PoolAssetId` that is going to be used for the next lp token.
 	/// This gets incremented whenever a new lp pool is created.
 	#[pallet::storage]
@@ -224,6 +235,8 @@ pub mod pallet {
 			/// The id of the liquidity tokens that will be minted when assets are added to this
 			/// pool.
 			lp_token: T::PoolAssetId,
+			/// The invariant used to price the swaps of the pool.
+			kind: PoolKind,
 		},
 
 		/// A successful call of the `AddLiquidity` extrinsic will create this event.
@@ -348,6 +361,10 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The amplification coefficient of a stable-swap pool is out of bounds.
+		InvalidAmplification,
+		/// The stable-swap invariant calculation did not converge.
+		InvariantNotConverged,
 	}
 
 	#[pallet::hooks]
@@ -375,51 +392,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
-				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
-			};
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
-			Ok(())
+			Self::do_create_pool(sender, *asset1, *asset2, PoolKind::ConstantProduct)
 		}
 
 		/// Provide liquidity into the pool of `asset1` and `asset2`.
@@ -722,9 +695,93 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Creates an empty liquidity pool pricing its swaps with the stable-swap invariant, and an
+		/// associated new `lp_token` asset (the id of which is returned in the `Event::PoolCreated`
+		/// event).
+		///
+		/// The `amplification` coefficient must be within `1..=MAX_AMPLIFICATION`. The higher it
+		/// is, the lower the slippage of the swaps is around the balanced pool.
+		///
+		/// Liquidity is provided, removed and swapped in the same way as for the pools created with
+		/// [`Pallet::create_pool`].
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_stable_pool())]
+		pub fn create_stable_pool(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			amplification: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				(1..=MAX_AMPLIFICATION).contains(&amplification),
+				Error::<T>::InvalidAmplification
+			);
+			Self::do_create_pool(sender, *asset1, *asset2, PoolKind::StableSwap { amplification })
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create an empty liquidity pool of `kind` for `asset1` and `asset2`, paying the setup fee
+		/// from `sender`.
+		pub(crate) fn do_create_pool(
+			sender: T::AccountId,
+			asset1: T::AssetKind,
+			asset2: T::AssetKind,
+			kind: PoolKind,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(asset1.clone(), &pool_account) {
+				T::Assets::touch(asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(asset2.clone(), &pool_account) {
+				T::Assets::touch(asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
+				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
+			};
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+			if kind != PoolKind::ConstantProduct {
+				PoolKinds::<T>::insert(pool_id.clone(), kind);
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+				kind,
+			});
+
+			Ok(())
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -1065,8 +1122,9 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let kind = Self::pool_kind(asset1, &asset2)?;
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in = Self::get_pool_amount_in(&kind, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1091,13 +1149,18 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let kind = Self::pool_kind(&asset1, asset2)?;
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_pool_amount_out(&kind, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
 
 		/// Used by the RPC service to provide current prices.
+		///
+		/// Without `include_fee`, constant product pools quote the spot price, while stable-swap
+		/// pools quote the swap without the LP fee, including the slippage of the curve.
 		pub fn quote_price_exact_tokens_for_tokens(
 			asset1: T::AssetKind,
 			asset2: T::AssetKind,
@@ -1105,14 +1168,25 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let kind = Self::pool_kind(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+				match (kind, include_fee) {
+					(PoolKind::ConstantProduct, true) =>
+						Self::get_amount_out(&amount, &balance1, &balance2).ok(),
+					(PoolKind::ConstantProduct, false) =>
+						Self::quote(&amount, &balance1, &balance2).ok(),
+					(PoolKind::StableSwap { amplification }, include_fee) =>
+						Self::get_stable_amount_out(
+							&amount,
+							&balance1,
+							&balance2,
+							amplification,
+							if include_fee { T::LPFee::get() } else { 0 },
+						)
+						.ok(),
 				}
 			} else {
 				None
@@ -1120,6 +1194,9 @@ pub mod pallet {
 		}
 
 		/// Used by the RPC service to provide current prices.
+		///
+		/// Without `include_fee`, constant product pools quote the spot price, while stable-swap
+		/// pools quote the swap without the LP fee, including the slippage of the curve.
 		pub fn quote_price_tokens_for_exact_tokens(
 			asset1: T::AssetKind,
 			asset2: T::AssetKind,
@@ -1127,14 +1204,25 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let kind = Self::pool_kind(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+				match (kind, include_fee) {
+					(PoolKind::ConstantProduct, true) =>
+						Self::get_amount_in(&amount, &balance1, &balance2).ok(),
+					(PoolKind::ConstantProduct, false) =>
+						Self::quote(&amount, &balance2, &balance1).ok(),
+					(PoolKind::StableSwap { amplification }, include_fee) =>
+						Self::get_stable_amount_in(
+							&amount,
+							&balance1,
+							&balance2,
+							amplification,
+							if include_fee { T::LPFee::get() } else { 0 },
+						)
+						.ok(),
 				}
 			} else {
 				None
@@ -1259,6 +1347,269 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// Returns the kind of the pool of `asset1` and `asset2`.
+		pub fn pool_kind(
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> Result<PoolKind, Error<T>> {
+			let pool_id = T::PoolLocator::pool_id(asset1, asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			Ok(PoolKinds::<T>::get(pool_id))
+		}
+
+		/// Calculates amount out for a pool of the given `kind`.
+		///
+		/// See [`Pallet::get_amount_out`] and [`Pallet::get_stable_amount_out`].
+		pub fn get_pool_amount_out(
+			kind: &PoolKind,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match kind {
+				PoolKind::ConstantProduct =>
+					Self::get_amount_out(amount_in, reserve_in, reserve_out),
+				PoolKind::StableSwap { amplification } => Self::get_stable_amount_out(
+					amount_in,
+					reserve_in,
+					reserve_out,
+					*amplification,
+					T::LPFee::get(),
+				),
+			}
+		}
+
+		/// Calculates amount in for a pool of the given `kind`.
+		///
+		/// See [`Pallet::get_amount_in`] and [`Pallet::get_stable_amount_in`].
+		pub fn get_pool_amount_in(
+			kind: &PoolKind,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match kind {
+				PoolKind::ConstantProduct =>
+					Self::get_amount_in(amount_out, reserve_in, reserve_out),
+				PoolKind::StableSwap { amplification } => Self::get_stable_amount_in(
+					amount_out,
+					reserve_in,
+					reserve_out,
+					*amplification,
+					T::LPFee::get(),
+				),
+			}
+		}
+
+		/// Calculates amount out of a stable-swap pool.
+		///
+		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+		/// of the other asset, keeping the stable-swap invariant with the `amplification`
+		/// coefficient. The `fee` is taken from the input amount, in 10ths of a percent.
+		pub fn get_stable_amount_out(
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			amplification: u32,
+			fee: u32,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_in = T::HigherPrecisionBalance::from(*amount_in);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				return Err(Error::<T>::ZeroLiquidity)
+			}
+
+			let amount_in_with_fee = amount_in
+				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let d = Self::get_stable_d(&reserve_in, &reserve_out, amplification)?;
+			let new_reserve_in =
+				reserve_in.checked_add(&amount_in_with_fee).ok_or(Error::<T>::Overflow)?;
+			let new_reserve_out = Self::get_stable_y(&new_reserve_in, &d, amplification)?;
+
+			// subtract one to round in favour of the pool.
+			let result = reserve_out
+				.checked_sub(&new_reserve_out)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_sub(&One::one())
+				.unwrap_or_else(Zero::zero);
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates amount in of a stable-swap pool.
+		///
+		/// Given an output amount of an asset and pair reserves, returns a required input amount
+		/// of the other asset, keeping the stable-swap invariant with the `amplification`
+		/// coefficient. The `fee` is taken from the input amount, in 10ths of a percent.
+		pub fn get_stable_amount_in(
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			amplification: u32,
+			fee: u32,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_out = T::HigherPrecisionBalance::from(*amount_out);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				Err(Error::<T>::ZeroLiquidity)?
+			}
+
+			if amount_out >= reserve_out {
+				Err(Error::<T>::AmountOutTooHigh)?
+			}
+
+			let d = Self::get_stable_d(&reserve_in, &reserve_out, amplification)?;
+			let new_reserve_out =
+				reserve_out.checked_sub(&amount_out).ok_or(Error::<T>::Overflow)?;
+			let new_reserve_in = Self::get_stable_y(&new_reserve_out, &d, amplification)?;
+
+			// add one to round in favour of the pool.
+			let amount_in_with_fee = new_reserve_in
+				.checked_sub(&reserve_in)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let result = amount_in_with_fee
+				.checked_mul(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&(T::HigherPrecisionBalance::from(1000u32) - fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates the stable-swap invariant `D` of a pool with the `reserve1` and `reserve2`
+		/// balances, using Newton's method.
+		///
+		/// `D` is the total amount of assets in the pool when they have an equal price, satisfying
+		/// `A * n^n * (x + y) + D = A * D * n^n + D^(n + 1) / (n^n * x * y)` with `n = 2` assets.
+		fn get_stable_d(
+			reserve1: &T::HigherPrecisionBalance,
+			reserve2: &T::HigherPrecisionBalance,
+			amplification: u32,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			// `A * n^n`
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+			let ann_minus_one = ann.checked_sub(&One::one()).ok_or(Error::<T>::Overflow)?;
+			let sum = reserve1.checked_add(reserve2).ok_or(Error::<T>::Overflow)?;
+			let ann_sum = ann.checked_mul(&sum).ok_or(Error::<T>::Overflow)?;
+			let reserve1_n = reserve1.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+			let reserve2_n = reserve2.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+
+			let mut d = sum;
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// d_p = d^(n + 1) / (n^n * x * y)
+				let d_p = d
+					.checked_mul(&d)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_div(&reserve1_n)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_mul(&d)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_div(&reserve2_n)
+					.ok_or(Error::<T>::Overflow)?;
+
+				// d = (A * n^n * (x + y) + n * d_p) * d / ((A * n^n - 1) * d + (n + 1) * d_p)
+				let numerator = d_p
+					.checked_mul(&two)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_add(&ann_sum)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_mul(&d)
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = ann_minus_one
+					.checked_mul(&d)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_add(&d_p.checked_mul(&3u32.into()).ok_or(Error::<T>::Overflow)?)
+					.ok_or(Error::<T>::Overflow)?;
+				let next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+
+				if Self::stable_converged(&next, &d) {
+					return Ok(next)
+				}
+				d = next;
+			}
+
+			Err(Error::<T>::InvariantNotConverged)
+		}
+
+		/// Calculates the reserve of one asset of a stable-swap pool with the invariant `d`, given
+		/// the `reserve` of the other asset, using Newton's method.
+		fn get_stable_y(
+			reserve: &T::HigherPrecisionBalance,
+			d: &T::HigherPrecisionBalance,
+			amplification: u32,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			// `A * n^n`
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+
+			// c = d^(n + 1) / (n^n * reserve * A * n^n)
+			let c = d
+				.checked_mul(d)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&reserve.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(d)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&ann.checked_mul(&two).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?;
+			// b = reserve + d / (A * n^n)
+			let b = reserve
+				.checked_add(&d.checked_div(&ann).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?;
+
+			let mut y = d.clone();
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// y = (y^2 + c) / (2 * y + b - d)
+				let numerator = y
+					.checked_mul(&y)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_add(&c)
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = y
+					.checked_mul(&two)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_add(&b)
+					.ok_or(Error::<T>::Overflow)?
+					.checked_sub(d)
+					.ok_or(Error::<T>::Overflow)?;
+				let next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+
+				if Self::stable_converged(&next, &y) {
+					return Ok(next)
+				}
+				y = next;
+			}
+
+			Err(Error::<T>::InvariantNotConverged)
+		}
+
+		/// Whether two consecutive approximations of Newton's method are within one unit.
+		fn stable_converged(
+			next: &T::HigherPrecisionBalance,
+			prev: &T::HigherPrecisionBalance,
+		) -> bool {
+			let diff = if next > prev { next.checked_sub(prev) } else { prev.checked_sub(next) };
+			diff.map_or(false, |diff| diff <= One::one())
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(path: &Vec<T::AssetKind>) -> Result<(), DispatchError> {
 			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
@@ -1302,6 +1653,11 @@ sp_api::decl_runtime_apis! {
 		///
 		/// Note that the price may have changed by the time the transaction is executed.
 		/// (Use `amount_in_max` to control slippage.)
+		///
+		/// When `include_fee` is `false`, the quote of a constant product pool is the spot price
+		/// `amount * reserve_in / reserve_out`. The quote of a stable-swap pool is the amount in
+		/// required by the curve without the LP fee, so it still includes the slippage of swapping
+		/// `amount`.
 		fn quote_price_tokens_for_exact_tokens(
 			asset1: AssetId,
 			asset2: AssetId,
@@ -1313,6 +1669,11 @@ sp_api::decl_runtime_apis! {
 		///
 		/// Note that the price may have changed by the time the transaction is executed.
 		/// (Use `amount_out_min` to control slippage.)
+		///
+		/// When `include_fee` is `false`, the quote of a constant product pool is the spot price
+		/// `amount * reserve_out / reserve_in`. The quote of a stable-swap pool is the amount out
+		/// given by the curve without the LP fee, so it still includes the slippage of swapping
+		/// `amount`.
 		fn quote_price_exact_tokens_for_tokens(
 			asset1: AssetId,
 			asset2: AssetId,
@@ -1322,6 +1683,10 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Returns the kind of the liquidity pool for the given asset pair, if it has liquidity.
+		#[api_version(2)]
+		fn get_pool_kind(asset1: AssetId, asset2: AssetId) -> Option<PoolKind>;
 	}
 }
 
@@ -204,7 +204,8 @@ fn can_create_pool() {
 				creator: user,
 				pool_id: pool_id.clone(),
 				pool_account: <Test as Config>::PoolLocator::address(&pool_id).unwrap(),
-				lp_token
+				lp_token,
+				kind: PoolKind::ConstantProduct,
 			}]
 		);
 		assert_eq!(pools(), vec![pool_id]);
@@ -306,7 +307,8 @@ fn different_pools_should_have_different_lp_tokens() {
 				creator: user,
 				pool_id: pool_id_1_2.clone(),
 				pool_account: <Test as Config>::PoolLocator::address(&pool_id_1_2).unwrap(),
-				lp_token: lp_token2_1
+				lp_token: lp_token2_1,
+				kind: PoolKind::ConstantProduct,
 			}]
 		);
 
@@ -322,6 +324,7 @@ fn different_pools_should_have_different_lp_tokens() {
 				pool_id: pool_id_1_3.clone(),
 				pool_account: <Test as Config>::PoolLocator::address(&pool_id_1_3).unwrap(),
 				lp_token: lp_token3_1,
+				kind: PoolKind::ConstantProduct,
 			}]
 		);
 
@@ -2493,3 +2496,252 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+#[test]
+fn can_create_stable_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_2.clone(), token_3.clone());
+		let kind = PoolKind::StableSwap { amplification: 100 };
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+
+		assert_noop!(
+			AssetConversion::create_stable_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(token_3.clone()),
+				Box::new(token_2.clone()),
+				0
+			),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			AssetConversion::create_stable_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(token_3.clone()),
+				Box::new(token_2.clone()),
+				MAX_AMPLIFICATION + 1
+			),
+			Error::<Test>::InvalidAmplification
+		);
+
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_stable_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_3.clone()),
+			Box::new(token_2.clone()),
+			100
+		));
+
+		assert_eq!(
+			events(),
+			[Event::<Test>::PoolCreated {
+				creator: user,
+				pool_id: pool_id.clone(),
+				pool_account: <Test as Config>::PoolLocator::address(&pool_id).unwrap(),
+				lp_token,
+				kind,
+			}]
+		);
+		assert_eq!(pools(), vec![pool_id.clone()]);
+		assert_eq!(PoolKinds::<Test>::get(&pool_id), kind);
+		assert_eq!(AssetConversion::pool_kind(&token_3, &token_2), Ok(kind));
+
+		assert_noop!(
+			AssetConversion::create_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(token_2.clone()),
+				Box::new(token_3.clone())
+			),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn stable_swap_has_lower_slippage() {
+	new_test_ext().execute_with(|| {
+		let reserve = 1_000_000;
+		let amount_in = 100_000;
+
+		assert_eq!(AssetConversion::get_amount_out(&amount_in, &reserve, &reserve), Ok(90661));
+		assert_eq!(
+			AssetConversion::get_stable_amount_out(&amount_in, &reserve, &reserve, 100, 3),
+			Ok(99650)
+		);
+		// the amount in required for the same amount out, rounded in favour of the pool.
+		assert_eq!(
+			AssetConversion::get_stable_amount_in(&99650, &reserve, &reserve, 100, 3),
+			Ok(amount_in + 1)
+		);
+
+		assert_eq!(
+			AssetConversion::get_stable_amount_out(&amount_in, &0, &reserve, 100, 3),
+			Err(Error::<Test>::ZeroLiquidity)
+		);
+		assert_eq!(
+			AssetConversion::get_stable_amount_in(&reserve, &reserve, &reserve, 100, 3),
+			Err(Error::<Test>::AmountOutTooHigh)
+		);
+	});
+}
+
+#[test]
+fn can_swap_in_stable_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_2.clone(), token_3.clone());
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_stable_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			100
+		));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1_000_000));
+
+		let liquidity = 100_000;
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			liquidity,
+			liquidity,
+			1,
+			1,
+			user,
+		));
+
+		let input_amount = 10_000;
+		let expect_receive = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			input_amount,
+			true,
+		)
+		.unwrap();
+		assert_eq!(expect_receive, 9965);
+		// Without the fee, the quote still follows the stable-swap curve.
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				input_amount,
+				false,
+			),
+			AssetConversion::get_stable_amount_out(&input_amount, &liquidity, &liquidity, 100, 0)
+				.ok()
+		);
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_3.clone()],
+			input_amount,
+			expect_receive,
+			user,
+			false,
+		));
+
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		assert_eq!(balance(pool_account, token_2.clone()), liquidity + input_amount);
+		assert_eq!(balance(pool_account, token_3.clone()), liquidity - expect_receive);
+
+		// swap back to the exact amount of the first asset.
+		let expect_pay = AssetConversion::quote_price_tokens_for_exact_tokens(
+			token_3.clone(),
+			token_2.clone(),
+			input_amount,
+			true,
+		)
+		.unwrap();
+		assert_eq!(expect_pay, 10026);
+
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_2.clone()],
+			input_amount,
+			expect_pay,
+			user,
+			false,
+		));
+
+		assert_eq!(balance(pool_account, token_2.clone()), liquidity);
+		assert_eq!(balance(pool_account, token_3.clone()), liquidity - expect_receive + expect_pay);
+	});
+}
+
+#[test]
+fn can_swap_across_pool_kinds() {
+	new_test_ext().execute_with(|| {
+		let user = 2;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_ok!(AssetConversion::create_stable_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			100
+		));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1_000_000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10_000,
+			10_000,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			100_000,
+			100_000,
+			1,
+			1,
+			user,
+		));
+
+		let input_amount = 1_000;
+		let (reserve_3, reserve_2) =
+			AssetConversion::get_reserves(token_3.clone(), token_2.clone()).unwrap();
+		let amount_2 =
+			AssetConversion::get_stable_amount_out(&input_amount, &reserve_3, &reserve_2, 100, 3)
+				.unwrap();
+		let (reserve_2, reserve_1) =
+			AssetConversion::get_reserves(token_2.clone(), token_1.clone()).unwrap();
+		let expect_receive =
+			AssetConversion::get_amount_out(&amount_2, &reserve_2, &reserve_1).unwrap();
+
+		let native_before = balance(user, token_1.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_2.clone(), token_1.clone()],
+			input_amount,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_1.clone()), native_before + expect_receive);
+	});
+}
@@ -19,7 +19,7 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_runtime::{traits::TryConvert, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +41,30 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// The maximum amplification coefficient of a [`PoolKind::StableSwap`] pool.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+/// The maximum number of iterations of Newton's method when computing the stable-swap invariant.
+pub(crate) const STABLE_SWAP_MAX_ITERATIONS: u32 = 255;
+
+/// The invariant used to price the swaps of a liquidity pool.
+#[derive(
+	Decode, Encode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum PoolKind {
+	/// The [Uniswap V2](https://github.com/Uniswap/v2-core) constant product invariant
+	/// `x * y = k`.
+	#[default]
+	ConstantProduct,
+	/// The [Curve](https://curve.fi/files/stableswap-paper.pdf) stable-swap invariant, suited for
+	/// pairs of assets expected to trade close to parity, e.g. two variants of a stablecoin.
+	StableSwap {
+		/// The amplification coefficient `A` of the invariant, within `1..=MAX_AMPLIFICATION`.
+		/// The higher it is, the flatter the price curve is around the balanced pool.
+		amplification: u32,
+	},
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
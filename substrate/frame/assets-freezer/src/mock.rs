@@ -99,6 +99,7 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 	type Currency = Balances;
 	type Freezer = AssetsFreezer;
+	type Holder = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -0,0 +1,254 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pallet_assets::{BalanceOnHold, FrozenBalance};
+
+// Implements [`BalanceOnHold`] from [`pallet-assets`], so it can understand how much of an
+// account balance is on hold, and is able to signal to this pallet when to clear the state of an
+// account.
+impl<T: Config<I>, I: 'static> BalanceOnHold<T::AssetId, T::AccountId, T::Balance>
+	for Pallet<T, I>
+{
+	fn balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		BalancesOnHold::<T, I>::get(asset, who)
+	}
+
+	fn died(asset: T::AssetId, who: &T::AccountId) {
+		BalancesOnHold::<T, I>::remove(asset.clone(), who);
+		Holds::<T, I>::remove(asset, who);
+	}
+}
+
+// Implement [`fungibles::Inspect`](frame_support::traits::fungibles::Inspect) as it is bound by
+// [`fungibles::InspectHold`](frame_support::traits::fungibles::InspectHold) and
+// [`fungibles::MutateHold`](frame_support::traits::fungibles::MutateHold). To do so, we'll
+// re-export all of `pallet-assets` implementation of the same trait.
+impl<T: Config<I>, I: 'static> Inspect<T::AccountId> for Pallet<T, I> {
+	type AssetId = T::AssetId;
+	type Balance = T::Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		pallet_assets::Pallet::<T, I>::total_issuance(asset)
+	}
+
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		pallet_assets::Pallet::<T, I>::minimum_balance(asset)
+	}
+
+	fn total_balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		pallet_assets::Pallet::<T, I>::total_balance(asset, who)
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		pallet_assets::Pallet::<T, I>::balance(asset, who)
+	}
+
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Self::Balance {
+		pallet_assets::Pallet::<T, I>::reducible_balance(asset, who, preservation, force)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		pallet_assets::Pallet::<T, I>::can_deposit(asset, who, amount, provenance)
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		pallet_assets::Pallet::<T, I>::can_withdraw(asset, who, amount)
+	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		pallet_assets::Pallet::<T, I>::asset_exists(asset)
+	}
+}
+
+// Implement [`fungibles::Unbalanced`](frame_support::traits::fungibles::Unbalanced) as it is bound
+// by [`fungibles::MutateHold`](frame_support::traits::fungibles::MutateHold), which moves funds
+// between the `pallet-assets` balance and the balance on hold. To do so, we'll re-export all of
+// `pallet-assets` implementation of the same trait.
+impl<T: Config<I>, I: 'static> Unbalanced<T::AccountId> for Pallet<T, I> {
+	fn handle_raw_dust(asset: Self::AssetId, amount: Self::Balance) {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::handle_raw_dust(asset, amount)
+	}
+
+	fn handle_dust(dust: fungibles::Dust<T::AccountId, Self>) {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::handle_dust(fungibles::Dust(
+			dust.0, dust.1,
+		))
+	}
+
+	fn write_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::write_balance(asset, who, amount)
+	}
+
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::set_total_issuance(asset, amount)
+	}
+
+	fn decrease_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		precision: Precision,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Result<Self::Balance, DispatchError> {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::decrease_balance(
+			asset,
+			who,
+			amount,
+			precision,
+			preservation,
+			force,
+		)
+	}
+
+	fn increase_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		precision: Precision,
+	) -> Result<Self::Balance, DispatchError> {
+		<pallet_assets::Pallet<T, I> as Unbalanced<T::AccountId>>::increase_balance(
+			asset, who, amount, precision,
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> InspectHold<T::AccountId> for Pallet<T, I> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		BalancesOnHold::<T, I>::get(asset, who).unwrap_or_else(Zero::zero)
+	}
+
+	fn reducible_total_balance_on_hold(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		force: Fortitude,
+	) -> Self::Balance {
+		let total_on_hold = Self::total_balance_on_hold(asset.clone(), who);
+		// The part of the frozen balance which the free balance can't cover is covered by the
+		// balance on hold.
+		let unavailable = match force {
+			Fortitude::Force => Zero::zero(),
+			Fortitude::Polite => {
+				let frozen =
+					<T as pallet_assets::Config<I>>::Freezer::frozen_balance(asset.clone(), who)
+						.unwrap_or_else(Zero::zero);
+				let free = pallet_assets::Pallet::<T, I>::balance(asset.clone(), who)
+					.saturating_sub(Self::minimum_balance(asset));
+				frozen.saturating_sub(free)
+			},
+		};
+		total_on_hold.saturating_sub(unavailable)
+	}
+
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		let holds = Holds::<T, I>::get(asset, who);
+		holds.into_iter().find(|h| &h.id == reason).map_or(Zero::zero(), |h| h.amount)
+	}
+
+	fn hold_available(asset: Self::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+		let holds = Holds::<T, I>::get(asset.clone(), who);
+		pallet_assets::Pallet::<T, I>::maybe_balance(asset, who).is_some() &&
+			(!holds.is_full() || holds.into_iter().any(|h| &h.id == reason))
+	}
+}
+
+impl<T: Config<I>, I: 'static> UnbalancedHold<T::AccountId> for Pallet<T, I> {
+	fn set_balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		// Funds can only be held by an existing asset account.
+		ensure!(
+			amount.is_zero() ||
+				pallet_assets::Pallet::<T, I>::maybe_balance(asset.clone(), who).is_some(),
+			TokenError::CannotCreateHold
+		);
+
+		let mut holds = Holds::<T, I>::get(asset.clone(), who);
+		if amount.is_zero() {
+			holds.retain(|h| &h.id != reason);
+		} else if let Some(h) = holds.iter_mut().find(|h| &h.id == reason) {
+			h.amount = amount;
+		} else {
+			holds
+				.try_push(IdAmount { id: *reason, amount })
+				.map_err(|_| Error::<T, I>::TooManyHolds)?;
+		}
+		Self::update_holds(asset, who, holds.as_bounded_slice())
+	}
+}
+
+impl<T: Config<I>, I: 'static> MutateHold<T::AccountId> for Pallet<T, I> {
+	fn done_hold(
+		asset_id: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) {
+		Self::deposit_event(Event::Held { who: who.clone(), asset_id, reason: *reason, amount });
+	}
+
+	fn done_release(
+		asset_id: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) {
+		Self::deposit_event(Event::Released {
+			who: who.clone(),
+			asset_id,
+			reason: *reason,
+			amount,
+		});
+	}
+
+	fn done_burn_held(
+		asset_id: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) {
+		Self::deposit_event(Event::Burned { who: who.clone(), asset_id, reason: *reason, amount });
+	}
+}
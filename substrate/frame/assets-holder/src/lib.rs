@@ -0,0 +1,215 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Holder Pallet
+//!
+//! A pallet capable of holding fungibles from `pallet-assets`. This is an extension of
+//! `pallet-assets`, wrapping [`fungibles::Inspect`](`Inspect`) and
+//! [`fungibles::Unbalanced`](`Unbalanced`).
+//! It implements
+//! [`fungibles::hold::Inspect`](InspectHold),
+//! [`fungibles::hold::Unbalanced`](UnbalancedHold) and
+//! [`fungibles::hold::Mutate`](MutateHold). The complexity
+//! of the operations is `O(n)`. where `n` is the variant count of `RuntimeHoldReason`.
+//!
+//! ## Pallet API
+//!
+//! See the [`pallet`] module for more information about the interfaces this pallet exposes,
+//! including its configuration trait, dispatchables, storage items, events and errors.
+//!
+//! ## Overview
+//!
+//! This pallet provides the following functionality:
+//!
+//! - Pallet hooks allowing [`pallet-assets`] to know the balance on hold for an account on a given
+//!   asset (see [`pallet_assets::BalanceOnHold`]).
+//! - An implementation of [`fungibles::hold::Inspect`](InspectHold),
+//!   [`fungibles::hold::Unbalanced`](UnbalancedHold) and [`fungibles::hold::Mutate`](MutateHold),
+//!   allowing other pallets to manage holds for the `pallet-assets` assets.
+//!
+//! Funds on hold are taken out of the `pallet-assets` balance of the account, but remain part of
+//! its total balance and of the asset's supply. An account with funds on hold is kept alive, and
+//! its funds on hold count towards its frozen balance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame::{
+	prelude::*,
+	traits::{
+		fungibles::{self, Inspect, InspectHold, MutateHold, Unbalanced, UnbalancedHold},
+		tokens::{
+			DepositConsequence, Fortitude, IdAmount, Precision, Preservation, Provenance,
+			WithdrawConsequence,
+		},
+	},
+};
+
+pub use pallet::*;
+
+#[cfg(feature = "try-runtime")]
+use frame::try_runtime::TryRuntimeError;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod impls;
+
+#[frame::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config(with_default)]
+	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_assets::Config<I> {
+		/// The overarching hold reason.
+		#[pallet::no_default_bounds]
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy + VariantCount;
+
+		/// The overarching event type.
+		#[pallet::no_default_bounds]
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Number of holds on an account would exceed the count of `RuntimeHoldReason`.
+		TooManyHolds,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		// `who`s balance on hold was increased by `amount`.
+		Held {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		// `who`s balance on hold was decreased by `amount`.
+		Released {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		// `who`s balance on hold was burned by `amount`.
+		Burned {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+	}
+
+	/// A map that stores holds applied on an account for a given AssetId.
+	#[pallet::storage]
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<
+			IdAmount<T::RuntimeHoldReason, T::Balance>,
+			VariantCountOf<T::RuntimeHoldReason>,
+		>,
+		ValueQuery,
+	>;
+
+	/// A map that stores the current total balance on hold for every account on a given AssetId.
+	#[pallet::storage]
+	pub(super) type BalancesOnHold<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	fn update_holds(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		holds: BoundedSlice<
+			IdAmount<T::RuntimeHoldReason, T::Balance>,
+			VariantCountOf<T::RuntimeHoldReason>,
+		>,
+	) -> DispatchResult {
+		let mut total = T::Balance::zero();
+		for hold in holds.iter() {
+			total = total.checked_add(&hold.amount).ok_or(ArithmeticError::Overflow)?;
+		}
+		if holds.is_empty() {
+			Holds::<T, I>::remove(asset.clone(), who);
+			BalancesOnHold::<T, I>::remove(asset, who);
+		} else {
+			Holds::<T, I>::insert(asset.clone(), who, holds);
+			BalancesOnHold::<T, I>::insert(asset, who, total);
+		}
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn do_try_state() -> Result<(), TryRuntimeError> {
+		for (asset, who, balance_on_hold) in BalancesOnHold::<T, I>::iter() {
+			let holds = Holds::<T, I>::get(asset.clone(), who.clone());
+			ensure!(
+				!holds.is_empty(),
+				"`BalancesOnHold` exists without `Holds` for (`asset`, `who`)"
+			);
+			ensure!(
+				holds.iter().all(|h| !h.amount.is_zero()),
+				"`Holds` contains a zero amount for (`asset`, `who`)"
+			);
+
+			let total = holds.iter().fold(T::Balance::zero(), |t, h| t.saturating_add(h.amount));
+			ensure!(
+				balance_on_hold == total,
+				"The `BalanceOnHold` is not equal to the sum of amounts in `Holds` for (`asset`, `who`)"
+			);
+			ensure!(
+				pallet_assets::Pallet::<T, I>::maybe_balance(asset, who).is_some(),
+				"An account with balance on hold has no asset account for (`asset`, `who`)"
+			);
+		}
+
+		for (asset, who, _) in Holds::<T, I>::iter() {
+			ensure!(
+				BalancesOnHold::<T, I>::contains_key(asset, who),
+				"`Holds` exists without `BalancesOnHold` for (`asset`, `who`)"
+			);
+		}
+
+		Ok(())
+	}
+}
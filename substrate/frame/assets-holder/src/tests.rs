@@ -0,0 +1,342 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for pallet-assets-holder.
+
+use crate::mock::{self, *};
+
+use codec::Compact;
+use frame::{
+	testing_prelude::*,
+	traits::tokens::{Precision, Restriction},
+};
+use pallet_assets::BalanceOnHold;
+
+const WHO: AccountId = 1;
+const ASSET_ID: mock::AssetId = 1;
+
+fn test_set_hold(id: DummyHoldReason, amount: mock::Balance) {
+	assert_ok!(AssetsHolder::set_balance_on_hold(ASSET_ID, &id, &WHO, amount));
+}
+
+mod impl_balance_on_hold {
+	use super::*;
+
+	#[test]
+	fn balance_on_hold_works() {
+		new_test_ext(|| {
+			assert_eq!(
+				<AssetsHolder as BalanceOnHold<_, _, _>>::balance_on_hold(ASSET_ID, &WHO),
+				None
+			);
+			test_set_hold(DummyHoldReason::Governance, 1);
+			assert_eq!(
+				<AssetsHolder as BalanceOnHold<_, _, _>>::balance_on_hold(ASSET_ID, &WHO),
+				Some(1u64)
+			);
+			test_set_hold(DummyHoldReason::Staking, 3);
+			assert_eq!(
+				<AssetsHolder as BalanceOnHold<_, _, _>>::balance_on_hold(ASSET_ID, &WHO),
+				Some(4u64)
+			);
+			// also test releasing works to reduce a balance, and finally releasing everything
+			// resets to None
+			test_set_hold(DummyHoldReason::Governance, 0);
+			assert_eq!(
+				<AssetsHolder as BalanceOnHold<_, _, _>>::balance_on_hold(ASSET_ID, &WHO),
+				Some(3u64)
+			);
+			test_set_hold(DummyHoldReason::Staking, 0);
+			assert_eq!(
+				<AssetsHolder as BalanceOnHold<_, _, _>>::balance_on_hold(ASSET_ID, &WHO),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn died_works() {
+		new_test_ext(|| {
+			test_set_hold(DummyHoldReason::Governance, 1);
+			AssetsHolder::died(ASSET_ID, &WHO);
+			assert!(BalancesOnHold::<Test>::get(ASSET_ID, WHO).is_none());
+			assert!(Holds::<Test>::get(ASSET_ID, WHO).is_empty());
+		});
+	}
+}
+
+mod impl_inspect_hold {
+	use super::*;
+
+	#[test]
+	fn total_balance_on_hold_works() {
+		new_test_ext(|| {
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 0u64);
+			test_set_hold(DummyHoldReason::Governance, 1);
+			test_set_hold(DummyHoldReason::Staking, 3);
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 4u64);
+			test_set_hold(DummyHoldReason::Staking, 2);
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 3u64);
+		});
+	}
+
+	#[test]
+	fn balance_on_hold_works() {
+		new_test_ext(|| {
+			assert_eq!(
+				<AssetsHolder as InspectHold<_>>::balance_on_hold(
+					ASSET_ID,
+					&DummyHoldReason::Governance,
+					&WHO
+				),
+				0u64
+			);
+			test_set_hold(DummyHoldReason::Governance, 1);
+			test_set_hold(DummyHoldReason::Staking, 3);
+			assert_eq!(
+				<AssetsHolder as InspectHold<_>>::balance_on_hold(
+					ASSET_ID,
+					&DummyHoldReason::Governance,
+					&WHO
+				),
+				1u64
+			);
+			assert_eq!(
+				<AssetsHolder as InspectHold<_>>::balance_on_hold(
+					ASSET_ID,
+					&DummyHoldReason::Staking,
+					&WHO
+				),
+				3u64
+			);
+		});
+	}
+
+	/// This tests it's not possible to hold once the holds [`BoundedVec`] is full, nor for an
+	/// account that doesn't exist.
+	/// This test assumes a mock configuration where the variant count of the hold reason is `2`.
+	#[test]
+	fn hold_available_works() {
+		new_test_ext(|| {
+			assert!(!AssetsHolder::hold_available(ASSET_ID, &DummyHoldReason::Governance, &2));
+			test_set_hold(DummyHoldReason::Governance, 1);
+			assert!(AssetsHolder::hold_available(ASSET_ID, &DummyHoldReason::Staking, &WHO));
+			test_set_hold(DummyHoldReason::Staking, 1);
+			assert!(!AssetsHolder::hold_available(ASSET_ID, &DummyHoldReason::Other, &WHO));
+			assert!(AssetsHolder::hold_available(ASSET_ID, &DummyHoldReason::Staking, &WHO));
+		});
+	}
+}
+
+mod impl_unbalanced_hold {
+	use super::*;
+
+	#[test]
+	fn set_balance_on_hold_works() {
+		new_test_ext(|| {
+			test_set_hold(DummyHoldReason::Governance, 1);
+			test_set_hold(DummyHoldReason::Staking, 2);
+			assert_noop!(
+				AssetsHolder::set_balance_on_hold(ASSET_ID, &DummyHoldReason::Other, &WHO, 1),
+				Error::<Test>::TooManyHolds
+			);
+			assert_noop!(
+				AssetsHolder::set_balance_on_hold(ASSET_ID, &DummyHoldReason::Other, &2, 1),
+				TokenError::CannotCreateHold
+			);
+			// a zero amount removes the hold and makes room for another one.
+			test_set_hold(DummyHoldReason::Governance, 0);
+			assert_ok!(AssetsHolder::set_balance_on_hold(
+				ASSET_ID,
+				&DummyHoldReason::Other,
+				&WHO,
+				1
+			));
+			assert_eq!(BalancesOnHold::<Test>::get(ASSET_ID, WHO), Some(3u64));
+		});
+	}
+}
+
+mod impl_mutate_hold {
+	use super::*;
+
+	#[test]
+	fn hold_works() {
+		new_test_ext(|| {
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Governance, &WHO, 10));
+			System::assert_last_event(
+				Event::<Test>::Held {
+					who: WHO,
+					asset_id: ASSET_ID,
+					reason: DummyHoldReason::Governance,
+					amount: 10,
+				}
+				.into(),
+			);
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 90);
+			assert_eq!(Assets::total_balance(ASSET_ID, &WHO), 100);
+			assert_eq!(Assets::total_issuance(ASSET_ID), 100);
+
+			// the held funds can't take the balance below the minimum balance.
+			assert_noop!(
+				AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Staking, &WHO, 90),
+				TokenError::FundsUnavailable
+			);
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Staking, &WHO, 89));
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 1);
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 99);
+		});
+	}
+
+	#[test]
+	fn release_works() {
+		new_test_ext(|| {
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Governance, &WHO, 10));
+			assert_ok!(AssetsHolder::release(
+				ASSET_ID,
+				&DummyHoldReason::Governance,
+				&WHO,
+				4,
+				Precision::Exact
+			));
+			System::assert_last_event(
+				Event::<Test>::Released {
+					who: WHO,
+					asset_id: ASSET_ID,
+					reason: DummyHoldReason::Governance,
+					amount: 4,
+				}
+				.into(),
+			);
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 94);
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 6);
+
+			assert_eq!(
+				AssetsHolder::release(
+					ASSET_ID,
+					&DummyHoldReason::Governance,
+					&WHO,
+					10,
+					Precision::BestEffort
+				),
+				Ok(6)
+			);
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 100);
+			assert!(BalancesOnHold::<Test>::get(ASSET_ID, WHO).is_none());
+		});
+	}
+
+	#[test]
+	fn burn_held_works() {
+		new_test_ext(|| {
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Governance, &WHO, 10));
+			assert_ok!(AssetsHolder::burn_held(
+				ASSET_ID,
+				&DummyHoldReason::Governance,
+				&WHO,
+				10,
+				Precision::Exact,
+				Fortitude::Polite
+			));
+			System::assert_last_event(
+				Event::<Test>::Burned {
+					who: WHO,
+					asset_id: ASSET_ID,
+					reason: DummyHoldReason::Governance,
+					amount: 10,
+				}
+				.into(),
+			);
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 90);
+			assert_eq!(Assets::total_issuance(ASSET_ID), 90);
+			assert!(BalancesOnHold::<Test>::get(ASSET_ID, WHO).is_none());
+		});
+	}
+
+	#[test]
+	fn transfer_on_hold_works() {
+		new_test_ext(|| {
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Governance, &WHO, 10));
+			assert_ok!(AssetsHolder::transfer_on_hold(
+				ASSET_ID,
+				&DummyHoldReason::Governance,
+				&WHO,
+				&2,
+				5,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite
+			));
+			assert_eq!(Assets::balance(ASSET_ID, 2), 5);
+
+			// the destination must have an asset account to receive funds on hold.
+			assert_noop!(
+				AssetsHolder::transfer_on_hold(
+					ASSET_ID,
+					&DummyHoldReason::Governance,
+					&WHO,
+					&3,
+					5,
+					Precision::Exact,
+					Restriction::OnHold,
+					Fortitude::Polite
+				),
+				TokenError::CannotCreateHold
+			);
+			assert_ok!(AssetsHolder::transfer_on_hold(
+				ASSET_ID,
+				&DummyHoldReason::Governance,
+				&WHO,
+				&2,
+				5,
+				Precision::Exact,
+				Restriction::OnHold,
+				Fortitude::Polite
+			));
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &2), 5);
+			assert_eq!(AssetsHolder::total_balance_on_hold(ASSET_ID, &WHO), 0);
+		});
+	}
+}
+
+mod with_pallet_assets {
+	use super::*;
+
+	#[test]
+	fn balance_on_hold_keeps_account_alive() {
+		new_test_ext(|| {
+			assert_ok!(AssetsHolder::hold(ASSET_ID, &DummyHoldReason::Governance, &WHO, 20));
+			assert_noop!(
+				Assets::transfer(RuntimeOrigin::signed(WHO), Compact(ASSET_ID), 2, 80),
+				pallet_assets::Error::<Test>::BalanceLow,
+			);
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(WHO), Compact(ASSET_ID), 2, 79));
+			assert_eq!(Assets::balance(ASSET_ID, WHO), 1);
+
+			// releasing the hold allows the account to be removed.
+			assert_ok!(AssetsHolder::release(
+				ASSET_ID,
+				&DummyHoldReason::Governance,
+				&WHO,
+				20,
+				Precision::Exact
+			));
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(WHO), Compact(ASSET_ID), 2, 21));
+			assert!(Assets::maybe_balance(ASSET_ID, WHO).is_none());
+		});
+	}
+}
@@ -193,7 +193,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
+			let held = T::Holder::balance_on_hold(id.clone(), who);
 			if let Some(frozen) = T::Freezer::frozen_balance(id.clone(), who) {
+				// Funds on hold count towards the frozen balance.
+				let frozen = frozen.saturating_sub(held.unwrap_or_else(Zero::zero));
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -202,7 +205,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			if rest < details.min_balance {
-				if keep_alive {
+				// An account with funds on hold must outlive its holds.
+				if keep_alive || held.is_some() {
 					WouldDie
 				} else {
 					ReducedToZero(rest)
@@ -228,14 +232,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 
+		let held = T::Holder::balance_on_hold(id.clone(), who);
 		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
-			// Frozen balance: account CANNOT be deleted
-			let required =
-				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
+			// Frozen balance: account CANNOT be deleted. Funds on hold count towards it.
+			let required = frozen
+				.saturating_sub(held.unwrap_or_else(Zero::zero))
+				.checked_add(&details.min_balance)
+				.ok_or(ArithmeticError::Overflow)?;
 			account.balance.saturating_sub(required)
 		} else {
-			if keep_alive {
-				// We want to keep the account around.
+			if keep_alive || held.is_some() {
+				// We want to keep the account around, or it has funds on hold.
 				account.balance.saturating_sub(details.min_balance)
 			} else {
 				// Don't care if the account dies
@@ -356,6 +363,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
+		ensure!(
+			T::Holder::balance_on_hold(id.clone(), &who).is_none(),
+			Error::<T, I>::ContainsHolds
+		);
 
 		if let Some(deposit) = account.reason.take_deposit() {
 			T::Currency::unreserve(&who, deposit);
@@ -371,7 +382,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 		Asset::<T, I>::insert(&id, details);
 		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		T::Freezer::died(id.clone(), &who);
+		T::Holder::died(id, &who);
 		Ok(())
 	}
 
@@ -394,6 +406,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(caller == depositor || caller == details.admin, Error::<T, I>::NoPermission);
 		}
 		ensure!(account.balance.is_zero(), Error::<T, I>::WouldBurn);
+		ensure!(
+			T::Holder::balance_on_hold(id.clone(), who).is_none(),
+			Error::<T, I>::ContainsHolds
+		);
 
 		T::Currency::unreserve(&depositor, deposit);
 
@@ -407,7 +423,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 		Asset::<T, I>::insert(&id, details);
 		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		T::Freezer::died(id.clone(), &who);
+		T::Holder::died(id, &who);
 		return Ok(())
 	}
 
@@ -575,7 +592,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		// Execute hook outside of `mutate`.
 		if let Some(Remove) = target_died {
-			T::Freezer::died(id, target);
+			T::Freezer::died(id.clone(), target);
+			T::Holder::died(id, target);
 		}
 		Ok(actual)
 	}
@@ -599,13 +617,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let (balance, died) =
 			Self::transfer_and_die(id.clone(), source, dest, amount, maybe_need_admin, f)?;
 		if let Some(Remove) = died {
-			T::Freezer::died(id, source);
+			T::Freezer::died(id.clone(), source);
+			T::Holder::died(id, source);
 		}
 		Ok(balance)
 	}
 
-	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` hook and
-	/// instead returns whether and how the `source` account died in this operation.
+	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` and
+	/// `BalanceOnHold::died` hooks and instead returns whether and how the `source` account died in
+	/// this operation.
 	fn transfer_and_die(
 		id: T::AssetId,
 		source: &T::AccountId,
@@ -800,6 +820,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		for who in &dead_accounts {
 			T::Freezer::died(id.clone(), &who);
+			T::Holder::died(id.clone(), &who);
 		}
 
 		Self::deposit_event(Event::AccountsDestroyed {
@@ -960,7 +981,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		// Execute hook outside of `mutate`.
 		if let Some(Remove) = owner_died {
-			T::Freezer::died(id, owner);
+			T::Freezer::died(id.clone(), owner);
+			T::Holder::died(id, owner);
 		}
 		Ok(())
 	}
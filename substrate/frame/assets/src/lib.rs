@@ -393,6 +393,11 @@ pub mod pallet {
 		#[pallet::no_default]
 		type Freezer: FrozenBalance<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook to inspect the per-asset, per-account balance that is held apart from the
+		/// account balance. Accounts with balance on hold are kept alive.
+		#[pallet::no_default]
+		type Holder: BalanceOnHold<Self::AssetId, Self::AccountId, Self::Balance>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -688,6 +693,8 @@ pub mod pallet {
 		CallbackFailed,
 		/// The asset ID must be equal to the [`NextAssetId`].
 		BadAssetId,
+		/// The asset-account has balance on hold and cannot be removed.
+		ContainsHolds,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -1375,6 +1375,62 @@ fn freezer_should_work() {
 	});
 }
 
+#[test]
+fn holder_should_work() {
+	use frame_support::traits::fungibles::Inspect;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// hold 50 on top of the balance.
+		set_balance_on_hold(0, 1, 50);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(<Assets as Inspect<_>>::total_balance(0, &1), 150);
+
+		// the held funds count towards the frozen balance, so only 20 of the 70 frozen need to
+		// stay in the account on top of the minimum balance.
+		set_frozen_balance(0, 1, 70);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 71),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 70));
+
+		// without freezes, the account must still be kept alive because of the hold.
+		clear_frozen_balance(0, 1);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+		assert_eq!(Assets::balance(0, 1), 10);
+
+		// once the hold is gone, the account can be removed.
+		clear_balance_on_hold(0, 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_eq!(hooks(), vec![Hook::Died(0, 1)]);
+	});
+}
+
+#[test]
+fn refund_with_balance_on_hold_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 10));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+
+		set_balance_on_hold(0, 1, 50);
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(1), 0, true),
+			Error::<Test>::ContainsHolds
+		);
+
+		clear_balance_on_hold(0, 1);
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), 0, true));
+	});
+}
+
 #[test]
 fn imbalances_should_work() {
 	use frame_support::traits::fungibles::Balanced;
@@ -232,6 +232,32 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// Trait for specifying a balance that is held apart from the balance of an account. Held funds
+/// are not part of the account balance, but they still belong to the account and are part of the
+/// asset's supply.
+pub trait BalanceOnHold<AssetId, AccountId, Balance> {
+	/// Return the balance on hold.
+	///
+	/// An account with balance on hold must not be removed, so its balance may not go below the
+	/// asset's `minimum_balance` while this is `Some`. The balance on hold counts towards the
+	/// frozen balance of the account (see [`FrozenBalance`]).
+	///
+	/// If `None` is returned, then nothing is held.
+	fn balance_on_hold(asset: AssetId, who: &AccountId) -> Option<Balance>;
+
+	/// Called after an account has been removed.
+	///
+	/// NOTE: It is possible that the asset does no longer exist when this hook is called.
+	fn died(asset: AssetId, who: &AccountId);
+}
+
+impl<AssetId, AccountId, Balance> BalanceOnHold<AssetId, AccountId, Balance> for () {
+	fn balance_on_hold(_: AssetId, _: &AccountId) -> Option<Balance> {
+		None
+	}
+	fn died(_: AssetId, _: &AccountId) {}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
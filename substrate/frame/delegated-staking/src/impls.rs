@@ -98,6 +98,14 @@ impl<T: Config> DelegationInterface for Pallet<T> {
 	) -> sp_runtime::DispatchResult {
 		Pallet::<T>::do_slash(agent, delegator, value, maybe_reporter)
 	}
+
+	fn transfer_delegation(
+		agent: Agent<Self::AccountId>,
+		delegator: Delegator<Self::AccountId>,
+		new_delegator: Delegator<Self::AccountId>,
+	) -> DispatchResult {
+		Pallet::<T>::do_transfer_delegation(agent, delegator, new_delegator)
+	}
 }
 
 impl<T: Config> DelegationMigrator for Pallet<T> {
@@ -266,6 +266,13 @@ pub mod pallet {
 		Slashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
 		/// Unclaimed delegation funds migrated to delegator.
 		MigratedDelegation { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegation transferred from one delegator to another.
+		DelegationTransferred {
+			agent: T::AccountId,
+			delegator: T::AccountId,
+			new_delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Map of Delegators to their `Delegation`.
@@ -710,6 +717,55 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Move the whole delegation of `delegator` to `agent` over to `new_delegator`.
+	///
+	/// The held funds are transferred along with the delegation, so the agent ledger is left
+	/// untouched.
+	pub fn do_transfer_delegation(
+		agent: Agent<T::AccountId>,
+		delegator: Delegator<T::AccountId>,
+		new_delegator: Delegator<T::AccountId>,
+	) -> DispatchResult {
+		// get inner type
+		let agent = agent.get();
+		let delegator = delegator.get();
+		let new_delegator = new_delegator.get();
+
+		// ensure new delegator is sane.
+		ensure!(new_delegator != delegator, Error::<T>::InvalidDelegation);
+		ensure!(!Self::is_agent(&new_delegator), Error::<T>::NotAllowed);
+		ensure!(!Self::is_delegator(&new_delegator), Error::<T>::NotAllowed);
+		ensure!(!Self::is_direct_staker(&new_delegator), Error::<T>::AlreadyStaking);
+
+		let delegation = Delegation::<T>::get(&delegator).ok_or(Error::<T>::NotDelegator)?;
+		ensure!(delegation.agent == agent, Error::<T>::NotAgent);
+		let amount = delegation.amount;
+
+		// transfer the held amount in `delegator` to `new_delegator`.
+		let _ = T::Currency::transfer_on_hold(
+			&HoldReason::StakingDelegation.into(),
+			&delegator,
+			&new_delegator,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		// create the delegation for the new delegator and clean up the old one.
+		Delegation::<T>::new(&agent, amount).update(&new_delegator);
+		Delegation::<T>::new(&agent, Zero::zero()).update(&delegator);
+
+		Self::deposit_event(Event::<T>::DelegationTransferred {
+			agent,
+			delegator,
+			new_delegator,
+			amount,
+		});
+
+		Ok(())
+	}
+
 	/// Take slash `amount` from agent's `pending_slash`counter and apply it to `delegator` account.
 	pub fn do_slash(
 		agent: Agent<T::AccountId>,
@@ -364,6 +364,85 @@ fn allow_full_amount_to_be_delegated() {
 	});
 }
 
+#[test]
+fn transfer_delegation_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let agent: AccountId = 200;
+		let reward_acc: AccountId = 201;
+		let delegator: AccountId = 300;
+		let new_delegator: AccountId = 301;
+		let other_delegator: AccountId = 302;
+
+		fund(&agent, 1000);
+		assert_ok!(DelegatedStaking::register_agent(RawOrigin::Signed(agent).into(), reward_acc));
+		for who in [delegator, other_delegator] {
+			fund(&who, 1000);
+			assert_ok!(DelegatedStaking::delegate_to_agent(
+				RawOrigin::Signed(who).into(),
+				agent,
+				500
+			));
+		}
+		fund(&new_delegator, 100);
+		let _ = events_since_last_call();
+
+		// cannot transfer to an existing delegator or agent.
+		assert_noop!(
+			DelegatedStaking::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(delegator),
+				Delegator::from(other_delegator)
+			),
+			Error::<T>::NotAllowed
+		);
+		assert_noop!(
+			DelegatedStaking::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(delegator),
+				Delegator::from(agent)
+			),
+			Error::<T>::NotAllowed
+		);
+		// delegation needs to exist and be to the given agent.
+		assert_noop!(
+			DelegatedStaking::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(new_delegator),
+				Delegator::from(303)
+			),
+			Error::<T>::NotDelegator
+		);
+		assert_noop!(
+			DelegatedStaking::transfer_delegation(
+				Agent::from(reward_acc),
+				Delegator::from(delegator),
+				Delegator::from(new_delegator)
+			),
+			Error::<T>::NotAgent
+		);
+
+		// when
+		assert_ok!(DelegatedStaking::transfer_delegation(
+			Agent::from(agent),
+			Delegator::from(delegator),
+			Delegator::from(new_delegator)
+		));
+
+		// then
+		assert!(!DelegatedStaking::is_delegator(&delegator));
+		assert!(DelegatedStaking::is_delegator(&new_delegator));
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(delegator)), 0);
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(new_delegator)), 500);
+		assert_eq!(DelegatedStaking::delegator_balance(Delegator::from(new_delegator)), Some(500));
+		// agent is not affected.
+		assert_eq!(get_agent_ledger(&agent).ledger.total_delegated, 1000);
+		assert_eq!(
+			events_since_last_call(),
+			vec![Event::DelegationTransferred { agent, delegator, new_delegator, amount: 500 }]
+		);
+	});
+}
+
 /// Integration tests with pallet-staking.
 mod staking_integration {
 	use super::*;
@@ -45,6 +45,24 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+/// Create a persistent multisig of `s` signatories with a threshold of `s`, created by the last
+/// signatory.
+fn setup_persistent_multi<T: Config>(
+	s: u32,
+	z: u32,
+) -> Result<(Vec<T::AccountId>, T::AccountId, Box<<T as Config>::RuntimeCall>), BenchmarkError> {
+	let (mut signatories, call) = setup_multi::<T>(s, z)?;
+	let creator = signatories.pop().ok_or("signatories should have len 2 or more")?;
+	let multisig = Multisig::<T>::persistent_account_id(&creator, &Multisig::<T>::timepoint());
+	Multisig::<T>::create_persistent_multisig(
+		RawOrigin::Signed(creator.clone()).into(),
+		s as u16,
+		signatories.clone(),
+	)?;
+	signatories.push(creator);
+	Ok((signatories, multisig, call))
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -296,5 +314,264 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn create_persistent_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (mut signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let multisig = Multisig::<T>::persistent_account_id(&caller, &Multisig::<T>::timepoint());
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories);
+
+		assert!(PersistentMultisigs::<T>::contains_key(multisig));
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn as_persistent_multi_create(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_persistent_multi(
+			RawOrigin::Signed(caller),
+			multisig.clone(),
+			None,
+			call,
+			Weight::zero(),
+		);
+
+		assert!(Multisigs::<T>::contains_key(multisig, call_hash));
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, need at least 3 people (so we don't complete the multisig)
+	#[benchmark]
+	fn as_persistent_multi_approve(
+		s: Linear<3, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_persistent_multi(
+			RawOrigin::Signed(signatories[0].clone()).into(),
+			multisig.clone(),
+			None,
+			call.clone(),
+			Weight::zero(),
+		)?;
+		let caller2 = signatories[1].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_persistent_multi(
+			RawOrigin::Signed(caller2),
+			multisig.clone(),
+			Some(timepoint),
+			call,
+			Weight::zero(),
+		);
+
+		let multisig = Multisigs::<T>::get(multisig, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn as_persistent_multi_complete(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Everyone except the last person approves
+		for (i, signatory) in signatories.iter().take(s as usize - 1).enumerate() {
+			Multisig::<T>::as_persistent_multi(
+				RawOrigin::Signed(signatory.clone()).into(),
+				multisig.clone(),
+				if i == 0 { None } else { Some(timepoint) },
+				call.clone(),
+				Weight::zero(),
+			)?;
+		}
+		assert!(Multisigs::<T>::contains_key(&multisig, call_hash));
+		let caller2 = signatories[s as usize - 1].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_persistent_multi(
+			RawOrigin::Signed(caller2),
+			multisig.clone(),
+			Some(timepoint),
+			call,
+			Weight::MAX,
+		);
+
+		assert!(!Multisigs::<T>::contains_key(&multisig, call_hash));
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn approve_as_persistent_multi_create(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// The call is neither in storage or an argument, so just use any:
+		let call_len = 10_000;
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, call_len)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		// Create the multi
+		#[extrinsic_call]
+		approve_as_persistent_multi(
+			RawOrigin::Signed(caller),
+			multisig.clone(),
+			None,
+			call_hash,
+			Weight::zero(),
+		);
+
+		assert!(Multisigs::<T>::contains_key(multisig, call_hash));
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn approve_as_persistent_multi_approve(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// The call is neither in storage or an argument, so just use any:
+		let call_len = 10_000;
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, call_len)?;
+		let call_hash = call.using_encoded(blake2_256);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_persistent_multi(
+			RawOrigin::Signed(signatories[0].clone()).into(),
+			multisig.clone(),
+			None,
+			call,
+			Weight::zero(),
+		)?;
+		let caller2 = signatories[1].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		approve_as_persistent_multi(
+			RawOrigin::Signed(caller2),
+			multisig.clone(),
+			Some(timepoint),
+			call_hash,
+			Weight::zero(),
+		);
+
+		let multisig = Multisigs::<T>::get(multisig, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn cancel_as_persistent_multi(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// The call is neither in storage or an argument, so just use any:
+		let call_len = 10_000;
+		let (signatories, multisig, call) = setup_persistent_multi::<T>(s, call_len)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let caller = signatories[0].clone();
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_persistent_multi(o, multisig.clone(), None, call, Weight::zero())?;
+		assert!(Multisigs::<T>::contains_key(&multisig, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig.clone(), timepoint, call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(multisig, call_hash));
+
+		Ok(())
+	}
+
+	/// `s`: Signatories of the new multisig, need at least 2 people
+	#[benchmark]
+	fn update_persistent_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (_, multisig, _) = setup_persistent_multi::<T>(2, 0)?;
+		// The new deposit is reserved from the multisig itself.
+		T::Currency::make_free_balance_be(&multisig, BalanceOf::<T>::max_value());
+		let mut signatories: Vec<T::AccountId> =
+			(0..s).map(|i| account("new_signatory", i, SEED)).collect();
+		signatories.sort();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()), s as u16, signatories);
+
+		let multisig = PersistentMultisigs::<T>::get(multisig).ok_or("multisig not stored")?;
+		assert_eq!(multisig.threshold, s as u16);
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn dissolve_persistent_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (_, multisig, _) = setup_persistent_multi::<T>(s, 0)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()));
+
+		assert!(!PersistentMultisigs::<T>::contains_key(multisig));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -31,6 +31,10 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! A multisig can also be persistent: its account id is fixed when it is created, and its
+//! signatories and threshold are stored, so the multisig itself can change them later without
+//! moving its funds to a new account.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +43,12 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_persistent_multisig` - Create a persistent multisig account.
+//! * `as_persistent_multi` - Approve and if possible dispatch a call from a persistent multisig.
+//! * `approve_as_persistent_multi` - Approve a call from a persistent multisig.
+//! * `cancel_as_persistent_multi` - Cancel a call from a persistent multisig.
+//! * `update_persistent_multisig` - Change the signatories and threshold of a persistent multisig.
+//! * `dissolve_persistent_multisig` - Remove a persistent multisig and return its deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -110,6 +120,23 @@ where
 	approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// The signatories and threshold of a persistent multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct PersistentMultisig<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The accounts who can approve operations of the multisig. Always sorted.
+	signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The number of approvals needed to dispatch an operation.
+	threshold: u16,
+	/// The amount held in reserve of the `depositor`, to be returned once the multisig changes.
+	deposit: Balance,
+	/// The account holding the deposit: the creator, then the multisig itself once it changed.
+	depositor: AccountId,
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -179,6 +206,15 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The persistent multisig accounts, with their signatories and threshold.
+	#[pallet::storage]
+	pub type PersistentMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		PersistentMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -209,6 +245,12 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a persistent multisig.
+		NotPersistentMultisig,
+		/// The sender is not a signatory of the persistent multisig.
+		NotSignatory,
+		/// Threshold must not be greater than the number of signatories.
+		ThresholdTooHigh,
 	}
 
 	#[pallet::event]
@@ -238,6 +280,12 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// A persistent multisig account has been created.
+		PersistentMultisigCreated { creator: T::AccountId, multisig: T::AccountId, threshold: u16 },
+		/// The signatories and threshold of a persistent multisig account have been changed.
+		PersistentMultisigUpdated { multisig: T::AccountId, threshold: u16 },
+		/// A persistent multisig account has been dissolved.
+		PersistentMultisigDissolved { multisig: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -469,23 +517,230 @@ pub mod pallet {
 			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
 			let id = Self::multi_account_id(&signatories, threshold);
+			Self::cancel(who, id, timepoint, call_hash)
+		}
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
+		/// Create a persistent multisig account, whose account id does not depend on its
+		/// signatories and threshold.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for each signatory will be reserved for as
+		/// long as the multisig is not changed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for a dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve dispatches
+		/// of the multisig. May not be empty.
+		///
+		/// The account id is derived from the sender and the current timepoint, see
+		/// [`Pallet::persistent_account_id`].
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::create_persistent_multisig(other_signatories.len() as u32))]
+		pub fn create_persistent_multisig(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+			Self::ensure_valid_threshold(threshold, signatories.len())?;
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
+			let id = Self::persistent_account_id(&who, &Self::timepoint());
+			ensure!(!<PersistentMultisigs<T>>::contains_key(&id), Error::<T>::AlreadyStored);
 
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
+			let deposit = Self::persistent_deposit(signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+
+			let signatories = signatories.try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+			<PersistentMultisigs<T>>::insert(
+				&id,
+				PersistentMultisig { signatories, threshold, deposit, depositor: who.clone() },
+			);
+
+			Self::deposit_event(Event::PersistentMultisigCreated {
+				creator: who,
 				multisig: id,
-				call_hash,
+				threshold,
 			});
 			Ok(())
 		}
+
+		/// Register approval for a dispatch to be made from a persistent multisig account, and if
+		/// there are enough approvals, dispatch the call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The persistent multisig account to dispatch from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// Approvals of accounts which are no longer signatories are not counted.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_persistent_multi_create(s, z)
+			.max(T::WeightInfo::as_persistent_multi_approve(s, z))
+			.max(T::WeightInfo::as_persistent_multi_complete(s, z))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_persistent(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a persistent multisig account.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The persistent multisig account to dispatch from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_persistent_multi`
+		/// instead.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_persistent_multi_create(s)
+				.max(T::WeightInfo::approve_as_persistent_multi_approve(s))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_persistent(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going persistent multisig transaction. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account who opened the
+		/// operation, even if it is no longer a signatory.
+		///
+		/// - `multisig`: The persistent multisig account of the operation. It may have been
+		/// dissolved since the operation was opened.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_as_persistent_multi(T::MaxSignatories::get()))]
+		pub fn cancel_as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::cancel(who, multisig, timepoint, call_hash)
+		}
+
+		/// Change the signatories and threshold of a persistent multisig account.
+		///
+		/// The deposit for storing the multisig is reserved from the multisig itself, and the
+		/// previous deposit is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the persistent multisig account.
+		///
+		/// - `threshold`: The total number of approvals for a dispatch before it is executed.
+		/// - `signatories`: The accounts who can approve dispatches of the multisig. Must be
+		/// sorted, and contain at least two accounts.
+		///
+		/// Pending operations are kept, but only approvals of the new signatories are counted.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::update_persistent_multisig(signatories.len() as u32))]
+		pub fn update_persistent_multisig(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let mut multisig =
+				<PersistentMultisigs<T>>::get(&id).ok_or(Error::<T>::NotPersistentMultisig)?;
+
+			ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+			ensure!(
+				signatories.windows(2).all(|pair| pair[0] < pair[1]),
+				Error::<T>::SignatoriesOutOfOrder
+			);
+			Self::ensure_valid_threshold(threshold, signatories.len())?;
+			let deposit = Self::persistent_deposit(signatories.len());
+			multisig.signatories =
+				signatories.try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+
+			T::Currency::reserve(&id, deposit)?;
+			let err_amount = T::Currency::unreserve(&multisig.depositor, multisig.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			multisig.threshold = threshold;
+			multisig.deposit = deposit;
+			multisig.depositor = id.clone();
+			<PersistentMultisigs<T>>::insert(&id, multisig);
+
+			Self::deposit_event(Event::PersistentMultisigUpdated { multisig: id, threshold });
+			Ok(())
+		}
+
+		/// Remove a persistent multisig account and return the deposit for storing it.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the persistent multisig account.
+		///
+		/// Pending operations can no longer be approved, but whoever opened them can still cancel
+		/// them with `cancel_as_persistent_multi` to get their deposit back. The funds of the
+		/// account are left untouched.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::dissolve_persistent_multisig(T::MaxSignatories::get()))]
+		pub fn dissolve_persistent_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let multisig =
+				<PersistentMultisigs<T>>::take(&id).ok_or(Error::<T>::NotPersistentMultisig)?;
+
+			let err_amount = T::Currency::unreserve(&multisig.depositor, multisig.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			Self::deposit_event(Event::PersistentMultisigDissolved { multisig: id });
+			Ok(())
+		}
 	}
 }
 
@@ -500,6 +755,16 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Derive the account ID of a persistent multisig created by `who` at `when`.
+	pub fn persistent_account_id(
+		who: &T::AccountId,
+		when: &Timepoint<BlockNumberFor<T>>,
+	) -> T::AccountId {
+		let entropy = (b"modlpy/persistmu", who, when).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -517,6 +782,58 @@ impl<T: Config> Pallet<T> {
 
 		let id = Self::multi_account_id(&signatories, threshold);
 
+		Self::operate_as(
+			who,
+			id,
+			threshold,
+			&signatories,
+			false,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	fn operate_persistent(
+		who: T::AccountId,
+		id: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let multisig =
+			<PersistentMultisigs<T>>::get(&id).ok_or(Error::<T>::NotPersistentMultisig)?;
+		ensure!(multisig.signatories.binary_search(&who).is_ok(), Error::<T>::NotSignatory);
+
+		Self::operate_as(
+			who,
+			id,
+			multisig.threshold,
+			&multisig.signatories,
+			true,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	/// Approve and if possible dispatch a call from `id`, whose sorted `signatories` need to
+	/// reach `threshold` approvals.
+	///
+	/// `persistent` tells whether `id` is a persistent multisig, whose operations are weighed
+	/// separately.
+	fn operate_as(
+		who: T::AccountId,
+		id: T::AccountId,
+		threshold: u16,
+		signatories: &[T::AccountId],
+		persistent: bool,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let other_signatories_len = signatories.len().saturating_sub(1);
+
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
 			CallOrHash::Call(call) => {
@@ -532,6 +849,10 @@ impl<T: Config> Pallet<T> {
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
+			// Only approvals of the current signatories count. They may have changed since the
+			// operation started if this is a persistent multisig.
+			m.approvals.retain(|approval| signatories.binary_search(approval).is_ok());
+
 			// Ensure that either we have not yet signed or that it is at threshold.
 			let mut approvals = m.approvals.len() as u16;
 			// We only bother with the approval if we're below threshold.
@@ -564,11 +885,13 @@ impl<T: Config> Pallet<T> {
 				});
 				Ok(get_result_weight(result)
 					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
+						let (s, z) = (other_signatories_len as u32, call_len as u32);
+						let complete_weight = if persistent {
+							T::WeightInfo::as_persistent_multi_complete(s, z)
+						} else {
+							T::WeightInfo::as_multi_complete(s, z)
+						};
+						complete_weight.saturating_add(actual_weight)
 					})
 					.into())
 			} else {
@@ -593,8 +916,12 @@ impl<T: Config> Pallet<T> {
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+				let (s, z) = (other_signatories_len as u32, call_len as u32);
+				let final_weight = if persistent {
+					T::WeightInfo::as_persistent_multi_approve(s, z)
+				} else {
+					T::WeightInfo::as_multi_approve(s, z)
+				};
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -622,13 +949,52 @@ impl<T: Config> Pallet<T> {
 			);
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
-			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			let (s, z) = (other_signatories_len as u32, call_len as u32);
+			let final_weight = if persistent {
+				T::WeightInfo::as_persistent_multi_create(s, z)
+			} else {
+				T::WeightInfo::as_multi_create(s, z)
+			};
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	fn cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: [u8; 32],
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	/// The deposit for storing a persistent multisig with `signatories_len` signatories.
+	fn persistent_deposit(signatories_len: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (signatories_len as u32).into()
+	}
+
+	/// Check that `threshold` approvals can be reached by `signatories_len` signatories.
+	fn ensure_valid_threshold(threshold: u16, signatories_len: usize) -> DispatchResult {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		ensure!(threshold as usize <= signatories_len, Error::<T>::ThresholdTooHigh);
+		Ok(())
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -54,6 +54,7 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 			RuntimeCall::Balances(_) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+			RuntimeCall::Multisig(crate::Call::update_persistent_multisig { .. }) => true,
 			_ => false,
 		}
 	}
@@ -688,3 +689,230 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn persistent_multisig_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![2, 3]));
+		let multi = Multisig::persistent_account_id(&1, &now());
+		System::assert_last_event(
+			Event::PersistentMultisigCreated { creator: 1, multisig: multi, threshold: 2 }.into(),
+		);
+		// `DepositBase` and `DepositFactor` for each signatory.
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 7);
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(4),
+				multi,
+				Some(now()),
+				call.clone(),
+				call_weight
+			),
+			Error::<Test>::NotSignatory,
+		);
+
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 4);
+	});
+}
+
+#[test]
+fn persistent_multisig_can_be_updated() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![2, 3]));
+		let multi = Multisig::persistent_account_id(&1, &now());
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		// 1 approves a transfer before being removed from the signatories.
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 7);
+
+		let update = Box::new(RuntimeCall::Multisig(crate::Call::update_persistent_multisig {
+			threshold: 2,
+			signatories: vec![2, 4],
+		}));
+		let update_weight = update.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			update.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			update,
+			update_weight
+		));
+		System::assert_has_event(
+			Event::PersistentMultisigUpdated { multisig: multi, threshold: 2 }.into(),
+		);
+
+		// The multisig now holds its own deposit, and the creator got theirs back.
+		assert_eq!(
+			PersistentMultisigs::<Test>::get(multi).unwrap().signatories.to_vec(),
+			vec![2, 4]
+		);
+		assert_eq!(Balances::reserved_balance(multi), 3);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				Some(now()),
+				call.clone(),
+				call_weight
+			),
+			Error::<Test>::NotSignatory,
+		);
+
+		// The approval of 1 no longer counts.
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(4),
+			multi,
+			Some(now()),
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn persistent_multisig_checks_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 1, vec![2, 3]),
+			Error::<Test>::MinimumThreshold,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 4, vec![2, 3]),
+			Error::<Test>::ThresholdTooHigh,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![3, 2]),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_ok!(Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![2, 3]));
+		let multi = Multisig::persistent_account_id(&1, &now());
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![2, 3]),
+			Error::<Test>::AlreadyStored,
+		);
+
+		// Only the multisig itself can update it.
+		assert_noop!(
+			Multisig::update_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![1, 2]),
+			Error::<Test>::NotPersistentMultisig,
+		);
+		assert_noop!(
+			Multisig::update_persistent_multisig(RuntimeOrigin::signed(multi), 2, vec![2, 1]),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::update_persistent_multisig(RuntimeOrigin::signed(multi), 3, vec![1, 2]),
+			Error::<Test>::ThresholdTooHigh,
+		);
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(1),
+				Multisig::multi_account_id(&[1, 2, 3][..], 2),
+				None,
+				call_transfer(6, 5),
+				Weight::zero()
+			),
+			Error::<Test>::NotPersistentMultisig,
+		);
+	});
+}
+
+#[test]
+fn persistent_multisig_can_be_dissolved() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), 2, vec![2, 3]));
+		let multi = Multisig::persistent_account_id(&1, &now());
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		let call = call_transfer(6, 5);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		// Only the multisig itself can dissolve it.
+		assert_noop!(
+			Multisig::dissolve_persistent_multisig(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotPersistentMultisig,
+		);
+		assert_ok!(Multisig::dissolve_persistent_multisig(RuntimeOrigin::signed(multi)));
+		System::assert_last_event(Event::PersistentMultisigDissolved { multisig: multi }.into());
+		assert!(!PersistentMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Pending operations can no longer be approved, but can still be cancelled.
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(3),
+				multi,
+				Some(now()),
+				call,
+				Weight::MAX
+			),
+			Error::<Test>::NotPersistentMultisig,
+		);
+		assert_ok!(Multisig::cancel_as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
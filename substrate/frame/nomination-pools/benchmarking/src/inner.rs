@@ -1172,5 +1172,36 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn transfer_membership() {
+		// Create a pool
+		let min_create_bond = Pools::<T>::depositor_min_bond();
+		let (_depositor, pool_account) = create_pool_account::<T>(0, min_create_bond, None);
+
+		// Join pool
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let joiner = create_funded_user_with_balance::<T>("joiner", 0, min_join_bond * 4u32.into());
+		Pools::<T>::join(RuntimeOrigin::Signed(joiner.clone()).into(), min_join_bond, 1).unwrap();
+
+		// the new member needs to exist in order to receive any held funds.
+		let new_member = create_funded_user_with_balance::<T>(
+			"new_member",
+			0,
+			CurrencyOf::<T>::minimum_balance(),
+		);
+		let new_member_lookup = T::Lookup::unlookup(new_member.clone());
+		whitelist_account!(joiner);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(joiner.clone()), new_member_lookup);
+
+		assert!(!PoolMembers::<T>::contains_key(&joiner));
+		assert_eq!(PoolMembers::<T>::get(&new_member).unwrap().total_balance(), min_join_bond);
+		assert_eq!(
+			T::StakeAdapter::active_stake(Pool::from(pool_account)),
+			min_create_bond + min_join_bond
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
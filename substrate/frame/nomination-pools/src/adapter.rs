@@ -221,6 +221,16 @@ pub trait StakeStrategy {
 		value: Self::Balance,
 	) -> DispatchResult;
 
+	/// Move the stake of member `who` in `pool_account` over to `new_member`.
+	///
+	/// This is used when a pool membership changes hands. The member's stake is expected to be
+	/// moved in full, such that `new_member` ends up with the same contribution to the pool.
+	fn member_transfer(
+		who: Member<Self::AccountId>,
+		new_member: Member<Self::AccountId>,
+		pool_account: Pool<Self::AccountId>,
+	) -> DispatchResult;
+
 	/// List of validators nominated by the pool account.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn nominations(pool_account: Pool<Self::AccountId>) -> Option<Vec<Self::AccountId>> {
@@ -351,6 +361,15 @@ impl<T: Config, Staking: StakingInterface<Balance = BalanceOf<T>, AccountId = T:
 	) -> DispatchResult {
 		Err(Error::<T>::Defensive(DefensiveError::DelegationUnsupported).into())
 	}
+
+	fn member_transfer(
+		_who: Member<Self::AccountId>,
+		_new_member: Member<Self::AccountId>,
+		_pool: Pool<Self::AccountId>,
+	) -> DispatchResult {
+		// member funds are already held by the pool account, nothing to move.
+		Ok(())
+	}
 }
 
 /// A staking strategy implementation that supports delegation based staking.
@@ -462,6 +481,14 @@ impl<
 		Delegation::migrate_delegation(pool.into(), delegator.into(), value)
 	}
 
+	fn member_transfer(
+		who: Member<Self::AccountId>,
+		new_member: Member<Self::AccountId>,
+		pool: Pool<Self::AccountId>,
+	) -> DispatchResult {
+		Delegation::transfer_delegation(pool.into(), who.into(), new_member.into())
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn remove_as_agent(pool: Pool<Self::AccountId>) {
 		Delegation::force_kill_agent(pool.into())
@@ -1883,6 +1883,16 @@ pub mod pallet {
 			max_members_per_pool: Option<u32>,
 			global_max_commission: Option<Perbill>,
 		},
+		/// A member has transferred their membership of a pool to another account.
+		///
+		/// `points` are the active points of the membership. Any unbonding points are moved as
+		/// well.
+		MemberTransferred {
+			member: T::AccountId,
+			new_member: T::AccountId,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -1970,6 +1980,8 @@ pub mod pallet {
 		NotMigrated,
 		/// This call is not allowed in the current state of the pallet.
 		NotSupported,
+		/// The depositor of a pool cannot transfer their membership.
+		DepositorCannotTransfer,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -3140,6 +3152,78 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Transfer the pool membership of `origin` to `new_member`.
+		///
+		/// All points of the member, including the ones that are unbonding, are moved to
+		/// `new_member` together with any pending rewards. The claim permission of `origin` is
+		/// reset and `new_member` starts off with the default [`ClaimPermission`].
+		///
+		/// If the pool uses [`adapter::StakeStrategyType::Delegate`], the funds delegated by
+		/// `origin` are moved to `new_member` as well. Otherwise, the funds are already held by
+		/// the pool account and only the membership changes hands.
+		///
+		/// # Note
+		///
+		/// * `new_member` must not be a member of any pool.
+		/// * The depositor of a pool cannot transfer their membership.
+		/// * The transfer is subject to [`MaxPoolMembers`] and [`MaxPoolMembersPerPool`] as if
+		///   `origin` left the pool and `new_member` joined it.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::transfer_membership())]
+		pub fn transfer_membership(
+			origin: OriginFor<T>,
+			new_member: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_member = T::Lookup::lookup(new_member)?;
+
+			// If a member already exists that means they already belong to a pool
+			ensure!(
+				!PoolMembers::<T>::contains_key(&new_member),
+				Error::<T>::AccountBelongsToOtherPool
+			);
+
+			let member = PoolMembers::<T>::get(&who).ok_or(Error::<T>::PoolMemberNotFound)?;
+			let pool_id = member.pool_id;
+			// ensure pool and member are not in an un-migrated state.
+			ensure!(!Self::api_pool_needs_delegate_migration(pool_id), Error::<T>::NotMigrated);
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+
+			let mut bonded_pool =
+				BondedPool::<T>::get(pool_id).defensive_ok_or(DefensiveError::PoolNotFound)?;
+			ensure!(bonded_pool.roles.depositor != who, Error::<T>::DepositorCannotTransfer);
+
+			// `who` leaves the pool and `new_member` joins it.
+			PoolMembers::<T>::remove(&who);
+			bonded_pool = bonded_pool.dec_members();
+			bonded_pool.try_inc_members()?;
+
+			T::StakeAdapter::member_transfer(
+				Member::from(who.clone()),
+				Member::from(new_member.clone()),
+				Pool::from(bonded_pool.bonded_account()),
+			)?;
+
+			ClaimPermissions::<T>::remove(&who);
+			let points = member.points;
+			// the member record is moved as is, which carries over the pending rewards since
+			// `last_recorded_reward_counter` stays the same.
+			PoolMembers::<T>::insert(new_member.clone(), member);
+			bonded_pool.put();
+
+			Self::deposit_event(Event::<T>::MemberTransferred {
+				member: who,
+				new_member,
+				pool_id,
+				points,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -361,6 +361,21 @@ impl DelegationInterface for DelegateMock {
 
 		Ok(())
 	}
+
+	fn transfer_delegation(
+		_agent: Agent<Self::AccountId>,
+		delegator: Delegator<Self::AccountId>,
+		new_delegator: Delegator<Self::AccountId>,
+	) -> DispatchResult {
+		let mut delegators = DelegatorBalanceMap::get();
+		let amount = delegators
+			.remove(&delegator.get())
+			.ok_or(DispatchError::Other("not a delegator"))?;
+		delegators.insert(new_delegator.get(), amount);
+		DelegatorBalanceMap::set(&delegators);
+
+		Ok(())
+	}
 }
 
 impl DelegateMock {
@@ -7568,3 +7568,107 @@ mod chill {
 		})
 	}
 }
+
+mod transfer_membership {
+	use super::*;
+	use sp_staking::Delegator;
+
+	#[test]
+	fn transfer_membership_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// given
+			assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 5));
+			assert_ok!(Pools::set_claim_permission(
+				RuntimeOrigin::signed(20),
+				ClaimPermission::PermissionlessAll
+			));
+			// 20 is entitled to 25 * 15 / 25 = 15 of the rewards.
+			deposit_rewards(25);
+			let _ = pool_events_since_last_call();
+
+			let member = PoolMembers::<Runtime>::get(20).unwrap();
+			assert_eq!(member.unbonding_eras, member_unbonding_eras!(3 => 5));
+			assert_eq!(DelegateMock::delegator_balance(Delegator::from(20)), Some(20));
+
+			// when
+			assert_ok!(Pools::transfer_membership(RuntimeOrigin::signed(20), 21));
+
+			// then
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::MemberTransferred {
+					member: 20,
+					new_member: 21,
+					pool_id: 1,
+					points: 15
+				}]
+			);
+			assert!(!PoolMembers::<Runtime>::contains_key(20));
+			assert_eq!(PoolMembers::<Runtime>::get(21).unwrap(), member);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 2);
+			assert_eq!(PoolMembers::<Runtime>::count(), 2);
+
+			// the delegation moved along with the membership.
+			assert_eq!(DelegateMock::delegator_balance(Delegator::from(20)), None);
+			assert_eq!(DelegateMock::delegator_balance(Delegator::from(21)), Some(20));
+
+			// claim permission of the previous member is reset.
+			assert!(!ClaimPermissions::<Runtime>::contains_key(20));
+			assert_eq!(ClaimPermissions::<Runtime>::get(21), ClaimPermission::Permissioned);
+
+			// pending rewards are claimable by the new member.
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(21)));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PaidOut { member: 21, pool_id: 1, payout: 15 }]
+			);
+			assert_eq!(Currency::free_balance(&21), 15);
+		});
+	}
+
+	#[test]
+	fn transfer_membership_checks_work() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (21, 20)])
+			.build_and_execute(|| {
+				// not a member.
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(22), 23),
+					Error::<Runtime>::PoolMemberNotFound
+				);
+
+				// new member already belongs to a pool.
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(20), 21),
+					Error::<Runtime>::AccountBelongsToOtherPool
+				);
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(20), 20),
+					Error::<Runtime>::AccountBelongsToOtherPool
+				);
+
+				// depositor cannot transfer their membership.
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(10), 22),
+					Error::<Runtime>::DepositorCannotTransfer
+				);
+
+				// member limits are respected, as if 20 left and 22 joined.
+				MaxPoolMembersPerPool::<Runtime>::set(Some(2));
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(20), 22),
+					Error::<Runtime>::MaxPoolMembers
+				);
+				MaxPoolMembersPerPool::<Runtime>::set(Some(3));
+				MaxPoolMembers::<Runtime>::set(Some(2));
+				assert_noop!(
+					Pools::transfer_membership(RuntimeOrigin::signed(20), 22),
+					Error::<Runtime>::MaxPoolMembers
+				);
+				MaxPoolMembers::<Runtime>::set(Some(3));
+
+				assert_ok!(Pools::transfer_membership(RuntimeOrigin::signed(20), 22));
+				assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 3);
+			});
+	}
+}
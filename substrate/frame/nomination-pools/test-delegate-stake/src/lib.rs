@@ -1346,6 +1346,10 @@ fn disable_pool_operations_on_non_migrated() {
 			Pools::set_commission_claim_permission(RuntimeOrigin::signed(10), 1, None),
 			PoolsError::<Runtime>::NotMigrated
 		);
+		assert_noop!(
+			Pools::transfer_membership(RuntimeOrigin::signed(20), 22),
+			PoolsError::<Runtime>::NotMigrated
+		);
 
 		// migrate the pool.
 		assert_ok!(Pools::migrate_pool_to_delegate_stake(RuntimeOrigin::signed(10), 1));
@@ -1384,6 +1388,10 @@ fn disable_pool_operations_on_non_migrated() {
 			Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0),
 			PoolsError::<Runtime>::NotMigrated
 		);
+		assert_noop!(
+			Pools::transfer_membership(RuntimeOrigin::signed(20), 22),
+			PoolsError::<Runtime>::NotMigrated
+		);
 
 		// migrate 20
 		assert_ok!(Pools::migrate_delegation(RuntimeOrigin::signed(10), 20));
@@ -1724,3 +1732,116 @@ fn pool_no_dangling_delegation() {
 		assert_eq!(Balances::total_balance_on_hold(&charlie), 0);
 	});
 }
+
+#[test]
+fn pool_membership_transfer_e2e() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::minimum_balance(), 5);
+		assert_eq!(CurrentEra::<T>::get(), None);
+
+		// create the pool, we know this has id 1.
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 50, 10, 10, 10));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 50, 1));
+
+		// 20 starts unbonding a part of their stake.
+		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 10));
+		assert_eq!(Balances::total_balance_on_hold(&20), 50);
+
+		// the pool earns some rewards, 20 is entitled to 45 * 40 / 90 = 20 of them.
+		assert_ok!(Balances::mint_into(&POOL1_REWARD, 45));
+
+		// flush events.
+		let _ = staking_events_since_last_call();
+		let _ = pool_events_since_last_call();
+		let _ = delegated_staking_events_since_last_call();
+
+		// depositor cannot transfer their membership.
+		assert_noop!(
+			Pools::transfer_membership(RuntimeOrigin::signed(10), 22),
+			PoolsError::<Runtime>::DepositorCannotTransfer
+		);
+		// cannot transfer to an existing member.
+		assert_noop!(
+			Pools::transfer_membership(RuntimeOrigin::signed(20), 10),
+			PoolsError::<Runtime>::AccountBelongsToOtherPool
+		);
+
+		let member = PoolMembers::<Runtime>::get(20).unwrap();
+		let pre_20 = Balances::free_balance(20);
+		assert_ok!(Pools::transfer_membership(RuntimeOrigin::signed(20), 22));
+
+		// membership and delegation are moved to 22.
+		assert!(PoolMembers::<Runtime>::get(20).is_none());
+		assert_eq!(PoolMembers::<Runtime>::get(22).unwrap(), member);
+		assert_eq!(BondedPools::<Runtime>::get(1).unwrap().member_counter, 2);
+		assert_eq!(Balances::total_balance_on_hold(&20), 0);
+		assert_eq!(Balances::total_balance_on_hold(&22), 50);
+		// no rewards were paid out to 20.
+		assert_eq!(Balances::free_balance(20), pre_20);
+
+		assert_eq!(staking_events_since_last_call(), vec![]);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![PoolsEvent::MemberTransferred {
+				member: 20,
+				new_member: 22,
+				pool_id: 1,
+				points: 40
+			}]
+		);
+		assert_eq!(
+			delegated_staking_events_since_last_call(),
+			vec![DelegatedStakingEvent::DelegationTransferred {
+				agent: POOL1_BONDED,
+				delegator: 20,
+				new_delegator: 22,
+				amount: 50
+			}]
+		);
+
+		// the pending rewards of 20 can now be claimed by 22.
+		let pre_22 = Balances::free_balance(22);
+		assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(22)));
+		assert_eq!(Balances::free_balance(22), pre_22 + 20);
+
+		// and so can the unbonding funds.
+		CurrentEra::<Runtime>::set(Some(BondingDuration::get()));
+		assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(22), 22, 0));
+		assert_eq!(Balances::total_balance_on_hold(&22), 40);
+		assert_eq!(PoolMembers::<Runtime>::get(22).unwrap().points, 40);
+	})
+}
+
+#[test]
+fn pool_membership_transfer_with_transfer_stake() {
+	new_test_ext().execute_with(|| {
+		LegacyAdapter::set(true);
+
+		// hack: mint ED to pool so that the deprecated `TransferStake` works correctly with
+		// staking.
+		assert_eq!(Balances::minimum_balance(), 5);
+		assert_ok!(Balances::mint_into(&POOL1_BONDED, 5));
+
+		// create the pool with TransferStake strategy.
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 50, 10, 10, 10));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 10, 1));
+
+		let pre_20 = Balances::free_balance(20);
+		let pre_22 = Balances::free_balance(22);
+		assert_ok!(Pools::transfer_membership(RuntimeOrigin::signed(20), 22));
+
+		// the funds stay in the pool account, only the membership changes hands.
+		assert!(PoolMembers::<Runtime>::get(20).is_none());
+		assert_eq!(PoolMembers::<Runtime>::get(22).unwrap().points, 10);
+		assert_eq!(Balances::free_balance(20), pre_20);
+		assert_eq!(Balances::free_balance(22), pre_22);
+		assert_eq!(delegated_staking_events_since_last_call(), vec![]);
+
+		// 22 can unbond and withdraw the transferred stake.
+		assert_ok!(Pools::unbond(RuntimeOrigin::signed(22), 22, 10));
+		CurrentEra::<Runtime>::set(Some(BondingDuration::get()));
+		assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(22), 22, 0));
+		assert_eq!(Balances::free_balance(22), pre_22 + 10);
+		assert!(PoolMembers::<Runtime>::get(22).is_none());
+	})
+}
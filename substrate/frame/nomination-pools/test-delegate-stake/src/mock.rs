@@ -253,6 +253,17 @@ impl pallet_nomination_pools::adapter::StakeStrategy for MockAdapter {
 		}
 		DelegateStake::migrate_delegation(agent, delegator, value)
 	}
+
+	fn member_transfer(
+		who: Member<Self::AccountId>,
+		new_member: Member<Self::AccountId>,
+		pool_account: Pool<Self::AccountId>,
+	) -> DispatchResult {
+		if LegacyAdapter::get() {
+			return TransferStake::member_transfer(who, new_member, pool_account)
+		}
+		DelegateStake::member_transfer(who, new_member, pool_account)
+	}
 }
 impl pallet_nomination_pools::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS logs (
+  block_hash BLOB NOT NULL,
+  block_number INTEGER NOT NULL,
+  transaction_index INTEGER NOT NULL,
+  transaction_hash BLOB NOT NULL,
+  log_index INTEGER NOT NULL,
+  address BLOB NOT NULL,
+  topic_0 BLOB,
+  topic_1 BLOB,
+  topic_2 BLOB,
+  topic_3 BLOB,
+  data BLOB,
+  PRIMARY KEY (block_hash, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_logs_block_number_address ON logs (block_number, address);
+CREATE INDEX IF NOT EXISTS idx_logs_address ON logs (address);
+CREATE INDEX IF NOT EXISTS idx_logs_topic_0 ON logs (topic_0);
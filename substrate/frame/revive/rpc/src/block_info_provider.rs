@@ -18,7 +18,7 @@
 use crate::{
 	client::{SubstrateBlock, SubstrateBlockNumber},
 	subxt_client::SrcChainConfig,
-	ClientError,
+	ClientError, LOG_TARGET,
 };
 use jsonrpsee::core::async_trait;
 use sp_core::H256;
@@ -29,11 +29,20 @@ use std::{
 use subxt::{backend::legacy::LegacyRpcMethods, OnlineClient};
 use tokio::sync::RwLock;
 
+/// The blocks removed from the cache when a new best block is cached.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CachedBlockRemovals {
+	/// The oldest block, pruned to keep the cache within its size.
+	pub pruned: Option<H256>,
+	/// The blocks that are no longer part of the best chain, from the highest to the lowest.
+	pub retracted: Vec<H256>,
+}
+
 /// BlockInfoProvider cache and retrieves information about blocks.
 #[async_trait]
 pub trait BlockInfoProvider: Send + Sync {
-	/// Cache a new block and return the pruned block hash.
-	async fn cache_block(&self, block: SubstrateBlock) -> Option<H256>;
+	/// Cache a new best block and return the blocks it removed from the cache.
+	async fn cache_block(&self, block: SubstrateBlock) -> CachedBlockRemovals;
 
 	/// Return the latest ingested block.
 	async fn latest_block(&self) -> Option<Arc<SubstrateBlock>>;
@@ -77,9 +86,33 @@ impl BlockInfoProviderImpl {
 
 #[async_trait]
 impl BlockInfoProvider for BlockInfoProviderImpl {
-	async fn cache_block(&self, block: SubstrateBlock) -> Option<H256> {
+	async fn cache_block(&self, block: SubstrateBlock) -> CachedBlockRemovals {
 		let mut cache = self.cache.write().await;
-		cache.insert(block)
+
+		// The cached blocks at or above the height of the new best block belong to another fork.
+		let mut retracted = cache.retract_from(block.number());
+
+		// Walk back the ancestry of the new best block until it joins the cached chain.
+		let mut number = block.number();
+		let mut parent_hash = block.header().parent_hash;
+		while let Some(cached_hash) = number.checked_sub(1).and_then(|n| cache.hash_by_number(n)) {
+			number -= 1;
+			if cached_hash == parent_hash {
+				break
+			}
+
+			retracted.extend(cache.retract_from(number));
+			parent_hash = match self.api.blocks().at(parent_hash).await {
+				Ok(parent) => parent.header().parent_hash,
+				Err(err) => {
+					log::warn!(target: LOG_TARGET, "Failed to fetch block {parent_hash:?}: {err:?}");
+					break
+				},
+			};
+		}
+
+		let pruned = cache.insert(block);
+		CachedBlockRemovals { pruned, retracted }
 	}
 
 	async fn latest_block(&self) -> Option<Arc<SubstrateBlock>> {
@@ -181,6 +214,25 @@ impl<B: BlockInfo> BlockCache<B> {
 		self.blocks_by_hash.insert(block.hash(), block);
 		pruned_block_hash
 	}
+
+	/// Returns the hash of the cached block with the given number.
+	pub fn hash_by_number(&self, block_number: SubstrateBlockNumber) -> Option<H256> {
+		self.blocks_by_number.get(&block_number).map(|block| block.hash())
+	}
+
+	/// Remove the cached blocks at or above the given height, and return their hashes from the
+	/// highest to the lowest.
+	pub fn retract_from(&mut self, block_number: SubstrateBlockNumber) -> Vec<H256> {
+		let mut retracted = Vec::new();
+		while self.buffer.back().is_some_and(|block| block.number() >= block_number) {
+			let Some(block) = self.buffer.pop_back() else { break };
+			let hash = block.hash();
+			self.blocks_by_hash.remove(&hash);
+			self.blocks_by_number.remove(&block.number());
+			retracted.push(hash);
+		}
+		retracted
+	}
 }
 
 #[cfg(test)]
@@ -220,13 +272,30 @@ pub mod test {
 		assert_eq!(cache.blocks_by_hash.len(), 2);
 	}
 
+	#[test]
+	fn cache_retract_works() {
+		let mut cache = BlockCache::<MockBlock>::new(10);
+		for i in 1u8..=4 {
+			cache.insert(MockBlock { block_number: i.into(), block_hash: H256::from([i; 32]) });
+		}
+
+		assert_eq!(cache.retract_from(5), vec![]);
+		assert_eq!(cache.retract_from(3), vec![H256::from([4; 32]), H256::from([3; 32])]);
+		assert_eq!(cache.hash_by_number(2), Some(H256::from([2; 32])));
+		assert_eq!(cache.hash_by_number(3), None);
+
+		assert_eq!(cache.buffer.len(), 2);
+		assert_eq!(cache.blocks_by_number.len(), 2);
+		assert_eq!(cache.blocks_by_hash.len(), 2);
+	}
+
 	/// A Noop BlockInfoProvider used to test [`db::DBReceiptProvider`].
 	pub struct MockBlockInfoProvider;
 
 	#[async_trait]
 	impl BlockInfoProvider for MockBlockInfoProvider {
-		async fn cache_block(&self, _block: SubstrateBlock) -> Option<H256> {
-			None
+		async fn cache_block(&self, _block: SubstrateBlock) -> CachedBlockRemovals {
+			Default::default()
 		}
 
 		async fn latest_block(&self) -> Option<Arc<SubstrateBlock>> {
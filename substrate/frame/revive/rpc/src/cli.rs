@@ -16,10 +16,11 @@
 // limitations under the License.
 //! The Ethereum JSON-RPC server.
 use crate::{
-	client::{connect, Client},
+	client::{connect, Client, LogsLimits, SubstrateBlockNumber},
 	BlockInfoProvider, BlockInfoProviderImpl, CacheReceiptProvider, DBReceiptProvider,
-	EthRpcServer, EthRpcServerImpl, ReceiptProvider, SystemHealthRpcServer,
-	SystemHealthRpcServerImpl, LOG_TARGET,
+	DebugRpcServer, DebugRpcServerImpl, EthPubSubRpcServer, EthPubSubServerImpl, EthRpcServer,
+	EthRpcServerImpl, ReceiptProvider, SystemHealthRpcServer, SystemHealthRpcServerImpl,
+	LOG_TARGET,
 };
 use clap::Parser;
 use futures::{pin_mut, FutureExt};
@@ -60,6 +61,14 @@ pub struct CliCommand {
 	#[clap(long, default_value = "true")]
 	pub database_read_only: bool,
 
+	/// The maximum number of blocks that can be queried by `eth_getLogs`.
+	#[clap(long, default_value = "1024")]
+	pub max_logs_block_range: SubstrateBlockNumber,
+
+	/// The maximum number of logs that can be returned by `eth_getLogs`.
+	#[clap(long, default_value = "10000")]
+	pub max_logs_results: usize,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -103,13 +112,20 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		cache_size,
 		database_url,
 		database_read_only,
+		max_logs_block_range,
+		max_logs_results,
 		shared_params,
 		..
 	} = cmd;
 
+	let logs_limits =
+		LogsLimits { max_block_range: max_logs_block_range, max_results: max_logs_results };
+
 	#[cfg(not(test))]
 	init_logger(&shared_params)?;
 	let is_dev = shared_params.dev;
+	// The debug methods re-execute transactions with tracing, only expose them on request.
+	let with_debug_api = matches!(rpc_params.rpc_methods, sc_cli::RpcMethods::Unsafe);
 	let rpc_addrs: Option<Vec<sc_service::config::RpcEndpoint>> = rpc_params
 		.rpc_addr(is_dev, false, 8545)?
 		.map(|addrs| addrs.into_iter().map(Into::into).collect());
@@ -172,7 +188,7 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		pin_mut!(fut);
 
 		match tokio_handle.block_on(signals.try_until_signal(fut)) {
-			Ok(Ok(client)) => rpc_module(is_dev, client),
+			Ok(Ok(client)) => rpc_module(is_dev, with_debug_api, client, logs_limits),
 			Ok(Err(err)) => {
 				log::error!("Error initializing: {err:?}");
 				Err(sc_service::Error::Application(err.into()))
@@ -200,15 +216,29 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 }
 
 /// Create the JSON-RPC module.
-fn rpc_module(is_dev: bool, client: Client) -> Result<RpcModule<()>, sc_service::Error> {
+///
+/// The `debug_*` methods are only included when `with_debug_api` is set.
+fn rpc_module(
+	is_dev: bool,
+	with_debug_api: bool,
+	client: Client,
+	logs_limits: LogsLimits,
+) -> Result<RpcModule<()>, sc_service::Error> {
 	let eth_api = EthRpcServerImpl::new(client.clone())
 		.with_accounts(if is_dev { vec![crate::Account::default()] } else { vec![] })
+		.with_logs_limits(logs_limits)
 		.into_rpc();
 
+	let pubsub_api = EthPubSubServerImpl::new(client.clone()).into_rpc();
+	let debug_api = with_debug_api.then(|| DebugRpcServerImpl::new(client.clone()).into_rpc());
 	let health_api = SystemHealthRpcServerImpl::new(client).into_rpc();
 
 	let mut module = RpcModule::new(());
 	module.merge(eth_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	module.merge(pubsub_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	if let Some(debug_api) = debug_api {
+		module.merge(debug_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	}
 	module.merge(health_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	Ok(module)
 }
@@ -18,20 +18,27 @@
 //! and is used by the rpc server to query and send transactions to the substrate chain.
 use crate::{
 	extract_receipts_from_block,
+	fee_history::{self, BlockFees, FeeHistoryCache, PercentilesError, MAX_FEE_HISTORY_BLOCKS},
 	subxt_client::{
 		revive::calls::types::EthTransact, runtime_types::pallet_revive::storage::ContractInfo,
 	},
-	BlockInfoProvider, ReceiptProvider, TransactionInfo, LOG_TARGET,
+	BlockInfoProvider, LogQuery, ReceiptProvider, TransactionInfo, LOG_TARGET,
+};
+use codec::{Compact, Encode};
+use jsonrpsee::types::{
+	error::{CALL_EXECUTION_FAILED_CODE, INVALID_PARAMS_CODE},
+	ErrorObjectOwned,
 };
-use jsonrpsee::types::{error::CALL_EXECUTION_FAILED_CODE, ErrorObjectOwned};
 use pallet_revive::{
 	evm::{
-		extract_revert_message, Block, BlockNumberOrTag, BlockNumberOrTagOrHash,
-		GenericTransaction, ReceiptInfo, SyncingProgress, SyncingStatus, TransactionSigned, H160,
+		extract_revert_message, runtime::GAS_PRICE, Block, BlockNumberOrTag,
+		BlockNumberOrTagOrHash, BlockTag, FeeHistoryResult, Filter, GenericTransaction, Log,
+		ReceiptInfo, SyncingProgress, SyncingStatus, Trace, TracerConfig, TransactionSigned, H160,
 		H256, U256,
 	},
 	EthTransactError, EthTransactInfo,
 };
+use sp_arithmetic::Permill;
 use sp_weights::Weight;
 use std::{ops::ControlFlow, sync::Arc, time::Duration};
 use subxt::{
@@ -48,7 +55,10 @@ use subxt::{
 	Config, OnlineClient,
 };
 use thiserror::Error;
-use tokio::{sync::RwLock, try_join};
+use tokio::{
+	sync::{broadcast, RwLock},
+	try_join,
+};
 
 use crate::subxt_client::{self, SrcChainConfig};
 
@@ -67,6 +77,35 @@ pub type Shared<T> = Arc<RwLock<T>>;
 /// The runtime balance type.
 pub type Balance = u128;
 
+/// Limits applied to log queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogsLimits {
+	/// The maximum number of blocks a single query can span.
+	pub max_block_range: SubstrateBlockNumber,
+	/// The maximum number of logs a single query can return.
+	pub max_results: usize,
+}
+
+impl Default for LogsLimits {
+	fn default() -> Self {
+		Self { max_block_range: 1024, max_results: 10_000 }
+	}
+}
+
+/// The capacity of the channels used to notify subscribers.
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 64;
+
+/// A notification sent to subscribers when a new best block is imported.
+#[derive(Debug, Clone)]
+pub struct BlockNotification {
+	/// The Ethereum block, with transaction hashes only.
+	pub block: Block,
+	/// The logs emitted by the transactions of the block.
+	pub logs: Vec<Log>,
+	/// The logs of the blocks retracted from the best chain by this block, marked as removed.
+	pub removed_logs: Vec<Log>,
+}
+
 /// The subscription type used to listen to new blocks.
 pub enum SubscriptionType {
 	/// Subscribe to the best blocks.
@@ -133,6 +172,21 @@ pub enum ClientError {
 	/// The cache is empty.
 	#[error("cache is empty")]
 	CacheEmpty,
+	/// The log filter is invalid.
+	#[error("invalid filter: {0}")]
+	InvalidFilter(&'static str),
+	/// The log filter block range is larger than allowed.
+	#[error("query exceeds max block range {0}")]
+	LogsBlockRangeExceeded(SubstrateBlockNumber),
+	/// The log filter matches more logs than allowed.
+	#[error("query returned more than {0} results")]
+	LogsLimitExceeded(usize),
+	/// The reward percentiles of a fee history request are invalid.
+	#[error(transparent)]
+	InvalidRewardPercentiles(#[from] PercentilesError),
+	/// The transaction could not be traced.
+	#[error("no trace found")]
+	TraceNotFound,
 }
 
 const REVERT_CODE: i32 = 3;
@@ -156,6 +210,11 @@ impl From<ClientError> for ErrorObjectOwned {
 			},
 			ClientError::Reverted(EthTransactError::Message(msg)) =>
 				ErrorObjectOwned::owned::<String>(CALL_EXECUTION_FAILED_CODE, msg, None),
+			ClientError::InvalidFilter(_) |
+			ClientError::LogsBlockRangeExceeded(_) |
+			ClientError::LogsLimitExceeded(_) |
+			ClientError::InvalidRewardPercentiles(_) =>
+				ErrorObjectOwned::owned::<String>(INVALID_PARAMS_CODE, err.to_string(), None),
 			_ =>
 				ErrorObjectOwned::owned::<String>(CALL_EXECUTION_FAILED_CODE, err.to_string(), None),
 		}
@@ -170,8 +229,11 @@ pub struct Client {
 	rpc: LegacyRpcMethods<SrcChainConfig>,
 	receipt_provider: Arc<dyn ReceiptProvider>,
 	block_provider: Arc<dyn BlockInfoProvider>,
+	fee_history: Shared<FeeHistoryCache>,
 	chain_id: u64,
 	max_block_weight: Weight,
+	block_notifier: broadcast::Sender<Arc<BlockNotification>>,
+	pending_transaction_notifier: broadcast::Sender<H256>,
 }
 
 /// Fetch the chain ID from the substrate chain.
@@ -236,11 +298,74 @@ impl Client {
 			rpc,
 			receipt_provider,
 			block_provider,
+			fee_history: Default::default(),
 			chain_id,
 			max_block_weight,
+			block_notifier: broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY).0,
+			pending_transaction_notifier: broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY).0,
 		})
 	}
 
+	/// Subscribe to the new best blocks imported by [`Self::subscribe_and_cache_blocks`].
+	pub fn block_notifications(&self) -> broadcast::Receiver<Arc<BlockNotification>> {
+		self.block_notifier.subscribe()
+	}
+
+	/// Subscribe to the hashes of the transactions submitted through this client.
+	pub fn pending_transaction_notifications(&self) -> broadcast::Receiver<H256> {
+		self.pending_transaction_notifier.subscribe()
+	}
+
+	/// Notify subscribers that a new transaction was submitted.
+	pub fn notify_pending_transaction(&self, transaction_hash: H256) {
+		// An error only means that there are no subscribers.
+		let _ = self.pending_transaction_notifier.send(transaction_hash);
+	}
+
+	/// Get the logs of the given retracted blocks, marked as removed, if anyone is subscribed to
+	/// new blocks.
+	async fn removed_logs(&self, retracted: &[H256]) -> Vec<Log> {
+		if self.block_notifier.receiver_count() == 0 {
+			return vec![];
+		}
+
+		let mut removed_logs = Vec::new();
+		for block_hash in retracted {
+			let query =
+				LogQuery { block_hash: Some(*block_hash), limit: usize::MAX, ..Default::default() };
+			match self.receipt_provider.logs(&query).await {
+				Ok(logs) => removed_logs
+					.extend(logs.into_iter().map(|log| Log { removed: Some(true), ..log })),
+				Err(err) => {
+					log::debug!(target: LOG_TARGET, "Failed to get the logs of retracted block {block_hash:?}: {err:?}");
+				},
+			}
+		}
+		removed_logs
+	}
+
+	/// Notify subscribers that a new best block was imported.
+	async fn notify_new_block(
+		&self,
+		block_hash: &H256,
+		receipts: Vec<(TransactionSigned, ReceiptInfo)>,
+		removed_logs: Vec<Log>,
+	) -> Result<(), ClientError> {
+		if self.block_notifier.receiver_count() == 0 {
+			return Ok(());
+		}
+
+		let block = self.block_by_hash(block_hash).await?.ok_or(ClientError::BlockNotFound)?;
+		let logs = receipts.iter().flat_map(|(_, receipt)| receipt.logs.clone()).collect();
+		let block = self.evm_block_from_receipts(block, receipts, false).await;
+
+		// An error only means that all subscribers are gone.
+		let _ = self
+			.block_notifier
+			.send(Arc::new(BlockNotification { block, logs, removed_logs }));
+		Ok(())
+	}
+
 	/// Subscribe to past blocks executing the callback for each block.
 	/// The subscription continues iterating past blocks until the closure returns
 	/// `ControlFlow::Break`. Blocks are iterated starting from the latest block and moving
@@ -337,13 +462,23 @@ impl Client {
 			let res = client
 				.subscribe_new_blocks(SubscriptionType::BestBlocks, |block| async {
 					let receipts = extract_receipts_from_block(&block).await?;
-
-					client.receipt_provider.insert(&block.hash(), &receipts).await;
-					if let Some(pruned) = client.block_provider.cache_block(block).await {
+					let block_hash = block.hash();
+
+					client.cache_block_fees(&block, &receipts).await;
+					let removals = client.block_provider.cache_block(block).await;
+					// Drop the receipts of the blocks that are no longer on the best chain before
+					// adding the new ones, a transaction can be included again in the new block.
+					let removed_logs = client.removed_logs(&removals.retracted).await;
+					for retracted in &removals.retracted {
+						log::debug!(target: LOG_TARGET, "Retracting block {retracted:?}");
+						client.receipt_provider.retract(retracted).await;
+					}
+					if let Some(pruned) = removals.pruned {
 						client.receipt_provider.remove(&pruned).await;
 					}
+					client.receipt_provider.insert(&block_hash, &receipts).await;
 
-					Ok(())
+					client.notify_new_block(&block_hash, receipts, removed_logs).await
 				})
 				.await;
 
@@ -578,6 +713,54 @@ impl Client {
 		}
 	}
 
+	/// Trace the transaction with the given hash, by re-executing its block up to the
+	/// transaction on top of the parent block state.
+	pub async fn trace_transaction(
+		&self,
+		tx_hash: H256,
+		config: TracerConfig,
+	) -> Result<Trace, ClientError> {
+		let receipt = self.receipt(&tx_hash).await.ok_or(ClientError::EthExtrinsicNotFound)?;
+		let block = self
+			.block_provider
+			.block_by_hash(&receipt.block_hash)
+			.await?
+			.ok_or(ClientError::BlockNotFound)?;
+		let tx_index: u32 = receipt
+			.transaction_index
+			.try_into()
+			.map_err(|_| ClientError::ConversionFailed)?;
+
+		// SCALE encode the block and the call parameters, the block type is not part of the
+		// metadata.
+		let extrinsics = block.extrinsics().await?;
+		let mut params = block.header().encode();
+		Compact(extrinsics.len() as u32).encode_to(&mut params);
+		for ext in extrinsics.iter() {
+			params.extend_from_slice(ext.bytes());
+		}
+		(tx_index, config).encode_to(&mut params);
+
+		let runtime_api = self.api.runtime_api().at(block.header().parent_hash);
+		let trace: Option<Trace> =
+			runtime_api.call_raw("ReviveApi_trace_tx", Some(&params)).await?;
+		trace.ok_or(ClientError::TraceNotFound)
+	}
+
+	/// Trace a call executed on top of the given block.
+	pub async fn trace_call(
+		&self,
+		tx: GenericTransaction,
+		block: BlockNumberOrTagOrHash,
+		config: TracerConfig,
+	) -> Result<Trace, ClientError> {
+		let runtime_api = self.runtime_api(&block).await?;
+		let params = (tx, config).encode();
+		let result: Result<Trace, EthTransactError> =
+			runtime_api.call_raw("ReviveApi_trace_call", Some(&params)).await?;
+		result.map_err(ClientError::Reverted)
+	}
+
 	/// Get the nonce of the given address.
 	pub async fn nonce(
 		&self,
@@ -641,11 +824,76 @@ impl Client {
 		self.block_provider.block_by_number(block_number).await
 	}
 
+	/// Resolve a block number or tag of a log filter into a block number.
+	async fn resolve_filter_block(
+		&self,
+		block: Option<&BlockNumberOrTag>,
+	) -> Result<SubstrateBlockNumber, ClientError> {
+		match block {
+			Some(BlockNumberOrTag::U256(n)) =>
+				(*n).try_into().map_err(|_| ClientError::ConversionFailed),
+			Some(BlockNumberOrTag::BlockTag(BlockTag::Earliest)) => Ok(0),
+			Some(BlockNumberOrTag::BlockTag(_)) | None => self.block_number().await,
+		}
+	}
+
+	/// Resolve a [`Filter`] into a [`LogQuery`], enforcing the given limits.
+	pub async fn log_query(
+		&self,
+		filter: &Filter,
+		limits: &LogsLimits,
+	) -> Result<LogQuery, ClientError> {
+		let mut query = LogQuery::new(filter, limits.max_results);
+
+		if filter.block_hash.is_some() {
+			if filter.from_block.is_some() || filter.to_block.is_some() {
+				return Err(ClientError::InvalidFilter(
+					"blockHash is mutually exclusive with fromBlock/toBlock",
+				));
+			}
+			return Ok(query);
+		}
+
+		query.from_block = self.resolve_filter_block(filter.from_block.as_ref()).await?;
+		query.to_block = self.resolve_filter_block(filter.to_block.as_ref()).await?;
+
+		if query.from_block > query.to_block {
+			return Err(ClientError::InvalidFilter("fromBlock is greater than toBlock"));
+		}
+
+		if query.to_block - query.from_block >= limits.max_block_range {
+			return Err(ClientError::LogsBlockRangeExceeded(limits.max_block_range));
+		}
+
+		Ok(query)
+	}
+
+	/// Get the logs matching the given filter.
+	pub async fn logs(
+		&self,
+		filter: &Filter,
+		limits: &LogsLimits,
+	) -> Result<Vec<Log>, ClientError> {
+		let query = self.log_query(filter, limits).await?;
+		self.receipt_provider.logs(&query).await
+	}
+
 	/// Get the EVM block for the given hash.
 	pub async fn evm_block(
 		&self,
 		block: Arc<SubstrateBlock>,
 		hydrated_transactions: bool,
+	) -> Block {
+		let receipts = extract_receipts_from_block(&block).await.unwrap_or_default();
+		self.evm_block_from_receipts(block, receipts, hydrated_transactions).await
+	}
+
+	/// Get the EVM block for the given block and its extracted receipts.
+	async fn evm_block_from_receipts(
+		&self,
+		block: Arc<SubstrateBlock>,
+		receipts: Vec<(TransactionSigned, ReceiptInfo)>,
+		hydrated_transactions: bool,
 	) -> Block {
 		let runtime_api = self.api.runtime_api().at(block.hash());
 		let gas_limit = Self::block_gas_limit(&runtime_api).await.unwrap_or_default();
@@ -658,7 +906,6 @@ impl Client {
 		let state_root = header.state_root.0.into();
 		let extrinsics_root = header.extrinsics_root.0.into();
 
-		let receipts = extract_receipts_from_block(&block).await.unwrap_or_default();
 		let gas_used =
 			receipts.iter().fold(U256::zero(), |acc, (_, receipt)| acc + receipt.gas_used);
 		let transactions = if hydrated_transactions {
@@ -683,7 +930,7 @@ impl Client {
 			number: header.number.into(),
 			timestamp: timestamp.into(),
 			difficulty: Some(0u32.into()),
-			base_fee_per_gas: Some(crate::GAS_PRICE.into()),
+			base_fee_per_gas: Some(GAS_PRICE.into()),
 			gas_limit,
 			gas_used,
 			receipts_root: extrinsics_root,
@@ -692,6 +939,88 @@ impl Client {
 		}
 	}
 
+	/// Compute the fee data of a newly imported block, and add it to the fee history cache.
+	async fn cache_block_fees(
+		&self,
+		block: &SubstrateBlock,
+		receipts: &[(TransactionSigned, ReceiptInfo)],
+	) {
+		// The runtime charges a constant [`GAS_PRICE`] per unit of gas: the fee multiplier is
+		// already folded into the gas estimate, and anything above it is paid as a tip.
+		let base_fee = U256::from(GAS_PRICE);
+		let gas_limit = match Self::block_gas_limit(&self.api.runtime_api().at(block.hash())).await
+		{
+			Ok(gas_limit) => gas_limit,
+			Err(err) => {
+				log::debug!(target: LOG_TARGET, "Failed to compute the fees of block {}: {err:?}", block.number());
+				return;
+			},
+		};
+
+		let receipts = receipts.iter().map(|(_, receipt)| receipt);
+		let fees = BlockFees::new(base_fee, base_fee, gas_limit, receipts);
+		self.fee_history.write().await.insert(block.number(), block.hash(), fees);
+	}
+
+	/// Get the fee history of the `block_count` blocks up to `newest_block`.
+	///
+	/// The fee data is computed when blocks are imported, so only the last
+	/// [`MAX_FEE_HISTORY_BLOCKS`] imported blocks can be reported.
+	pub async fn fee_history(
+		&self,
+		block_count: u64,
+		newest_block: &BlockNumberOrTag,
+		reward_percentiles: Option<Vec<f64>>,
+	) -> Result<FeeHistoryResult, ClientError> {
+		if let Some(percentiles) = &reward_percentiles {
+			fee_history::validate_percentiles(percentiles)?;
+		}
+
+		let newest_number = self
+			.block_by_number_or_tag(newest_block)
+			.await?
+			.ok_or(ClientError::BlockNotFound)?
+			.number();
+		let percentiles = reward_percentiles.unwrap_or_default();
+
+		let cache = self.fee_history.read().await;
+		let fees = cache.range(newest_number, block_count.min(MAX_FEE_HISTORY_BLOCKS));
+		let Some(newest_fees) = fees.last() else {
+			return Ok(FeeHistoryResult {
+				oldest_block: newest_number.into(),
+				..Default::default()
+			});
+		};
+
+		Ok(FeeHistoryResult {
+			oldest_block: (newest_number + 1 - fees.len() as SubstrateBlockNumber).into(),
+			base_fee_per_gas: fees
+				.iter()
+				.map(|fees| fees.base_fee_per_gas)
+				.chain([newest_fees.next_base_fee_per_gas])
+				.collect(),
+			gas_used_ratio: fees.iter().map(|fees| fees.gas_used_ratio).collect(),
+			reward: (!percentiles.is_empty())
+				.then(|| fees.iter().map(|fees| fees.rewards(&percentiles)).collect()),
+		})
+	}
+
+	/// Suggest a priority fee per gas, from the rewards of the transactions included in the
+	/// recent blocks of the fee history cache.
+	pub async fn max_priority_fee_per_gas(&self) -> U256 {
+		// Fallback to a fraction of the gas price when there is no recent transaction.
+		self.fee_history
+			.read()
+			.await
+			.suggest_priority_fee()
+			.unwrap_or_else(|| Permill::from_percent(20).mul_ceil(GAS_PRICE).into())
+	}
+
+	/// Suggest a gas price, as the base fee charged by the runtime and the suggested priority fee.
+	pub async fn gas_price(&self) -> U256 {
+		U256::from(GAS_PRICE).saturating_add(self.max_priority_fee_per_gas().await)
+	}
+
 	/// Convert a weight to a fee.
 	async fn block_gas_limit(
 		runtime_api: &subxt::runtime_api::RuntimeApi<SrcChainConfig, OnlineClient<SrcChainConfig>>,
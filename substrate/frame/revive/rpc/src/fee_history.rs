@@ -0,0 +1,319 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Fee history and priority fee estimation, computed from the receipts of recent blocks.
+use crate::client::SubstrateBlockNumber;
+use pallet_revive::evm::{ReceiptInfo, H256, U256};
+use std::collections::{BTreeMap, HashMap};
+use thiserror::Error;
+
+/// The maximum number of blocks that can be requested with `eth_feeHistory`, which is also the
+/// number of recent blocks kept in the [`FeeHistoryCache`].
+pub const MAX_FEE_HISTORY_BLOCKS: u64 = 64;
+
+/// The number of recent blocks used to suggest a priority fee.
+pub const PRIORITY_FEE_BLOCKS: u64 = 20;
+
+/// The percentile of the block rewards used to suggest a priority fee.
+pub const PRIORITY_FEE_PERCENTILE: f64 = 60.0;
+
+/// Errors returned when validating the reward percentiles of a fee history request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PercentilesError {
+	/// A percentile is not within `[0, 100]`.
+	#[error("reward percentile out of range")]
+	OutOfRange,
+	/// The percentiles are not in ascending order.
+	#[error("reward percentiles are not in ascending order")]
+	NotAscending,
+}
+
+/// Check that the given percentiles are within `[0, 100]` and in ascending order.
+pub fn validate_percentiles(percentiles: &[f64]) -> Result<(), PercentilesError> {
+	if percentiles.iter().any(|p| !(0.0..=100.0).contains(p)) {
+		return Err(PercentilesError::OutOfRange);
+	}
+	if percentiles.windows(2).any(|w| w[0] > w[1]) {
+		return Err(PercentilesError::NotAscending);
+	}
+	Ok(())
+}
+
+/// Convert the given value to `u128`, saturating on overflow.
+fn saturated_u128(value: U256) -> u128 {
+	value.try_into().unwrap_or(u128::MAX)
+}
+
+/// The ratio of gas used in a block, relative to its gas limit.
+pub fn gas_used_ratio(gas_used: U256, gas_limit: U256) -> f64 {
+	if gas_limit.is_zero() {
+		return 0.0;
+	}
+	saturated_u128(gas_used) as f64 / saturated_u128(gas_limit) as f64
+}
+
+/// The effective priority fees per gas of the given receipts along with the gas they used, sorted
+/// by priority fee.
+fn sorted_rewards<'a>(
+	base_fee: U256,
+	receipts: impl IntoIterator<Item = &'a ReceiptInfo>,
+) -> Vec<(U256, U256)> {
+	let mut rewards = receipts
+		.into_iter()
+		.map(|receipt| (receipt.effective_gas_price.saturating_sub(base_fee), receipt.gas_used))
+		.collect::<Vec<_>>();
+	rewards.sort_by_key(|(reward, _)| *reward);
+	rewards
+}
+
+/// The priority fees at the given percentiles of rewards sorted by [`sorted_rewards`].
+///
+/// As with geth, each percentile is weighted by the gas used by the transactions. Zeroes are
+/// returned for blocks without transactions.
+fn rewards_at_percentiles(rewards: &[(U256, U256)], percentiles: &[f64]) -> Vec<U256> {
+	if rewards.is_empty() {
+		return vec![U256::zero(); percentiles.len()];
+	}
+
+	let total_gas_used =
+		saturated_u128(rewards.iter().fold(U256::zero(), |acc, (_, gas)| acc.saturating_add(*gas)));
+
+	let mut index = 0;
+	let mut cumulative_gas_used = saturated_u128(rewards[0].1);
+	percentiles
+		.iter()
+		.map(|percentile| {
+			let threshold = total_gas_used as f64 * percentile / 100.0;
+			while (cumulative_gas_used as f64) < threshold && index < rewards.len() - 1 {
+				index += 1;
+				cumulative_gas_used =
+					cumulative_gas_used.saturating_add(saturated_u128(rewards[index].1));
+			}
+			rewards[index].0
+		})
+		.collect()
+}
+
+/// Suggest a priority fee per gas from the rewards of recent blocks, at
+/// [`PRIORITY_FEE_PERCENTILE`].
+///
+/// Returns `None` if none of the blocks contained transactions.
+fn suggest_priority_fee(mut rewards: Vec<U256>) -> Option<U256> {
+	if rewards.is_empty() {
+		return None;
+	}
+	rewards.sort();
+	let index = (rewards.len() - 1) * PRIORITY_FEE_PERCENTILE as usize / 100;
+	Some(rewards[index])
+}
+
+/// The fee data of a block, computed once when the block is imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockFees {
+	/// The base fee per gas of the block.
+	pub base_fee_per_gas: U256,
+	/// The base fee per gas of the next block.
+	pub next_base_fee_per_gas: U256,
+	/// The ratio of gas used in the block, relative to its gas limit.
+	pub gas_used_ratio: f64,
+	/// The effective priority fees per gas of the transactions of the block along with the gas
+	/// they used, sorted by priority fee.
+	rewards: Vec<(U256, U256)>,
+}
+
+impl BlockFees {
+	/// Compute the fee data of a block from its receipts.
+	pub fn new<'a>(
+		base_fee_per_gas: U256,
+		next_base_fee_per_gas: U256,
+		gas_limit: U256,
+		receipts: impl IntoIterator<Item = &'a ReceiptInfo>,
+	) -> Self {
+		let rewards = sorted_rewards(base_fee_per_gas, receipts);
+		let gas_used = rewards
+			.iter()
+			.fold(U256::zero(), |acc, (_, gas_used)| acc.saturating_add(*gas_used));
+		Self {
+			base_fee_per_gas,
+			next_base_fee_per_gas,
+			gas_used_ratio: gas_used_ratio(gas_used, gas_limit),
+			rewards,
+		}
+	}
+
+	/// The effective priority fees per gas of the block at the given percentiles.
+	pub fn rewards(&self, percentiles: &[f64]) -> Vec<U256> {
+		rewards_at_percentiles(&self.rewards, percentiles)
+	}
+}
+
+/// A cache of the fee data of the last [`MAX_FEE_HISTORY_BLOCKS`] imported blocks.
+#[derive(Default)]
+pub struct FeeHistoryCache {
+	/// The hashes of the cached blocks of the current fork, by block number.
+	hashes_by_number: BTreeMap<SubstrateBlockNumber, H256>,
+	/// The fee data of the cached blocks, by block hash.
+	fees_by_hash: HashMap<H256, BlockFees>,
+}
+
+impl FeeHistoryCache {
+	/// Insert the fee data of a newly imported block.
+	///
+	/// The blocks of a previous fork at the same or greater heights are removed, as well as the
+	/// oldest block if the cache is full.
+	pub fn insert(&mut self, number: SubstrateBlockNumber, hash: H256, fees: BlockFees) {
+		for (_, hash) in self.hashes_by_number.split_off(&number) {
+			self.fees_by_hash.remove(&hash);
+		}
+		self.hashes_by_number.insert(number, hash);
+		self.fees_by_hash.insert(hash, fees);
+
+		while self.hashes_by_number.len() > MAX_FEE_HISTORY_BLOCKS as usize {
+			if let Some((_, hash)) = self.hashes_by_number.pop_first() {
+				self.fees_by_hash.remove(&hash);
+			}
+		}
+	}
+
+	/// Get the fee data of the block with the given hash.
+	#[cfg(test)]
+	pub fn by_hash(&self, hash: &H256) -> Option<&BlockFees> {
+		self.fees_by_hash.get(hash)
+	}
+
+	/// Get the fee data of the most recent block.
+	#[cfg(test)]
+	pub fn latest(&self) -> Option<&BlockFees> {
+		let (_, hash) = self.hashes_by_number.last_key_value()?;
+		self.fees_by_hash.get(hash)
+	}
+
+	/// Get the fee data of at most `block_count` consecutive cached blocks ending with
+	/// `newest_block`, oldest first.
+	pub fn range(&self, newest_block: SubstrateBlockNumber, block_count: u64) -> Vec<&BlockFees> {
+		let mut fees = Vec::new();
+		let mut next = newest_block;
+		for (number, hash) in
+			self.hashes_by_number.range(..=newest_block).rev().take(block_count as usize)
+		{
+			// Stop at the first block missing from the cache.
+			if *number != next {
+				break;
+			}
+			fees.extend(self.fees_by_hash.get(hash));
+			next = number.saturating_sub(1);
+		}
+		fees.reverse();
+		fees
+	}
+
+	/// Suggest a priority fee per gas, from the rewards of the transactions included in the
+	/// last [`PRIORITY_FEE_BLOCKS`] blocks.
+	///
+	/// Returns `None` if none of the blocks contained transactions.
+	pub fn suggest_priority_fee(&self) -> Option<U256> {
+		let rewards = self
+			.hashes_by_number
+			.values()
+			.rev()
+			.take(PRIORITY_FEE_BLOCKS as usize)
+			.filter_map(|hash| self.fees_by_hash.get(hash))
+			.filter(|fees| !fees.rewards.is_empty())
+			.flat_map(|fees| fees.rewards(&[PRIORITY_FEE_PERCENTILE]))
+			.collect();
+		suggest_priority_fee(rewards)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn receipt(effective_gas_price: u32, gas_used: u32) -> ReceiptInfo {
+		ReceiptInfo {
+			effective_gas_price: effective_gas_price.into(),
+			gas_used: gas_used.into(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn validate_percentiles_works() {
+		assert_eq!(validate_percentiles(&[]), Ok(()));
+		assert_eq!(validate_percentiles(&[0.0, 50.0, 50.0, 100.0]), Ok(()));
+		assert_eq!(validate_percentiles(&[-1.0]), Err(PercentilesError::OutOfRange));
+		assert_eq!(validate_percentiles(&[101.0]), Err(PercentilesError::OutOfRange));
+		assert_eq!(validate_percentiles(&[50.0, 10.0]), Err(PercentilesError::NotAscending));
+	}
+
+	fn block_fees(receipts: &[ReceiptInfo]) -> BlockFees {
+		BlockFees::new(U256::from(100), U256::from(100), U256::from(1000), receipts)
+	}
+
+	#[test]
+	fn block_rewards_are_weighted_by_gas_used() {
+		assert_eq!(block_fees(&[]).rewards(&[10.0, 90.0]), vec![U256::zero(); 2]);
+
+		let receipts = [receipt(130, 10), receipt(110, 80), receipt(120, 10), receipt(90, 0)];
+		let fees = block_fees(&receipts);
+		assert_eq!(fees.gas_used_ratio, 0.1);
+		assert_eq!(
+			fees.rewards(&[0.0, 10.0, 50.0, 85.0, 95.0, 100.0]),
+			[0, 10, 10, 20, 30, 30].map(U256::from).to_vec()
+		);
+	}
+
+	#[test]
+	fn fee_history_cache_follows_the_best_fork() {
+		let mut cache = FeeHistoryCache::default();
+		let blocks = MAX_FEE_HISTORY_BLOCKS as SubstrateBlockNumber + 2;
+		for number in 0..blocks {
+			let fees = block_fees(&[receipt(100 + number, 1)]);
+			cache.insert(number, H256::repeat_byte(number as u8), fees);
+		}
+
+		// The oldest blocks were pruned.
+		assert!(cache.by_hash(&H256::repeat_byte(1)).is_none());
+		assert_eq!(cache.range(blocks - 1, u64::MAX).len(), MAX_FEE_HISTORY_BLOCKS as usize);
+		assert_eq!(
+			cache.range(blocks - 1, 3),
+			[blocks - 3, blocks - 2, blocks - 1]
+				.map(|n| cache.by_hash(&H256::repeat_byte(n as u8)).unwrap())
+		);
+		assert_eq!(cache.latest().unwrap().rewards(&[50.0]), vec![U256::from(blocks - 1)]);
+		assert!(cache.range(blocks, 1).is_empty());
+
+		// A block of another fork replaces the blocks at the same or greater heights.
+		let fork_hash = H256::repeat_byte(0xff);
+		cache.insert(blocks - 2, fork_hash, block_fees(&[]));
+		assert!(cache.by_hash(&H256::repeat_byte((blocks - 1) as u8)).is_none());
+		assert!(cache.by_hash(&H256::repeat_byte((blocks - 2) as u8)).is_none());
+		assert_eq!(cache.latest(), cache.by_hash(&fork_hash));
+		// The priority fee is suggested from the rewards of blocks 45 to 63, the new best block
+		// being empty.
+		assert_eq!(cache.suggest_priority_fee(), Some(U256::from(55)));
+	}
+
+	#[test]
+	fn suggest_priority_fee_works() {
+		assert_eq!(suggest_priority_fee(vec![]), None);
+		assert_eq!(suggest_priority_fee(vec![U256::from(5)]), Some(U256::from(5)));
+		assert_eq!(
+			suggest_priority_fee([5, 1, 4, 2, 3, 6].map(U256::from).to_vec()),
+			Some(U256::from(4))
+		);
+	}
+}
@@ -0,0 +1,213 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Filters installed with `eth_newFilter` and `eth_newBlockFilter`, polled with
+//! `eth_getFilterChanges`.
+use crate::client::{Client, ClientError, LogsLimits, SubstrateBlockNumber};
+use pallet_revive::evm::{BlockNumberOrTag, Filter, FilterResults, U256};
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// Filters that are not polled within this duration are uninstalled.
+const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// The kind of an installed filter.
+#[derive(Debug, Clone)]
+pub enum FilterKind {
+	/// A filter notifying about new logs matching the given filter.
+	Logs(Filter),
+	/// A filter notifying about new block hashes.
+	Blocks,
+}
+
+/// A filter installed on the server.
+#[derive(Debug)]
+struct InstalledFilter {
+	/// The kind of the filter.
+	kind: FilterKind,
+	/// The last block reported to the client.
+	last_block: SubstrateBlockNumber,
+	/// The last time the filter was polled.
+	last_poll: Instant,
+}
+
+#[derive(Default)]
+struct FiltersState {
+	/// The id of the next installed filter.
+	next_id: u64,
+	/// The installed filters by id.
+	filters: HashMap<U256, InstalledFilter>,
+}
+
+impl FiltersState {
+	/// Remove the filters that have not been polled within [`FILTER_TIMEOUT`].
+	fn prune_expired(&mut self, now: Instant) {
+		self.filters
+			.retain(|_, filter| now.saturating_duration_since(filter.last_poll) < FILTER_TIMEOUT);
+	}
+}
+
+/// Keep track of the installed filters.
+#[derive(Clone, Default)]
+pub struct Filters {
+	state: Arc<Mutex<FiltersState>>,
+}
+
+impl Filters {
+	/// Install a new filter, tracking changes from the given block onwards.
+	pub async fn install(&self, kind: FilterKind, current_block: SubstrateBlockNumber) -> U256 {
+		let now = Instant::now();
+		let mut state = self.state.lock().await;
+		state.prune_expired(now);
+
+		state.next_id += 1;
+		let id = U256::from(state.next_id);
+		state
+			.filters
+			.insert(id, InstalledFilter { kind, last_block: current_block, last_poll: now });
+		id
+	}
+
+	/// Uninstall the filter with the given id, returning `true` if it existed.
+	pub async fn uninstall(&self, id: &U256) -> bool {
+		self.state.lock().await.filters.remove(id).is_some()
+	}
+
+	/// Get the kind of the filter with the given id.
+	pub async fn kind(&self, id: &U256) -> Option<FilterKind> {
+		let mut state = self.state.lock().await;
+		state.prune_expired(Instant::now());
+		state.filters.get(id).map(|filter| filter.kind.clone())
+	}
+
+	/// Get the changes of the filter with the given id since it was last polled.
+	pub async fn changes(
+		&self,
+		client: &Client,
+		id: &U256,
+		limits: &LogsLimits,
+	) -> Result<Option<FilterResults>, ClientError> {
+		let now = Instant::now();
+		let (kind, last_block) = {
+			let mut state = self.state.lock().await;
+			state.prune_expired(now);
+			let Some(filter) = state.filters.get_mut(id) else { return Ok(None) };
+			filter.last_poll = now;
+			(filter.kind.clone(), filter.last_block)
+		};
+
+		let current_block = client.block_number().await?;
+		if current_block <= last_block {
+			let results = match kind {
+				FilterKind::Logs(_) => FilterResults::Logs(vec![]),
+				FilterKind::Blocks => FilterResults::Hashes(vec![]),
+			};
+			return Ok(Some(results));
+		}
+
+		// Only report changes within the allowed block range, the remaining blocks will be
+		// reported on the next poll.
+		let from_block = last_block + 1;
+		let to_block = current_block.min(last_block.saturating_add(limits.max_block_range));
+
+		let results = match kind {
+			// A filter pinned to a block hash reports the logs of that block once, on the poll
+			// covering its height.
+			FilterKind::Logs(filter @ Filter { block_hash: Some(hash), .. }) => {
+				let block_number = client.block_by_hash(&hash).await?.map(|block| block.number());
+				let logs = match block_number {
+					Some(n) if (from_block..=to_block).contains(&n) =>
+						client.logs(&filter, limits).await?,
+					_ => vec![],
+				};
+				FilterResults::Logs(logs)
+			},
+			FilterKind::Logs(filter) => {
+				let from_block = match filter.from_block {
+					Some(BlockNumberOrTag::U256(n)) =>
+						n.try_into().map_or(from_block, |n: SubstrateBlockNumber| n.max(from_block)),
+					_ => from_block,
+				};
+				let to_block = match filter.to_block {
+					Some(BlockNumberOrTag::U256(n)) =>
+						n.try_into().map_or(to_block, |n: SubstrateBlockNumber| n.min(to_block)),
+					_ => to_block,
+				};
+
+				let logs = if from_block <= to_block {
+					let filter = Filter {
+						from_block: Some(U256::from(from_block).into()),
+						to_block: Some(U256::from(to_block).into()),
+						block_hash: None,
+						..filter
+					};
+					client.logs(&filter, limits).await?
+				} else {
+					vec![]
+				};
+				FilterResults::Logs(logs)
+			},
+			FilterKind::Blocks => {
+				let mut hashes = Vec::new();
+				for block_number in from_block..=to_block {
+					if let Some(hash) = client.get_block_hash(block_number).await? {
+						hashes.push(hash);
+					}
+				}
+				FilterResults::Hashes(hashes)
+			},
+		};
+
+		if let Some(filter) = self.state.lock().await.filters.get_mut(id) {
+			filter.last_block = to_block;
+		}
+
+		Ok(Some(results))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn install_and_uninstall_works() {
+		let filters = Filters::default();
+		let first = filters.install(FilterKind::Blocks, 1).await;
+		let second = filters.install(FilterKind::Logs(Filter::default()), 1).await;
+		assert_ne!(first, second);
+
+		assert!(matches!(filters.kind(&first).await, Some(FilterKind::Blocks)));
+		assert!(filters.uninstall(&first).await);
+		assert!(!filters.uninstall(&first).await);
+		assert!(filters.kind(&first).await.is_none());
+		assert!(matches!(filters.kind(&second).await, Some(FilterKind::Logs(_))));
+	}
+
+	#[tokio::test]
+	async fn expired_filters_are_pruned() {
+		let filters = Filters::default();
+		let id = filters.install(FilterKind::Blocks, 1).await;
+
+		let mut state = filters.state.lock().await;
+		state.prune_expired(Instant::now() + FILTER_TIMEOUT);
+		assert!(!state.filters.contains_key(&id));
+	}
+}
@@ -17,14 +17,13 @@
 //! The [`EthRpcServer`] RPC server implementation
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use crate::runtime::GAS_PRICE;
-use client::ClientError;
+use client::{ClientError, LogsLimits};
+use filters::{FilterKind, Filters};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	types::{ErrorCode, ErrorObjectOwned},
 };
 use pallet_revive::evm::*;
-use sp_arithmetic::Permill;
 use sp_core::{keccak_256, H160, H256, U256};
 use thiserror::Error;
 
@@ -39,12 +38,22 @@ mod tests;
 mod block_info_provider;
 pub use block_info_provider::*;
 
+mod fee_history;
+
+mod filters;
+
 mod receipt_provider;
 pub use receipt_provider::*;
 
+mod rpc_debug;
+pub use rpc_debug::*;
+
 mod rpc_health;
 pub use rpc_health::*;
 
+mod rpc_pubsub;
+pub use rpc_pubsub::*;
+
 mod rpc_methods_gen;
 pub use rpc_methods_gen::*;
 
@@ -57,12 +66,23 @@ pub struct EthRpcServerImpl {
 
 	/// The accounts managed by the server.
 	accounts: Vec<Account>,
+
+	/// The filters installed with `eth_newFilter` and `eth_newBlockFilter`.
+	filters: Filters,
+
+	/// The limits applied to log queries.
+	logs_limits: LogsLimits,
 }
 
 impl EthRpcServerImpl {
 	/// Creates a new [`EthRpcServerImpl`].
 	pub fn new(client: client::Client) -> Self {
-		Self { client, accounts: vec![] }
+		Self {
+			client,
+			accounts: vec![],
+			filters: Default::default(),
+			logs_limits: Default::default(),
+		}
 	}
 
 	/// Sets the accounts managed by the server.
@@ -70,6 +90,12 @@ impl EthRpcServerImpl {
 		self.accounts = accounts;
 		self
 	}
+
+	/// Sets the limits applied to log queries.
+	pub fn with_logs_limits(mut self, logs_limits: LogsLimits) -> Self {
+		self.logs_limits = logs_limits;
+		self
+	}
 }
 
 /// The error type for the EVM RPC server.
@@ -96,6 +122,9 @@ pub enum EthRpcError {
 	/// Received an invalid transaction
 	#[error("Invalid transaction {0:?}")]
 	TransactionTypeNotSupported(Byte),
+	/// The filter was not found, or has expired.
+	#[error("filter not found")]
+	FilterNotFound(U256),
 }
 
 // TODO use https://eips.ethereum.org/EIPS/eip-1474#error-codes
@@ -167,6 +196,7 @@ impl EthRpcServer for EthRpcServerImpl {
 		})?;
 
 		log::debug!(target: LOG_TARGET, "send_raw_transaction hash: {hash:?}");
+		self.client.notify_pending_transaction(hash);
 		Ok(hash)
 	}
 
@@ -229,12 +259,23 @@ impl EthRpcServer for EthRpcServerImpl {
 	}
 
 	async fn gas_price(&self) -> RpcResult<U256> {
-		Ok(U256::from(GAS_PRICE))
+		Ok(self.client.gas_price().await)
 	}
 
 	async fn max_priority_fee_per_gas(&self) -> RpcResult<U256> {
-		// TODO: Provide better estimation
-		Ok(U256::from(Permill::from_percent(20).mul_ceil(GAS_PRICE)))
+		Ok(self.client.max_priority_fee_per_gas().await)
+	}
+
+	async fn fee_history(
+		&self,
+		block_count: U256,
+		newest_block: BlockNumberOrTag,
+		reward_percentiles: Option<Vec<f64>>,
+	) -> RpcResult<FeeHistoryResult> {
+		let block_count = block_count.try_into().unwrap_or(u64::MAX);
+		let result =
+			self.client.fee_history(block_count, &newest_block, reward_percentiles).await?;
+		Ok(result)
 	}
 
 	async fn get_code(&self, address: H160, block: BlockNumberOrTagOrHash) -> RpcResult<Bytes> {
@@ -345,4 +386,35 @@ impl EthRpcServer for EthRpcServerImpl {
 		let nonce = self.client.nonce(address, block).await?;
 		Ok(nonce)
 	}
+
+	async fn get_logs(&self, filter: Option<Filter>) -> RpcResult<FilterResults> {
+		let logs = self.client.logs(&filter.unwrap_or_default(), &self.logs_limits).await?;
+		Ok(FilterResults::Logs(logs))
+	}
+
+	async fn new_filter(&self, filter: Filter) -> RpcResult<U256> {
+		let current_block = self.client.block_number().await?;
+		Ok(self.filters.install(FilterKind::Logs(filter), current_block).await)
+	}
+
+	async fn new_block_filter(&self) -> RpcResult<U256> {
+		let current_block = self.client.block_number().await?;
+		Ok(self.filters.install(FilterKind::Blocks, current_block).await)
+	}
+
+	async fn get_filter_changes(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		let changes = self.filters.changes(&self.client, &filter_id, &self.logs_limits).await?;
+		Ok(changes.ok_or(EthRpcError::FilterNotFound(filter_id))?)
+	}
+
+	async fn get_filter_logs(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		let Some(FilterKind::Logs(filter)) = self.filters.kind(&filter_id).await else {
+			return Err(EthRpcError::FilterNotFound(filter_id).into());
+		};
+		self.get_logs(Some(filter)).await
+	}
+
+	async fn uninstall_filter(&self, filter_id: U256) -> RpcResult<bool> {
+		Ok(self.filters.uninstall(&filter_id).await)
+	}
 }
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::{
-	client::SubstrateBlock,
+	client::{SubstrateBlock, SubstrateBlockNumber},
 	subxt_client::{
 		revive::{calls::types::EthTransact, events::ContractEmitted},
 		system::events::ExtrinsicSuccess,
@@ -29,7 +29,10 @@ use futures::{stream, StreamExt};
 use jsonrpsee::core::async_trait;
 use pallet_revive::{
 	create1,
-	evm::{GenericTransaction, Log, ReceiptInfo, TransactionSigned, H256, U256},
+	evm::{
+		AddressOrAddresses, Filter, FilterTopic, GenericTransaction, Log, ReceiptInfo,
+		TransactionSigned, H160, H256, U256,
+	},
 };
 use sp_core::keccak_256;
 use tokio::join;
@@ -40,6 +43,83 @@ pub use cache::CacheReceiptProvider;
 mod db;
 pub use db::DBReceiptProvider;
 
+/// A log query, with the block tags of the originating filter resolved to block numbers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogQuery {
+	/// Only match logs from this block. When set, the block range is ignored.
+	pub block_hash: Option<H256>,
+	/// The first block of the range (inclusive).
+	pub from_block: SubstrateBlockNumber,
+	/// The last block of the range (inclusive).
+	pub to_block: SubstrateBlockNumber,
+	/// Only match logs emitted by one of these addresses. Empty matches any address.
+	pub addresses: Vec<H160>,
+	/// The topics to match, by position. An empty set matches any topic at that position.
+	pub topics: Vec<Vec<H256>>,
+	/// The maximum number of logs the query may return.
+	pub limit: usize,
+}
+
+impl LogQuery {
+	/// Create a new query matching the block hash, addresses and topics of the given filter.
+	/// The block range is left empty, and must be resolved by the caller.
+	pub fn new(filter: &Filter, limit: usize) -> Self {
+		let addresses = match &filter.address {
+			Some(AddressOrAddresses::Address(address)) => vec![*address],
+			Some(AddressOrAddresses::Addresses(addresses)) => addresses.clone(),
+			None => vec![],
+		};
+
+		let topics = filter
+			.topics
+			.iter()
+			.flatten()
+			.map(|topic| match topic {
+				Some(FilterTopic::Single(topic)) => vec![*topic],
+				Some(FilterTopic::Multiple(topics)) => topics.clone(),
+				None => vec![],
+			})
+			.collect();
+
+		Self { block_hash: filter.block_hash, addresses, topics, limit, ..Default::default() }
+	}
+
+	/// Returns `true` if the block with the given hash and number is covered by the query.
+	pub fn matches_block(&self, block_hash: &H256, block_number: SubstrateBlockNumber) -> bool {
+		match self.block_hash {
+			Some(hash) => hash == *block_hash,
+			None => self.from_block <= block_number && block_number <= self.to_block,
+		}
+	}
+
+	/// Returns `true` if the log address and topics match the query.
+	pub fn matches_log(&self, log: &Log) -> bool {
+		if !self.addresses.is_empty() && !self.addresses.contains(&log.address) {
+			return false;
+		}
+
+		self.topics.iter().enumerate().all(|(i, topics)| {
+			topics.is_empty() || log.topics.get(i).is_some_and(|topic| topics.contains(topic))
+		})
+	}
+}
+
+/// Order logs by block number, transaction index and log index, remove duplicates and make sure
+/// the result fits in the query limit.
+pub(crate) fn sort_and_limit_logs(
+	mut logs: Vec<Log>,
+	limit: usize,
+) -> Result<Vec<Log>, ClientError> {
+	logs.sort_by_key(|log| {
+		(log.block_number, log.transaction_index, log.log_index, log.block_hash)
+	});
+	logs.dedup_by_key(|log| (log.block_hash, log.log_index));
+	if logs.len() > limit {
+		return Err(ClientError::LogsLimitExceeded(limit));
+	}
+	Ok(logs)
+}
+
 /// Provide means to store and retrieve receipts.
 #[async_trait]
 pub trait ReceiptProvider: Send + Sync {
@@ -49,6 +129,9 @@ pub trait ReceiptProvider: Send + Sync {
 	/// Remove receipts with the given block hash.
 	async fn remove(&self, block_hash: &H256);
 
+	/// Remove the receipts and logs of a block that is no longer part of the best chain.
+	async fn retract(&self, block_hash: &H256);
+
 	/// Get the receipt for the given block hash and transaction index.
 	async fn receipt_by_block_hash_and_index(
 		&self,
@@ -64,6 +147,12 @@ pub trait ReceiptProvider: Send + Sync {
 
 	/// Get the signed transaction for the given transaction hash.
 	async fn signed_tx_by_hash(&self, transaction_hash: &H256) -> Option<TransactionSigned>;
+
+	/// Get the logs matching the given query, ordered by block number, transaction index and
+	/// log index.
+	///
+	/// Returns [`ClientError::LogsLimitExceeded`] if more than `query.limit` logs match.
+	async fn logs(&self, query: &LogQuery) -> Result<Vec<Log>, ClientError>;
 }
 
 #[async_trait]
@@ -76,6 +165,10 @@ impl<Main: ReceiptProvider, Fallback: ReceiptProvider> ReceiptProvider for (Main
 		join!(self.0.remove(block_hash), self.1.remove(block_hash));
 	}
 
+	async fn retract(&self, block_hash: &H256) {
+		join!(self.0.retract(block_hash), self.1.retract(block_hash));
+	}
+
 	async fn receipt_by_block_hash_and_index(
 		&self,
 		block_hash: &H256,
@@ -110,6 +203,12 @@ impl<Main: ReceiptProvider, Fallback: ReceiptProvider> ReceiptProvider for (Main
 		}
 		self.1.signed_tx_by_hash(hash).await
 	}
+
+	async fn logs(&self, query: &LogQuery) -> Result<Vec<Log>, ClientError> {
+		let (main, fallback) = join!(self.0.logs(query), self.1.logs(query));
+		let logs = main?.into_iter().chain(fallback?).collect();
+		sort_and_limit_logs(logs, query.limit)
+	}
 }
 
 /// Extract a [`TransactionSigned`] and a [`ReceiptInfo`] and  from an extrinsic.
@@ -14,9 +14,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use super::ReceiptProvider;
+use super::{sort_and_limit_logs, LogQuery, ReceiptProvider};
+use crate::ClientError;
 use jsonrpsee::core::async_trait;
-use pallet_revive::evm::{ReceiptInfo, TransactionSigned, H256, U256};
+use pallet_revive::evm::{Log, ReceiptInfo, TransactionSigned, H256, U256};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 
@@ -45,6 +46,10 @@ impl ReceiptProvider for CacheReceiptProvider {
 		cache.remove(block_hash);
 	}
 
+	async fn retract(&self, block_hash: &H256) {
+		self.remove(block_hash).await;
+	}
+
 	async fn receipt_by_block_hash_and_index(
 		&self,
 		block_hash: &H256,
@@ -73,6 +78,11 @@ impl ReceiptProvider for CacheReceiptProvider {
 		let cache = self.cache().await;
 		cache.signed_tx_by_hash.get(hash).cloned()
 	}
+
+	async fn logs(&self, query: &LogQuery) -> Result<Vec<Log>, ClientError> {
+		let cache = self.cache().await;
+		sort_and_limit_logs(cache.logs(query), query.limit)
+	}
 }
 
 #[derive(Default)]
@@ -110,6 +120,20 @@ impl ReceiptCache {
 		}
 	}
 
+	/// Get the cached logs matching the given query.
+	pub fn logs(&self, query: &LogQuery) -> Vec<Log> {
+		self.receipts_by_hash
+			.values()
+			.filter(|receipt| {
+				receipt.block_number.try_into().is_ok_and(|block_number| {
+					query.matches_block(&receipt.block_hash, block_number)
+				})
+			})
+			.flat_map(|receipt| receipt.logs.iter().filter(|log| query.matches_log(log)))
+			.cloned()
+			.collect()
+	}
+
 	/// Remove entry from the cache.
 	pub fn remove(&mut self, hash: &H256) {
 		if let Some(entries) = self.transaction_hashes_by_block_and_index.remove(hash) {
@@ -124,6 +148,7 @@ impl ReceiptCache {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use pallet_revive::evm::H160;
 
 	#[test]
 	fn cache_insert_and_remove_works() {
@@ -145,4 +170,69 @@ mod test {
 		assert_eq!(cache.receipts_by_hash.len(), 2);
 		assert_eq!(cache.signed_tx_by_hash.len(), 2);
 	}
+
+	#[test]
+	fn cache_logs_works() {
+		let mut cache = ReceiptCache::default();
+		let address = H160::from([1u8; 20]);
+		let topic = H256::from([2u8; 32]);
+
+		for i in 1u8..=3 {
+			let block_hash = H256::from([i; 32]);
+			let log = Log {
+				address,
+				topics: vec![topic, H256::from([i; 32])],
+				block_hash: Some(block_hash),
+				block_number: Some(i.into()),
+				log_index: Some(U256::zero()),
+				..Default::default()
+			};
+			cache.insert(
+				&block_hash,
+				&[(
+					TransactionSigned::default(),
+					ReceiptInfo {
+						block_hash,
+						block_number: i.into(),
+						transaction_hash: H256::from([i + 10; 32]),
+						logs: vec![log],
+						..Default::default()
+					},
+				)],
+			);
+		}
+
+		let query = LogQuery {
+			from_block: 2,
+			to_block: 3,
+			addresses: vec![address],
+			topics: vec![vec![topic]],
+			limit: 10,
+			..Default::default()
+		};
+		let logs = sort_and_limit_logs(cache.logs(&query), query.limit).unwrap();
+		assert_eq!(
+			logs.iter().map(|log| log.block_number).collect::<Vec<_>>(),
+			vec![Some(2.into()), Some(3.into())]
+		);
+
+		let query = LogQuery {
+			block_hash: Some(H256::from([1u8; 32])),
+			topics: vec![vec![], vec![H256::from([1u8; 32]), H256::from([3u8; 32])]],
+			limit: 10,
+			..Default::default()
+		};
+		assert_eq!(cache.logs(&query).len(), 1);
+
+		let query = LogQuery { from_block: 1, to_block: 3, limit: 2, ..Default::default() };
+		assert!(matches!(
+			sort_and_limit_logs(cache.logs(&query), query.limit),
+			Err(ClientError::LogsLimitExceeded(2))
+		));
+
+		// The logs of a retracted block are no longer returned.
+		cache.remove(&H256::from([3u8; 32]));
+		let query = LogQuery { from_block: 1, to_block: 3, limit: 10, ..Default::default() };
+		assert_eq!(cache.logs(&query).len(), 2);
+	}
 }
@@ -18,11 +18,14 @@
 use super::*;
 use crate::BlockInfoProvider;
 use jsonrpsee::core::async_trait;
-use pallet_revive::evm::{ReceiptInfo, TransactionSigned};
-use sp_core::{H256, U256};
-use sqlx::{query, SqlitePool};
+use pallet_revive::evm::{Log, ReceiptInfo, TransactionSigned};
+use sp_core::{H160, H256, U256};
+use sqlx::{query, sqlite::SqliteRow, QueryBuilder, Row, Sqlite, SqlitePool};
 use std::sync::Arc;
 
+/// The number of topics indexed in the `logs` table.
+const MAX_TOPICS: usize = 4;
+
 /// A `[ReceiptProvider]` that stores receipts in a SQLite database.
 #[derive(Clone)]
 pub struct DBReceiptProvider {
@@ -63,6 +66,112 @@ impl DBReceiptProvider {
 		let transaction_index = result.transaction_index.try_into().ok()?;
 		Some((block_hash, transaction_index))
 	}
+
+	/// Insert the logs of the given receipts.
+	/// Logs previously stored for another block at the same height are removed, so that range
+	/// queries only return logs from the latest imported fork.
+	async fn insert_logs(
+		&self,
+		block_hash: &H256,
+		receipts: &[(TransactionSigned, ReceiptInfo)],
+	) -> Result<(), sqlx::Error> {
+		// A block without receipts still replaces the logs of a reorged block at its height.
+		let block_number = match receipts.first() {
+			Some((_, receipt)) => receipt.block_number.as_u64(),
+			None => match self.block_provider.block_by_hash(block_hash).await {
+				Ok(Some(block)) => block.number().into(),
+				_ => {
+					log::debug!("Block {block_hash:?} not found, skipping log insertion");
+					return Ok(())
+				},
+			},
+		};
+		let block_number = block_number as i64;
+		let block_hash = block_hash.as_bytes();
+
+		let mut tx = self.pool.begin().await?;
+		sqlx::query("DELETE FROM logs WHERE block_number = $1 AND block_hash != $2")
+			.bind(block_number)
+			.bind(block_hash)
+			.execute(&mut *tx)
+			.await?;
+
+		for (_, receipt) in receipts {
+			for log in &receipt.logs {
+				let topic = |i: usize| log.topics.get(i).map(|topic| topic.as_bytes());
+				sqlx::query(
+					r#"
+					INSERT OR REPLACE INTO logs (
+						block_hash, block_number, transaction_index, transaction_hash, log_index,
+						address, topic_0, topic_1, topic_2, topic_3, data
+					)
+					VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+					"#,
+				)
+				.bind(block_hash)
+				.bind(block_number)
+				.bind(receipt.transaction_index.as_u32() as i64)
+				.bind(receipt.transaction_hash.as_bytes())
+				.bind(log.log_index.unwrap_or_default().as_u32() as i64)
+				.bind(log.address.as_bytes())
+				.bind(topic(0))
+				.bind(topic(1))
+				.bind(topic(2))
+				.bind(topic(3))
+				.bind(log.data.as_ref().map(|data| data.0.as_slice()))
+				.execute(&mut *tx)
+				.await?;
+			}
+		}
+
+		tx.commit().await
+	}
+
+	/// Delete the logs and transaction hashes of the given block.
+	async fn delete_block(&self, block_hash: &H256) -> Result<(), sqlx::Error> {
+		let mut tx = self.pool.begin().await?;
+		sqlx::query("DELETE FROM logs WHERE block_hash = $1")
+			.bind(block_hash.as_bytes())
+			.execute(&mut *tx)
+			.await?;
+		sqlx::query("DELETE FROM transaction_hashes WHERE block_hash = $1")
+			.bind(hex::encode(block_hash))
+			.execute(&mut *tx)
+			.await?;
+		tx.commit().await
+	}
+}
+
+/// Decode a row of the `logs` table into a [`Log`].
+fn decode_log(row: &SqliteRow) -> Result<Log, sqlx::Error> {
+	let h256 = |column: &str| -> Result<Option<H256>, sqlx::Error> {
+		let bytes: Option<Vec<u8>> = row.try_get(column)?;
+		Ok(bytes.filter(|bytes| bytes.len() == 32).map(|bytes| H256::from_slice(&bytes)))
+	};
+
+	let address: Vec<u8> = row.try_get("address")?;
+	let block_number: i64 = row.try_get("block_number")?;
+	let transaction_index: i64 = row.try_get("transaction_index")?;
+	let log_index: i64 = row.try_get("log_index")?;
+	let data: Option<Vec<u8>> = row.try_get("data")?;
+	let topics = (0..MAX_TOPICS)
+		.map(|i| h256(&format!("topic_{i}")))
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.map_while(|topic| topic)
+		.collect();
+
+	Ok(Log {
+		address: H160::from_slice(&address),
+		block_hash: h256("block_hash")?,
+		block_number: Some(U256::from(block_number)),
+		data: data.map(Into::into),
+		log_index: Some(U256::from(log_index)),
+		topics,
+		transaction_hash: h256("transaction_hash")?.unwrap_or_default(),
+		transaction_index: Some(U256::from(transaction_index)),
+		..Default::default()
+	})
 }
 
 #[async_trait]
@@ -72,6 +181,10 @@ impl ReceiptProvider for DBReceiptProvider {
 			return
 		}
 
+		if let Err(err) = self.insert_logs(block_hash, receipts).await {
+			log::error!("Error inserting logs for block hash {block_hash:?}: {err:?}");
+		}
+
 		let block_hash_str = hex::encode(block_hash);
 		for (_, receipt) in receipts {
 			let transaction_hash = hex::encode(receipt.transaction_hash);
@@ -104,6 +217,16 @@ impl ReceiptProvider for DBReceiptProvider {
 
 	async fn remove(&self, _block_hash: &H256) {}
 
+	async fn retract(&self, block_hash: &H256) {
+		if self.read_only {
+			return
+		}
+
+		if let Err(err) = self.delete_block(block_hash).await {
+			log::error!("Error deleting retracted block {block_hash:?}: {err:?}");
+		}
+	}
+
 	async fn receipts_count_per_block(&self, block_hash: &H256) -> Option<usize> {
 		let block_hash = hex::encode(block_hash);
 		let row = query!(
@@ -165,14 +288,76 @@ impl ReceiptProvider for DBReceiptProvider {
 			extract_receipts_from_transaction(&block, transaction_index).await.ok()?;
 		Some(signed_tx)
 	}
+
+	async fn logs(&self, query: &LogQuery) -> Result<Vec<Log>, ClientError> {
+		let mut builder = QueryBuilder::<Sqlite>::new(
+			r#"
+			SELECT
+				block_hash, block_number, transaction_index, transaction_hash, log_index,
+				address, topic_0, topic_1, topic_2, topic_3, data
+			FROM logs
+			WHERE
+			"#,
+		);
+
+		match query.block_hash {
+			Some(hash) => {
+				builder.push("block_hash = ").push_bind(hash.as_bytes().to_vec());
+			},
+			None => {
+				builder
+					.push("block_number BETWEEN ")
+					.push_bind(i64::from(query.from_block))
+					.push(" AND ")
+					.push_bind(i64::from(query.to_block));
+			},
+		}
+
+		if !query.addresses.is_empty() {
+			builder.push(" AND address IN (");
+			let mut separated = builder.separated(", ");
+			for address in &query.addresses {
+				separated.push_bind(address.as_bytes().to_vec());
+			}
+			separated.push_unseparated(")");
+		}
+
+		for (i, topics) in query.topics.iter().enumerate().take(MAX_TOPICS) {
+			if topics.is_empty() {
+				continue;
+			}
+
+			builder.push(format!(" AND topic_{i} IN ("));
+			let mut separated = builder.separated(", ");
+			for topic in topics {
+				separated.push_bind(topic.as_bytes().to_vec());
+			}
+			separated.push_unseparated(")");
+		}
+
+		// Fetch one more row than the limit, to detect queries exceeding it.
+		builder
+			.push(" ORDER BY block_number, transaction_index, log_index LIMIT ")
+			.push_bind(query.limit.saturating_add(1) as i64);
+
+		let logs = builder
+			.build()
+			.fetch_all(&self.pool)
+			.await?
+			.iter()
+			.map(decode_log)
+			.collect::<Result<Vec<_>, _>>()?;
+
+		sort_and_limit_logs(logs, query.limit)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::test::MockBlockInfoProvider;
-	use pallet_revive::evm::{ReceiptInfo, TransactionSigned};
-	use sp_core::H256;
+	use pallet_revive::evm::{Log, ReceiptInfo, TransactionSigned};
+	use sp_core::{H160, H256, U256};
 	use sqlx::SqlitePool;
 
 	async fn setup_sqlite_provider(pool: SqlitePool) -> DBReceiptProvider {
@@ -213,4 +398,115 @@ mod tests {
 		let count = provider.receipts_count_per_block(&block_hash).await;
 		assert_eq!(count, Some(2));
 	}
+
+	fn receipt_with_logs(block_number: u32, address: H160, topics: Vec<H256>) -> ReceiptInfo {
+		let block_hash = H256::from_low_u64_be(block_number.into());
+		let transaction_hash = H256::from_low_u64_be(u64::from(block_number) + 100);
+		let log = Log {
+			address,
+			topics,
+			data: Some(vec![block_number as u8].into()),
+			block_hash: Some(block_hash),
+			block_number: Some(block_number.into()),
+			transaction_hash,
+			transaction_index: Some(U256::zero()),
+			log_index: Some(U256::one()),
+			..Default::default()
+		};
+		ReceiptInfo {
+			block_hash,
+			block_number: block_number.into(),
+			transaction_hash,
+			logs: vec![log],
+			..Default::default()
+		}
+	}
+
+	#[sqlx::test]
+	async fn test_logs(pool: SqlitePool) {
+		let provider = setup_sqlite_provider(pool).await;
+		let address = H160::from([1u8; 20]);
+		let other_address = H160::from([2u8; 20]);
+		let topic = H256::from([3u8; 32]);
+
+		for (block_number, address) in [(1, address), (2, other_address), (3, address)] {
+			let receipt = receipt_with_logs(block_number, address, vec![topic]);
+			provider
+				.insert(&receipt.block_hash, &[(TransactionSigned::default(), receipt.clone())])
+				.await;
+		}
+
+		let query = LogQuery {
+			from_block: 1,
+			to_block: 3,
+			addresses: vec![address],
+			topics: vec![vec![topic]],
+			limit: 10,
+			..Default::default()
+		};
+		let logs = provider.logs(&query).await.unwrap();
+		assert_eq!(
+			logs,
+			vec![
+				receipt_with_logs(1, address, vec![topic]).logs[0].clone(),
+				receipt_with_logs(3, address, vec![topic]).logs[0].clone(),
+			]
+		);
+
+		let query = LogQuery {
+			block_hash: Some(H256::from_low_u64_be(2)),
+			topics: vec![vec![H256::from([4u8; 32])]],
+			limit: 10,
+			..Default::default()
+		};
+		assert_eq!(provider.logs(&query).await.unwrap(), vec![]);
+
+		let query = LogQuery { from_block: 1, to_block: 3, limit: 2, ..Default::default() };
+		assert!(matches!(provider.logs(&query).await, Err(ClientError::LogsLimitExceeded(2))));
+	}
+
+	#[sqlx::test]
+	async fn test_logs_replaced_on_reorg(pool: SqlitePool) {
+		let provider = setup_sqlite_provider(pool).await;
+		let address = H160::from([1u8; 20]);
+
+		let receipt = receipt_with_logs(1, address, vec![]);
+		provider
+			.insert(&receipt.block_hash, &[(TransactionSigned::default(), receipt.clone())])
+			.await;
+
+		let fork_hash = H256::from([42u8; 32]);
+		let mut fork_receipt = receipt_with_logs(1, address, vec![]);
+		fork_receipt.block_hash = fork_hash;
+		fork_receipt.logs[0].block_hash = Some(fork_hash);
+		provider
+			.insert(&fork_hash, &[(TransactionSigned::default(), fork_receipt.clone())])
+			.await;
+
+		let query = LogQuery { from_block: 1, to_block: 1, limit: 10, ..Default::default() };
+		assert_eq!(provider.logs(&query).await.unwrap(), fork_receipt.logs);
+	}
+
+	#[sqlx::test]
+	async fn test_retract(pool: SqlitePool) {
+		let provider = setup_sqlite_provider(pool).await;
+		let address = H160::from([1u8; 20]);
+
+		for block_number in [1, 2] {
+			let receipt = receipt_with_logs(block_number, address, vec![]);
+			provider
+				.insert(&receipt.block_hash, &[(TransactionSigned::default(), receipt.clone())])
+				.await;
+		}
+
+		let retracted = receipt_with_logs(2, address, vec![]);
+		provider.retract(&retracted.block_hash).await;
+
+		let query = LogQuery { from_block: 1, to_block: 2, limit: 10, ..Default::default() };
+		assert_eq!(
+			provider.logs(&query).await.unwrap(),
+			receipt_with_logs(1, address, vec![]).logs
+		);
+		assert_eq!(provider.fetch_row(&retracted.transaction_hash).await, None);
+	}
 }
@@ -0,0 +1,83 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Debug JSON-RPC methods, used to trace transactions.
+
+use super::*;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+/// The tracer used when none is given. Unlike the struct logger, its output is bounded by the
+/// call depth rather than the number of executed instructions.
+fn default_tracer_config() -> TracerConfig {
+	TracerConfig::CallTracer { with_logs: false }
+}
+
+#[rpc(server, client)]
+pub trait DebugRpc {
+	/// Trace the execution of the transaction with the given hash.
+	/// Defaults to the call tracer when no `tracer_config` is given.
+	#[method(name = "debug_traceTransaction")]
+	async fn trace_transaction(
+		&self,
+		transaction_hash: H256,
+		tracer_config: Option<TracerConfig>,
+	) -> RpcResult<Trace>;
+
+	/// Trace the execution of a call, executed on top of the given block.
+	/// Defaults to the call tracer when no `tracer_config` is given.
+	#[method(name = "debug_traceCall")]
+	async fn trace_call(
+		&self,
+		transaction: GenericTransaction,
+		block: Option<BlockNumberOrTagOrHash>,
+		tracer_config: Option<TracerConfig>,
+	) -> RpcResult<Trace>;
+}
+
+pub struct DebugRpcServerImpl {
+	client: client::Client,
+}
+
+impl DebugRpcServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl DebugRpcServer for DebugRpcServerImpl {
+	async fn trace_transaction(
+		&self,
+		transaction_hash: H256,
+		tracer_config: Option<TracerConfig>,
+	) -> RpcResult<Trace> {
+		let config = tracer_config.unwrap_or_else(default_tracer_config);
+		let trace = self.client.trace_transaction(transaction_hash, config).await?;
+		Ok(trace)
+	}
+
+	async fn trace_call(
+		&self,
+		transaction: GenericTransaction,
+		block: Option<BlockNumberOrTagOrHash>,
+		tracer_config: Option<TracerConfig>,
+	) -> RpcResult<Trace> {
+		let block = block.unwrap_or_else(|| BlockTag::Latest.into());
+		let config = tracer_config.unwrap_or_else(default_tracer_config);
+		let trace = self.client.trace_call(transaction, block, config).await?;
+		Ok(trace)
+	}
+}
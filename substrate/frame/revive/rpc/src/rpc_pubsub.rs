@@ -0,0 +1,121 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Ethereum `eth_subscribe` JSON-RPC subscriptions.
+
+use super::*;
+use futures::{stream, Stream, StreamExt};
+use jsonrpsee::{
+	core::SubscriptionResult, proc_macros::rpc, types::ErrorObject, PendingSubscriptionSink,
+};
+use sc_rpc::utils::{BoundedVecDeque, PendingSubscription};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// The maximum number of items buffered for a subscription, before the oldest items are dropped.
+const SUBSCRIPTION_BUFFER_CAPACITY: usize = 128;
+
+#[rpc(server, client)]
+pub trait EthPubSubRpc {
+	/// Subscribe to new block headers, logs or pending transactions.
+	/// The `filter` is only supported by `logs` subscriptions; `fromBlock`, `toBlock` and
+	/// `blockHash` are ignored since only logs from new blocks are reported.
+	/// When blocks are retracted from the best chain, their logs are sent again with `removed`
+	/// set, before the logs of the new best block.
+	#[subscription(
+		name = "eth_subscribe" => "eth_subscription",
+		unsubscribe = "eth_unsubscribe",
+		item = SubscriptionItem
+	)]
+	async fn subscribe(&self, kind: SubscriptionKind, filter: Option<Filter>);
+}
+
+pub struct EthPubSubServerImpl {
+	client: client::Client,
+}
+
+impl EthPubSubServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+/// Turn a broadcast receiver into a stream, skipping the items missed by a lagging receiver.
+fn broadcast_stream<T: Clone + Send + 'static>(
+	receiver: broadcast::Receiver<T>,
+) -> impl Stream<Item = T> + Send {
+	stream::unfold(receiver, |mut receiver| async move {
+		loop {
+			match receiver.recv().await {
+				Ok(item) => return Some((item, receiver)),
+				Err(RecvError::Lagged(skipped)) => {
+					log::debug!(target: LOG_TARGET, "Subscription lagging, skipped {skipped} notifications");
+				},
+				Err(RecvError::Closed) => return None,
+			}
+		}
+	})
+}
+
+#[async_trait]
+impl EthPubSubRpcServer for EthPubSubServerImpl {
+	async fn subscribe(
+		&self,
+		pending: PendingSubscriptionSink,
+		kind: SubscriptionKind,
+		filter: Option<Filter>,
+	) -> SubscriptionResult {
+		let stream = match (kind, filter) {
+			(SubscriptionKind::NewHeads, None) =>
+				broadcast_stream(self.client.block_notifications())
+					.map(|notification| SubscriptionItem::from(notification.block.clone()))
+					.boxed(),
+			(SubscriptionKind::Logs, filter) => {
+				let query = LogQuery::new(&filter.unwrap_or_default(), usize::MAX);
+				broadcast_stream(self.client.block_notifications())
+					.flat_map(move |notification| {
+						let logs = notification
+							.removed_logs
+							.iter()
+							.chain(notification.logs.iter())
+							.filter(|log| query.matches_log(log))
+							.cloned()
+							.map(SubscriptionItem::from)
+							.collect::<Vec<_>>();
+						stream::iter(logs)
+					})
+					.boxed()
+			},
+			(SubscriptionKind::NewPendingTransactions, None) =>
+				broadcast_stream(self.client.pending_transaction_notifications())
+					.map(SubscriptionItem::from)
+					.boxed(),
+			(_, Some(_)) => {
+				let err = ErrorObject::owned::<()>(
+					ErrorCode::InvalidParams.code(),
+					"filter is only supported for logs subscriptions",
+					None,
+				);
+				pending.reject(err).await;
+				return Ok(());
+			},
+		};
+
+		PendingSubscription::from(pending)
+			.pipe_from_stream(stream, BoundedVecDeque::new(SUBSCRIPTION_BUFFER_CAPACITY))
+			.await;
+		Ok(())
+	}
+}
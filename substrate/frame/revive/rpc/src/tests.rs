@@ -19,14 +19,14 @@
 use crate::{
 	cli::{self, CliCommand},
 	example::{wait_for_successful_receipt, TransactionBuilder},
-	EthRpcClient,
+	EthPubSubRpcClient, EthRpcClient,
 };
 use clap::Parser;
 use ethabi::Token;
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use pallet_revive::{
 	create1,
-	evm::{Account, BlockTag, U256},
+	evm::{Account, BlockTag, Filter, FilterResults, SubscriptionItem, SubscriptionKind, U256},
 };
 use static_init::dynamic;
 use std::thread;
@@ -250,13 +250,130 @@ async fn event_logs() -> anyhow::Result<()> {
 		.input(bytecode)
 		.send_and_wait_for_receipt(&client)
 		.await?;
+	let contract_address = receipt.contract_address.unwrap();
+	let filter = Filter {
+		address: Some(contract_address.into()),
+		from_block: Some(receipt.block_number.into()),
+		..Default::default()
+	};
+	let filter_id = client.new_filter(filter.clone()).await?;
 
 	let receipt = TransactionBuilder::default()
-		.to(receipt.contract_address.unwrap())
+		.to(contract_address)
 		.input(contract.function("triggerEvent")?.encode_input(&[])?.to_vec())
 		.send_and_wait_for_receipt(&client)
 		.await?;
 	assert_eq!(receipt.logs.len(), 1, "There should be one log.");
+
+	let logs = client.get_logs(Some(filter)).await?;
+	assert_eq!(logs, FilterResults::Logs(receipt.logs.clone()));
+
+	let changes = client.get_filter_changes(filter_id).await?;
+	assert_eq!(changes, FilterResults::Logs(receipt.logs));
+	assert_eq!(client.get_filter_changes(filter_id).await?, FilterResults::Logs(vec![]));
+	assert!(client.uninstall_filter(filter_id).await?);
+	Ok(())
+}
+
+#[tokio::test]
+async fn subscriptions() -> anyhow::Result<()> {
+	let _lock = SHARED_RESOURCES.write();
+	let client = SharedResources::client().await;
+	let (bytecode, contract) = get_contract("EventExample")?;
+	let contract_address = TransactionBuilder::default()
+		.input(bytecode)
+		.send_and_wait_for_receipt(&client)
+		.await?
+		.contract_address
+		.unwrap();
+
+	let mut heads =
+		EthPubSubRpcClient::subscribe(&client, SubscriptionKind::NewHeads, None).await?;
+	let filter = Filter { address: Some(contract_address.into()), ..Default::default() };
+	let mut logs =
+		EthPubSubRpcClient::subscribe(&client, SubscriptionKind::Logs, Some(filter)).await?;
+	let mut pending_txs =
+		EthPubSubRpcClient::subscribe(&client, SubscriptionKind::NewPendingTransactions, None)
+			.await?;
+
+	let hash = TransactionBuilder::default()
+		.to(contract_address)
+		.input(contract.function("triggerEvent")?.encode_input(&[])?.to_vec())
+		.send(&client)
+		.await?;
+	let receipt = wait_for_successful_receipt(&client, hash).await?;
+
+	assert_eq!(
+		pending_txs.next().await.transpose()?,
+		Some(SubscriptionItem::TransactionHash(hash))
+	);
+	assert!(matches!(heads.next().await.transpose()?, Some(SubscriptionItem::Header(_))));
+	assert_eq!(
+		logs.next().await.transpose()?,
+		Some(SubscriptionItem::Log(receipt.logs[0].clone()))
+	);
+
+	heads.unsubscribe().await?;
+	logs.unsubscribe().await?;
+	pending_txs.unsubscribe().await?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn fee_history() -> anyhow::Result<()> {
+	let _lock = SHARED_RESOURCES.write();
+	let client = SharedResources::client().await;
+	let ethan = Account::from(subxt_signer::eth::dev::ethan());
+
+	let hash = TransactionBuilder::default()
+		.value(U256::from(1_000_000_000_000u128))
+		.to(ethan.address())
+		.send(&client)
+		.await?;
+	let receipt = wait_for_successful_receipt(&client, hash).await?;
+
+	let history = client
+		.fee_history(U256::from(4), receipt.block_number.into(), Some(vec![25.0, 75.0]))
+		.await?;
+	let block_count = history.gas_used_ratio.len();
+	assert!(block_count > 0 && block_count <= 4);
+	assert_eq!(history.oldest_block + block_count - 1, receipt.block_number);
+	assert_eq!(history.base_fee_per_gas.len(), block_count + 1);
+
+	let rewards = history.reward.unwrap();
+	assert_eq!(rewards.len(), block_count);
+	assert!(rewards.iter().all(|reward| reward.len() == 2));
+	assert!(*history.gas_used_ratio.last().unwrap() > 0.0);
+
+	let base_fee = *history.base_fee_per_gas.last().unwrap();
+	assert!(client.gas_price().await? >= base_fee);
+
+	// The runtime charges exactly the quoted base fee: a transaction priced at the base fee is
+	// accepted, while a transaction priced below it does not cover its fee and is rejected.
+	let hash = TransactionBuilder::default()
+		.value(U256::from(1_000_000_000_000u128))
+		.to(ethan.address())
+		.mutate(move |tx| tx.gas_price = base_fee)
+		.send(&client)
+		.await?;
+	let receipt = wait_for_successful_receipt(&client, hash).await?;
+	assert_eq!(receipt.effective_gas_price, base_fee);
+
+	let res = TransactionBuilder::default()
+		.value(U256::from(1_000_000_000_000u128))
+		.to(ethan.address())
+		.mutate(move |tx| tx.gas_price = base_fee / 2)
+		.send(&client)
+		.await;
+	assert!(res.is_err());
+
+	let err: anyhow::Error = client
+		.fee_history(U256::from(4), BlockTag::Latest.into(), Some(vec![75.0, 25.0]))
+		.await
+		.unwrap_err()
+		.into();
+	let call_err = unwrap_call_err!(err);
+	assert_eq!(call_err.message(), "reward percentiles are not in ascending order");
 	Ok(())
 }
 
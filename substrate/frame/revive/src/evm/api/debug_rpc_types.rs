@@ -15,8 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::evm::{Bytes, CallTracer};
-use alloc::{fmt, string::String, vec::Vec};
+use crate::{
+	evm::{Bytes, EvmTracer},
+	Config,
+};
+use alloc::{collections::BTreeMap, fmt, string::String, vec::Vec};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use serde::{
@@ -36,14 +39,25 @@ pub enum TracerConfig {
 		#[serde(rename = "withLog")]
 		with_logs: bool,
 	},
+	/// A tracer that captures the state of the accounts touched by a transaction.
+	#[serde(rename = "prestateTracer")]
+	PrestateTracer {
+		/// Whether or not to capture the state before and after the transaction.
+		#[serde(rename = "diffMode")]
+		diff_mode: bool,
+	},
+	/// A tracer that captures every executed instruction.
+	#[serde(rename = "structLogger")]
+	StructLogger {
+		/// The maximum number of instructions to capture, or `0` for no limit.
+		limit: u64,
+	},
 }
 
 impl TracerConfig {
 	/// Build the tracer associated to this config.
-	pub fn build<G>(self, gas_mapper: G) -> CallTracer<U256, G> {
-		match self {
-			Self::CallTracer { with_logs } => CallTracer::new(with_logs, gas_mapper),
-		}
+	pub fn build<T: Config>(self) -> EvmTracer<T> {
+		EvmTracer::new(self)
 	}
 }
 
@@ -56,6 +70,16 @@ impl TracerConfig {
 /// ```json
 /// { "tracer": "callTracer" }
 /// ```
+///
+/// ```json
+/// { "tracer": "prestateTracer", "tracerConfig": { "diffMode": true } }
+/// ```
+///
+/// The struct logger is used when no tracer is specified:
+///
+/// ```json
+/// { "limit": 100 }
+/// ```
 impl<'de> Deserialize<'de> for TracerConfig {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -76,6 +100,8 @@ impl<'de> Deserialize<'de> for TracerConfig {
 			{
 				let mut tracer_type: Option<String> = None;
 				let mut with_logs = None;
+				let mut diff_mode = None;
+				let mut limit = None;
 
 				while let Some(key) = map.next_key::<String>()? {
 					match key.as_str() {
@@ -84,20 +110,31 @@ impl<'de> Deserialize<'de> for TracerConfig {
 						},
 						"tracerConfig" => {
 							#[derive(Deserialize)]
-							struct CallTracerConfig {
+							struct InnerTracerConfig {
 								#[serde(rename = "withLogs")]
 								with_logs: Option<bool>,
+								#[serde(rename = "diffMode")]
+								diff_mode: Option<bool>,
 							}
-							let inner: CallTracerConfig = map.next_value()?;
+							let inner: InnerTracerConfig = map.next_value()?;
 							with_logs = inner.with_logs;
+							diff_mode = inner.diff_mode;
+						},
+						"limit" => {
+							limit = map.next_value()?;
+						},
+						_ => {
+							map.next_value::<de::IgnoredAny>()?;
 						},
-						_ => {},
 					}
 				}
 
 				match tracer_type.as_deref() {
 					Some("callTracer") =>
 						Ok(TracerConfig::CallTracer { with_logs: with_logs.unwrap_or(true) }),
+					Some("prestateTracer") =>
+						Ok(TracerConfig::PrestateTracer { diff_mode: diff_mode.unwrap_or(false) }),
+					None => Ok(TracerConfig::StructLogger { limit: limit.unwrap_or(0) }),
 					_ => Err(de::Error::custom("Unsupported or missing tracer type")),
 				}
 			}
@@ -119,6 +156,13 @@ fn test_tracer_config_serialization() {
 			r#"{"tracer": "callTracer", "tracerConfig": { "withLogs": false }}"#,
 			TracerConfig::CallTracer { with_logs: false },
 		),
+		(r#"{"tracer": "prestateTracer"}"#, TracerConfig::PrestateTracer { diff_mode: false }),
+		(
+			r#"{"tracer": "prestateTracer", "tracerConfig": { "diffMode": true }}"#,
+			TracerConfig::PrestateTracer { diff_mode: true },
+		),
+		(r#"{}"#, TracerConfig::StructLogger { limit: 0 }),
+		(r#"{"limit": 10, "disableStack": true}"#, TracerConfig::StructLogger { limit: 10 }),
 	];
 
 	for (json_data, expected) in tracers {
@@ -207,6 +251,91 @@ pub struct CallLog {
 	pub position: u32,
 }
 
+/// The trace of a transaction, as reported by the configured tracer.
+#[derive(TypeInfo, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Trace {
+	/// A call trace, see [`TracerConfig::CallTracer`].
+	Call(CallTrace),
+	/// A prestate trace, see [`TracerConfig::PrestateTracer`].
+	Prestate(PrestateTrace),
+	/// A struct logger trace, see [`TracerConfig::StructLogger`].
+	StructLogger(StructLoggerTrace),
+}
+
+/// The state of the accounts touched by a transaction.
+#[derive(TypeInfo, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+	/// The state of the modified accounts, before and after the transaction.
+	DiffMode {
+		/// The state before the transaction.
+		pre: BTreeMap<H160, PrestateTraceInfo>,
+		/// The modified state after the transaction.
+		post: BTreeMap<H160, PrestateTraceInfo>,
+	},
+	/// The state of the touched accounts before the transaction.
+	Prestate(BTreeMap<H160, PrestateTraceInfo>),
+}
+
+/// The state of an account.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct PrestateTraceInfo {
+	/// The balance of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub balance: Option<U256>,
+	/// The nonce of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<u64>,
+	/// The code of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<Bytes>,
+	/// The storage of the account, `None` values are unset slots.
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub storage: BTreeMap<Bytes, Option<Bytes>>,
+}
+
+/// A trace of every instruction executed by a transaction.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct StructLoggerTrace {
+	/// The gas used by the transaction.
+	pub gas: U256,
+	/// Whether the transaction failed.
+	pub failed: bool,
+	/// The return data of the transaction.
+	#[serde(rename = "returnValue")]
+	pub return_value: Bytes,
+	/// The executed instructions.
+	#[serde(rename = "structLogs")]
+	pub struct_logs: Vec<StructLog>,
+}
+
+/// An instruction executed by a contract.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct StructLog {
+	/// The program counter of the instruction.
+	pub pc: u32,
+	/// The name of the syscall, if the instruction is an `ecalli`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub op: Option<String>,
+	/// The PolkaVM gas left before the instruction.
+	pub gas: u64,
+	/// The PolkaVM gas consumed by the instruction.
+	#[serde(rename = "gasCost")]
+	pub gas_cost: u64,
+	/// The depth of the call executing the instruction.
+	pub depth: u32,
+	/// The error message, if the instruction failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
 /// A transaction trace
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TransactionTrace {
@@ -215,5 +344,5 @@ pub struct TransactionTrace {
 	pub tx_hash: H256,
 	/// The trace of the transaction.
 	#[serde(rename = "result")]
-	pub trace: CallTrace,
+	pub trace: Trace,
 }
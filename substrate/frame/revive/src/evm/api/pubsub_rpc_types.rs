@@ -0,0 +1,49 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Types used by the `eth_subscribe` JSON-RPC subscription.
+use crate::evm::{Block, Log};
+use codec::{Decode, Encode};
+use derive_more::From;
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+
+/// The kind of events an `eth_subscribe` subscription is notified about.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq)]
+pub enum SubscriptionKind {
+	/// New block headers, as blocks are imported.
+	#[serde(rename = "newHeads")]
+	NewHeads,
+	/// Logs included in new imported blocks, matching an optional filter.
+	#[serde(rename = "logs")]
+	Logs,
+	/// Hashes of the transactions added to the pending state.
+	#[serde(rename = "newPendingTransactions")]
+	NewPendingTransactions,
+}
+
+/// An item pushed to an `eth_subscribe` subscription.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum SubscriptionItem {
+	/// A new block header, sent to `newHeads` subscriptions.
+	Header(Block),
+	/// A log, sent to `logs` subscriptions.
+	Log(Log),
+	/// A transaction hash, sent to `newPendingTransactions` subscriptions.
+	TransactionHash(H256),
+}
@@ -25,6 +25,23 @@ pub use ethereum_types::*;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 
+/// Address(es)
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum AddressOrAddresses {
+	/// Address
+	Address(Address),
+	/// Addresses
+	Addresses(Addresses),
+}
+impl Default for AddressOrAddresses {
+	fn default() -> Self {
+		AddressOrAddresses::Address(Default::default())
+	}
+}
+
 /// Block object
 #[derive(
 	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
@@ -137,6 +154,65 @@ impl Default for BlockNumberOrTagOrHash {
 	}
 }
 
+/// Fee history results
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeeHistoryResult {
+	/// Array of block base fees per gas. This includes the next block after the newest of the
+	/// returned range, because this value can be derived from the newest block. Zeroes are
+	/// returned for pre-EIP-1559 blocks.
+	#[serde(rename = "baseFeePerGas")]
+	pub base_fee_per_gas: Vec<U256>,
+	/// Array of block gas used ratios. These are calculated as the ratio of gasUsed and gasLimit.
+	#[serde(rename = "gasUsedRatio")]
+	pub gas_used_ratio: Vec<f64>,
+	/// Lowest number block of the returned range.
+	#[serde(rename = "oldestBlock")]
+	pub oldest_block: U256,
+	/// A two-dimensional array of effective priority fees per gas at the requested block
+	/// percentiles.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reward: Option<Vec<Vec<U256>>>,
+}
+
+/// Filter
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct Filter {
+	/// Address(es)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub address: Option<AddressOrAddresses>,
+	/// Block hash, mutually exclusive with `fromBlock` and `toBlock`
+	#[serde(rename = "blockHash", skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<H256>,
+	/// from block
+	#[serde(rename = "fromBlock", skip_serializing_if = "Option::is_none")]
+	pub from_block: Option<BlockNumberOrTag>,
+	/// to block
+	#[serde(rename = "toBlock", skip_serializing_if = "Option::is_none")]
+	pub to_block: Option<BlockNumberOrTag>,
+	/// Topics
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub topics: Option<FilterTopics>,
+}
+
+/// Filter results
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterResults {
+	/// new block or transaction hashes
+	Hashes(Vec<H256>),
+	/// new logs
+	Logs(Vec<Log>),
+}
+impl Default for FilterResults {
+	fn default() -> Self {
+		FilterResults::Hashes(Default::default())
+	}
+}
+
 /// Transaction object generic to all types
 #[derive(
 	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
@@ -326,6 +402,13 @@ impl Default for TransactionUnsigned {
 /// Access list
 pub type AccessList = Vec<AccessListEntry>;
 
+/// Addresses
+pub type Addresses = Vec<Address>;
+
+/// Filter Topics
+/// A `null` entry matches any topic at that position.
+pub type FilterTopics = Vec<Option<FilterTopic>>;
+
 /// Block tag
 /// `earliest`: The lowest numbered block the client has available; `finalized`: The most recent
 /// crypto-economically secure block, cannot be re-orged outside of manual intervention driven by
@@ -353,6 +436,23 @@ pub enum BlockTag {
 	Pending,
 }
 
+/// Filter Topic List Entry
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterTopic {
+	/// Single Topic Match
+	Single(H256),
+	/// Multiple Topic Match
+	Multiple(Vec<H256>),
+}
+impl Default for FilterTopic {
+	fn default() -> Self {
+		FilterTopic::Single(Default::default())
+	}
+}
+
 #[derive(
 	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
 )]
@@ -15,120 +15,103 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
-	evm::{extract_revert_message, CallLog, CallTrace, CallType},
-	primitives::ExecReturnValue,
-	tracing::Tracer,
-	DispatchError, Weight,
+	evm::{CallTracer, Trace, TracerConfig},
+	tracing::{trace, Tracer},
+	Config, EthTransactError, Pallet, Weight,
 };
-use alloc::{format, string::ToString, vec::Vec};
-use sp_core::{H160, H256, U256};
+use sp_core::U256;
+use sp_runtime::traits::Block as BlockT;
 
-/// A Tracer that reports logs and nested call traces transactions.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
-pub struct CallTracer<Gas, GasMapper> {
-	/// Map Weight to Gas equivalent.
-	gas_mapper: GasMapper,
-	/// Store all in-progress CallTrace instances.
-	traces: Vec<CallTrace<Gas>>,
-	/// Stack of indices to the current active traces.
-	current_stack: Vec<usize>,
-	/// whether or not to capture logs.
-	with_log: bool,
-}
+mod call_tracing;
+pub use call_tracing::*;
 
-impl<Gas, GasMapper> CallTracer<Gas, GasMapper> {
-	/// Create a new [`CallTracer`] instance.
-	pub fn new(with_log: bool, gas_mapper: GasMapper) -> Self {
-		Self { gas_mapper, traces: Vec::new(), current_stack: Vec::new(), with_log }
-	}
+mod prestate_tracing;
+pub use prestate_tracing::*;
 
-	/// Collect the traces and return them.
-	pub fn collect_traces(&mut self) -> Vec<CallTrace<Gas>> {
-		core::mem::take(&mut self.traces)
-	}
-}
+mod struct_logger;
+pub use struct_logger::*;
 
-impl<Gas: Default, GasMapper: Fn(Weight) -> Gas> Tracer for CallTracer<Gas, GasMapper> {
-	fn enter_child_span(
-		&mut self,
-		from: H160,
-		to: H160,
-		is_delegate_call: bool,
-		is_read_only: bool,
-		value: U256,
-		input: &[u8],
-		gas_left: Weight,
-	) {
-		let call_type = if is_read_only {
-			CallType::StaticCall
-		} else if is_delegate_call {
-			CallType::DelegateCall
-		} else {
-			CallType::Call
-		};
+/// A function mapping the weight of an execution to its gas equivalent.
+pub type GasMapper = fn(Weight) -> U256;
 
-		self.traces.push(CallTrace {
-			from,
-			to,
-			value,
-			call_type,
-			input: input.to_vec(),
-			gas: (self.gas_mapper)(gas_left),
-			..Default::default()
-		});
+/// The tracers that can be configured with a [`TracerConfig`].
+pub enum EvmTracer<T: Config> {
+	/// A tracer reporting nested call traces, see [`CallTracer`].
+	CallTracer(CallTracer<U256, GasMapper>),
+	/// A tracer reporting the state of the touched accounts, see [`PrestateTracer`].
+	PrestateTracer(PrestateTracer<T>),
+	/// A tracer reporting every executed instruction, see [`StructLogger`].
+	StructLogger(StructLogger<GasMapper>),
+}
 
-		// Push the index onto the stack of the current active trace
-		self.current_stack.push(self.traces.len() - 1);
+impl<T: Config> EvmTracer<T> {
+	/// Build the tracer associated to the given config.
+	pub fn new(config: TracerConfig) -> Self {
+		let gas_mapper: GasMapper = Pallet::<T>::evm_gas_from_weight;
+		match config {
+			TracerConfig::CallTracer { with_logs } =>
+				Self::CallTracer(CallTracer::new(with_logs, gas_mapper)),
+			TracerConfig::PrestateTracer { diff_mode } =>
+				Self::PrestateTracer(PrestateTracer::new(diff_mode)),
+			TracerConfig::StructLogger { limit } =>
+				Self::StructLogger(StructLogger::new(limit, gas_mapper)),
+		}
 	}
 
-	fn log_event(&mut self, address: H160, topics: &[H256], data: &[u8]) {
-		if !self.with_log {
-			return;
+	/// Get the tracer, to be passed to [`crate::tracing::trace`].
+	pub fn as_tracing(&mut self) -> &mut (dyn Tracer + 'static) {
+		match self {
+			Self::CallTracer(tracer) => tracer,
+			Self::PrestateTracer(tracer) => tracer,
+			Self::StructLogger(tracer) => tracer,
 		}
-
-		let current_index = self.current_stack.last().unwrap();
-		let position = self.traces[*current_index].calls.len() as u32;
-		let log =
-			CallLog { address, topics: topics.to_vec(), data: data.to_vec().into(), position };
-
-		let current_index = *self.current_stack.last().unwrap();
-		self.traces[current_index].logs.push(log);
 	}
 
-	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight) {
-		// Set the output of the current trace
-		let current_index = self.current_stack.pop().unwrap();
-		let trace = &mut self.traces[current_index];
-		trace.output = output.data.clone().into();
-		trace.gas_used = (self.gas_mapper)(gas_used);
-
-		if output.did_revert() {
-			trace.revert_reason = extract_revert_message(&output.data);
-			trace.error = Some("execution reverted".to_string());
+	/// Collect the trace of the traced execution.
+	///
+	/// Returns `None` if nothing was traced.
+	pub fn collect_trace(&mut self) -> Option<Trace> {
+		match self {
+			Self::CallTracer(tracer) => tracer.collect_traces().pop().map(Trace::Call),
+			Self::PrestateTracer(tracer) => tracer.collect_trace().map(Trace::Prestate),
+			Self::StructLogger(tracer) => tracer.collect_trace().map(Trace::StructLogger),
 		}
+	}
+}
 
-		//  Move the current trace into its parent
-		if let Some(parent_index) = self.current_stack.last() {
-			let child_trace = self.traces.remove(current_index);
-			self.traces[*parent_index].calls.push(child_trace);
+/// Trace the transaction at `tx_index` of the given block, see [`crate::ReviveApi::trace_tx`].
+///
+/// The block is executed with `initialize_block` and `apply_extrinsic`, usually the ones of the
+/// runtime's `Executive`, and must therefore be executed on top of its parent block state.
+pub fn trace_tx<T: Config, Block: BlockT>(
+	block: Block,
+	tx_index: u32,
+	config: TracerConfig,
+	initialize_block: impl FnOnce(&Block::Header),
+	mut apply_extrinsic: impl FnMut(Block::Extrinsic),
+) -> Option<Trace> {
+	let mut tracer = config.build::<T>();
+	let (header, extrinsics) = block.deconstruct();
+	initialize_block(&header);
+	for (index, ext) in extrinsics.into_iter().enumerate() {
+		if index as u32 == tx_index {
+			trace(tracer.as_tracing(), || apply_extrinsic(ext));
+			return tracer.collect_trace();
 		}
+		apply_extrinsic(ext);
 	}
-	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_used: Weight) {
-		// Set the output of the current trace
-		let current_index = self.current_stack.pop().unwrap();
-		let trace = &mut self.traces[current_index];
-		trace.gas_used = (self.gas_mapper)(gas_used);
 
-		trace.error = match error {
-			DispatchError::Module(sp_runtime::ModuleError { message, .. }) =>
-				Some(message.unwrap_or_default().to_string()),
-			_ => Some(format!("{:?}", error)),
-		};
+	None
+}
 
-		//  Move the current trace into its parent
-		if let Some(parent_index) = self.current_stack.last() {
-			let child_trace = self.traces.remove(current_index);
-			self.traces[*parent_index].calls.push(child_trace);
-		}
-	}
+/// Trace an Ethereum call, see [`crate::ReviveApi::trace_call`].
+///
+/// `eth_transact` is usually the runtime's implementation of [`crate::ReviveApi::eth_transact`].
+pub fn trace_call<T: Config, R>(
+	config: TracerConfig,
+	eth_transact: impl FnOnce() -> Result<R, EthTransactError>,
+) -> Result<Trace, EthTransactError> {
+	let mut tracer = config.build::<T>();
+	trace(tracer.as_tracing(), eth_transact)?;
+	tracer.collect_trace().ok_or(EthTransactError::Message("Empty trace".into()))
 }
@@ -0,0 +1,207 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{Bytes, PrestateTrace, PrestateTraceInfo},
+	primitives::ExecReturnValue,
+	tracing::Tracer,
+	AddressMapper, Config, ContractInfoOf, DispatchError, Pallet, PristineCode, System, Weight,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::marker::PhantomData;
+use sp_core::{H160, H256, U256};
+use sp_runtime::SaturatedConversion;
+
+/// A Tracer that reports the state of the accounts touched by a transaction.
+///
+/// In diff mode, only the modified accounts are reported, with their state before and after
+/// the execution.
+pub struct PrestateTracer<T> {
+	/// Whether to report the state before and after the execution.
+	diff_mode: bool,
+	/// The state of the touched accounts, before the execution.
+	pre: BTreeMap<H160, PrestateTraceInfo>,
+	/// The last value written to each storage slot of the touched accounts, one layer per entered
+	/// call, so that the writes of a reverted call can be discarded.
+	writes: Vec<BTreeMap<H160, BTreeMap<Bytes, Option<Bytes>>>>,
+	_phantom: PhantomData<T>,
+}
+
+impl<T: Config> PrestateTracer<T> {
+	/// Create a new [`PrestateTracer`] instance.
+	pub fn new(diff_mode: bool) -> Self {
+		Self {
+			diff_mode,
+			pre: BTreeMap::new(),
+			writes: vec![BTreeMap::new()],
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Collect the trace and return it.
+	///
+	/// This reads the current state of the touched accounts, so it must be called right after the
+	/// traced execution.
+	pub fn collect_trace(&mut self) -> Option<PrestateTrace> {
+		let pre = core::mem::take(&mut self.pre);
+		while self.writes.len() > 1 {
+			self.commit_writes();
+		}
+		let writes = core::mem::replace(&mut self.writes, vec![BTreeMap::new()])
+			.pop()
+			.unwrap_or_default();
+		if pre.is_empty() {
+			return None;
+		}
+
+		if !self.diff_mode {
+			return Some(PrestateTrace::Prestate(pre));
+		}
+
+		let mut diff_pre = BTreeMap::new();
+		let mut diff_post = BTreeMap::new();
+		for (address, mut pre_info) in pre {
+			let current = Self::account_info(&address);
+			let mut post_info = PrestateTraceInfo::default();
+
+			if current.balance != pre_info.balance {
+				post_info.balance = current.balance;
+			}
+			if current.nonce != pre_info.nonce {
+				post_info.nonce = current.nonce;
+			}
+			if current.code != pre_info.code {
+				post_info.code = current.code;
+			}
+
+			let account_writes = writes.get(&address);
+			pre_info.storage.retain(|key, value| {
+				match account_writes.and_then(|writes| writes.get(key)) {
+					Some(new_value) if new_value != value => {
+						post_info.storage.insert(key.clone(), new_value.clone());
+						true
+					},
+					_ => false,
+				}
+			});
+
+			if post_info != PrestateTraceInfo::default() {
+				diff_pre.insert(address, pre_info);
+				diff_post.insert(address, post_info);
+			}
+		}
+
+		Some(PrestateTrace::DiffMode { pre: diff_pre, post: diff_post })
+	}
+
+	/// Read the current state of the given account, without its storage.
+	fn account_info(address: &H160) -> PrestateTraceInfo {
+		let account = T::AddressMapper::to_account_id(address);
+		let code = ContractInfoOf::<T>::get(address)
+			.and_then(|info| PristineCode::<T>::get(info.code_hash))
+			.map(|code| Bytes(code.into_inner()));
+
+		PrestateTraceInfo {
+			balance: Some(Pallet::<T>::evm_balance(address)),
+			nonce: Some(System::<T>::account_nonce(&account).saturated_into()),
+			code,
+			storage: BTreeMap::new(),
+		}
+	}
+
+	/// Record the state of the given account, the first time it is touched.
+	fn touch(&mut self, address: H160) {
+		self.pre.entry(address).or_insert_with(|| Self::account_info(&address));
+	}
+
+	/// Merge the writes of the exited call into the ones of its caller.
+	fn commit_writes(&mut self) {
+		let Some(exited) = self.writes.pop() else { return };
+		let Some(caller) = self.writes.last_mut() else {
+			self.writes.push(exited);
+			return;
+		};
+		for (address, slots) in exited {
+			caller.entry(address).or_default().extend(slots);
+		}
+	}
+
+	/// Discard the writes of the exited call, which were rolled back.
+	fn revert_writes(&mut self) {
+		if self.writes.len() > 1 {
+			self.writes.pop();
+		}
+	}
+}
+
+impl<T: Config> Tracer for PrestateTracer<T> {
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas: Weight,
+	) {
+		self.touch(from);
+		self.touch(to);
+		self.writes.push(BTreeMap::new());
+	}
+
+	fn watch_address(&mut self, address: &H160) {
+		self.touch(*address);
+	}
+
+	fn log_event(&mut self, _address: H160, _topics: &[H256], _data: &[u8]) {}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, _gas_used: Weight) {
+		if output.did_revert() {
+			self.revert_writes();
+		} else {
+			self.commit_writes();
+		}
+	}
+
+	fn exit_child_span_with_error(&mut self, _error: DispatchError, _gas_used: Weight) {
+		self.revert_writes();
+	}
+
+	fn storage_read(&mut self, address: &H160, key: &[u8], value: Option<&[u8]>) {
+		self.touch(*address);
+		let info = self.pre.get_mut(address).expect("account was just touched; qed");
+		info.storage
+			.entry(Bytes(key.to_vec()))
+			.or_insert_with(|| value.map(|value| Bytes(value.to_vec())));
+	}
+
+	fn storage_write(
+		&mut self,
+		address: &H160,
+		key: &[u8],
+		old_value: Option<&[u8]>,
+		new_value: Option<&[u8]>,
+	) {
+		self.storage_read(address, key, old_value);
+		self.writes
+			.last_mut()
+			.expect("the base layer is never removed; qed")
+			.entry(*address)
+			.or_default()
+			.insert(Bytes(key.to_vec()), new_value.map(|value| Bytes(value.to_vec())));
+	}
+}
@@ -0,0 +1,141 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{StructLog, StructLoggerTrace},
+	primitives::ExecReturnValue,
+	tracing::Tracer,
+	DispatchError, Weight,
+};
+use alloc::{format, string::String, vec::Vec};
+use sp_core::{H160, H256, U256};
+
+/// A Tracer that reports every instruction executed by the contracts.
+///
+/// The gas of the individual steps is reported in PolkaVM gas units, while the gas used by the
+/// whole execution is mapped to its Ethereum equivalent.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct StructLogger<GasMapper> {
+	/// Map Weight to Gas equivalent.
+	gas_mapper: GasMapper,
+	/// The maximum number of steps to record, or `0` for no limit.
+	limit: u64,
+	/// The depth of the current call.
+	depth: u32,
+	/// The recorded steps.
+	struct_logs: Vec<StructLog>,
+	/// The trace of the top-level call, once it has been executed.
+	trace: Option<StructLoggerTrace>,
+}
+
+impl<GasMapper> StructLogger<GasMapper> {
+	/// Create a new [`StructLogger`] instance.
+	pub fn new(limit: u64, gas_mapper: GasMapper) -> Self {
+		Self { gas_mapper, limit, depth: 0, struct_logs: Vec::new(), trace: None }
+	}
+
+	/// Collect the trace and return it.
+	pub fn collect_trace(&mut self) -> Option<StructLoggerTrace> {
+		let mut trace = self.trace.take()?;
+		trace.struct_logs = core::mem::take(&mut self.struct_logs);
+		Some(trace)
+	}
+
+	/// Record a new step, unless the limit has been reached.
+	fn push(&mut self, program_counter: u32, gas_left: u64, op: Option<String>) {
+		// The cost of the previous step is only known once the next one starts.
+		if let Some(last) = self.struct_logs.last_mut().filter(|last| last.depth == self.depth) {
+			last.gas_cost = last.gas.saturating_sub(gas_left);
+		}
+
+		if self.limit != 0 && self.struct_logs.len() as u64 >= self.limit {
+			return;
+		}
+
+		self.struct_logs.push(StructLog {
+			pc: program_counter,
+			op,
+			gas: gas_left,
+			gas_cost: 0,
+			depth: self.depth,
+			error: None,
+		});
+	}
+
+	/// Leave the current call, recording the trace if it is the top-level call.
+	fn exit(&mut self, gas: U256, failed: bool, return_value: Vec<u8>) {
+		if self.depth == 1 {
+			self.trace = Some(StructLoggerTrace {
+				gas,
+				failed,
+				return_value: return_value.into(),
+				struct_logs: Vec::new(),
+			});
+		}
+		self.depth = self.depth.saturating_sub(1);
+	}
+}
+
+impl<GasMapper: Fn(Weight) -> U256> Tracer for StructLogger<GasMapper> {
+	fn enter_child_span(
+		&mut self,
+		_from: H160,
+		_to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas: Weight,
+	) {
+		self.depth += 1;
+	}
+
+	fn log_event(&mut self, _address: H160, _topics: &[H256], _data: &[u8]) {}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight) {
+		self.exit((self.gas_mapper)(gas_used), output.did_revert(), output.data.clone());
+	}
+
+	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_used: Weight) {
+		if let Some(last) = self.struct_logs.last_mut().filter(|last| last.depth == self.depth) {
+			last.error = Some(match error {
+				DispatchError::Module(sp_runtime::ModuleError { message, .. }) =>
+					message.unwrap_or_default().into(),
+				_ => format!("{:?}", error),
+			});
+		}
+		self.exit((self.gas_mapper)(gas_used), true, Vec::new());
+	}
+
+	fn is_step_tracing_enabled(&self) -> bool {
+		true
+	}
+
+	fn step(&mut self, program_counter: u32, gas_left: u64) {
+		self.push(program_counter, gas_left, None);
+	}
+
+	fn syscall(&mut self, program_counter: u32, gas_left: u64, name: &[u8]) {
+		let name = String::from_utf8_lossy(name).into_owned();
+		match self.struct_logs.last_mut() {
+			// The step of the `ecalli` instruction has already been recorded.
+			Some(last)
+				if last.pc == program_counter && last.depth == self.depth && last.op.is_none() =>
+				last.op = Some(name),
+			_ => self.push(program_counter, gas_left, Some(name)),
+		}
+	}
+}
@@ -82,8 +82,7 @@ impl Key {
 	///
 	/// # Note
 	///
-	/// Only used by benchmarking in order to generate storage collisions on purpose.
-	#[cfg(feature = "runtime-benchmarks")]
+	/// Used by benchmarking in order to generate storage collisions on purpose, and by tracers.
 	pub fn unhashed(&self) -> &[u8] {
 		match self {
 			Key::Fix(v) => v.as_ref(),
@@ -1019,6 +1018,19 @@ where
 
 		let do_transaction = || -> ExecResult {
 			let caller = self.caller();
+
+			// The accounts are modified below, before the call is entered.
+			if_tracing(|tracer| {
+				let accounts = [
+					self.origin.account_id().ok(),
+					caller.account_id().ok(),
+					Some(&self.top_frame().account_id),
+				];
+				for account in accounts.into_iter().flatten() {
+					tracer.watch_address(&T::AddressMapper::to_address(account));
+				}
+			});
+
 			let frame = top_frame_mut!(self);
 			let read_only = frame.read_only;
 			let value_transferred = frame.value_transferred;
@@ -1524,7 +1536,12 @@ where
 	}
 
 	fn get_storage(&mut self, key: &Key) -> Option<Vec<u8>> {
-		self.top_frame_mut().contract_info().read(key)
+		let value = self.top_frame_mut().contract_info().read(key);
+		if_tracing(|tracer| {
+			let address = T::AddressMapper::to_address(self.account_id());
+			tracer.storage_read(&address, key.unhashed(), value.as_deref());
+		});
+		value
 	}
 
 	fn get_storage_size(&mut self, key: &Key) -> Option<u32> {
@@ -1538,6 +1555,11 @@ where
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
 		let frame = self.top_frame_mut();
+		if_tracing(|tracer| {
+			let address = T::AddressMapper::to_address(&frame.account_id);
+			let old_value = frame.contract_info.get(&frame.account_id).read(key);
+			tracer.storage_write(&address, key.unhashed(), old_value.as_deref(), value.as_deref());
+		});
 		frame.contract_info.get(&frame.account_id).write(
 			key.into(),
 			value,
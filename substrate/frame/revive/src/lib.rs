@@ -1334,6 +1334,11 @@ where
 		}
 	}
 
+	/// Convert a weight into its EVM gas equivalent, using the fixed `GAS_PRICE`.
+	pub fn evm_gas_from_weight(weight: Weight) -> U256 {
+		Self::evm_fee_to_gas(T::WeightPrice::convert(weight))
+	}
+
 	pub fn evm_block_gas_limit() -> U256 {
 		let max_block_weight = T::BlockWeights::get()
 			.get(DispatchClass::Normal)
@@ -1441,7 +1446,7 @@ environmental!(executing_contract: bool);
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ReviveApi<AccountId, Balance, Nonce, BlockNumber> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1488,6 +1493,26 @@ sp_api::decl_runtime_apis! {
 		/// See [`crate::Pallet::bare_eth_transact`]
 		fn eth_transact(tx: GenericTransaction) -> Result<EthTransactInfo<Balance>, EthTransactError>;
 
+		/// Trace the execution of the transaction at `tx_index` in the given block.
+		///
+		/// The block must be executed on top of its parent block state. Returns `None` if the
+		/// transaction does not exist or did not call into pallet-revive.
+		#[api_version(2)]
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: evm::TracerConfig,
+		) -> Option<evm::Trace>;
+
+		/// Trace the execution of an Ethereum call.
+		///
+		/// See [`crate::Pallet::bare_eth_transact`]
+		#[api_version(2)]
+		fn trace_call(
+			tx: GenericTransaction,
+			config: evm::TracerConfig,
+		) -> Result<evm::Trace, EthTransactError>;
+
 		/// Upload new code without instantiating a contract from it.
 		///
 		/// See [`crate::Pallet::bare_upload_code`].
@@ -4568,3 +4568,90 @@ fn tracing_works() {
 		}
 	});
 }
+
+#[test]
+fn prestate_tracing_works() {
+	use crate::{evm::*, primitives::ExecReturnValue, tracing::Tracer};
+	let (code, _code_hash) = compile_module("store_call").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code.clone())).build_and_unwrap_contract();
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		let key = Bytes(key.to_vec());
+
+		// Without diff mode, the state of all touched accounts is reported.
+		let mut tracer = PrestateTracer::<Test>::new(false);
+		trace(&mut tracer, || {
+			builder::bare_call(addr).data(4u32.encode()).build_and_unwrap_result()
+		});
+		let Some(PrestateTrace::Prestate(pre)) = tracer.collect_trace() else {
+			panic!("expected a prestate trace")
+		};
+		assert_eq!(pre.len(), 2);
+		assert_eq!(pre[&ALICE_ADDR].code, None);
+		assert_eq!(pre[&addr].code, Some(Bytes(code.clone())));
+		assert_eq!(pre[&addr].storage.get(&key), Some(&None));
+
+		// In diff mode, only the modified state is reported, as it was before the value transfer.
+		let balance = Pallet::<Test>::evm_balance(&addr);
+		let mut tracer = PrestateTracer::<Test>::new(true);
+		trace(&mut tracer, || {
+			builder::bare_call(addr)
+				.value(1_000)
+				.data(8u32.encode())
+				.build_and_unwrap_result()
+		});
+		let Some(PrestateTrace::DiffMode { pre, post }) = tracer.collect_trace() else {
+			panic!("expected a diff mode trace")
+		};
+		assert_eq!(pre[&addr].balance, Some(balance));
+		assert_eq!(post[&addr].balance, Some(Pallet::<Test>::evm_balance(&addr)));
+		assert_ne!(post[&addr].balance, Some(balance));
+		assert_eq!(pre[&addr].storage.get(&key), Some(&Some(Bytes(vec![0u8; 4]))));
+		assert_eq!(post[&addr].storage.get(&key), Some(&Some(Bytes(vec![0u8; 8]))));
+		assert_eq!(post[&addr].code, None);
+
+		// The writes of a reverted call are not reported.
+		let mut tracer = PrestateTracer::<Test>::new(true);
+		tracer.enter_child_span(ALICE_ADDR, addr, false, false, U256::zero(), &[], Weight::zero());
+		tracer.storage_write(&addr, &key.0, Some(&[0u8; 8]), Some(&[1u8; 4]));
+		tracer.exit_child_span(
+			&ExecReturnValue { flags: ReturnFlags::REVERT, data: vec![] },
+			Weight::zero(),
+		);
+		assert_matches!(
+			tracer.collect_trace(),
+			Some(PrestateTrace::DiffMode { post, .. }) if post.is_empty()
+		);
+	});
+}
+
+#[test]
+fn struct_logger_works() {
+	use crate::evm::*;
+	let (code, _code_hash) = compile_module("store_call").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let mut tracer = StructLogger::new(0, |_| U256::zero());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).data(4u32.encode()).build_and_unwrap_result()
+		});
+		let trace = tracer.collect_trace().unwrap();
+		assert!(!trace.failed);
+		assert!(trace.struct_logs.iter().all(|log| log.depth == 1));
+		assert!(trace.struct_logs.iter().any(|log| log.op.as_deref() == Some("set_storage")));
+
+		// The number of recorded steps is capped by the limit.
+		let mut tracer = StructLogger::new(3, |_| U256::zero());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).data(4u32.encode()).build_and_unwrap_result()
+		});
+		assert_eq!(tracer.collect_trace().unwrap().struct_logs.len(), 3);
+	});
+}
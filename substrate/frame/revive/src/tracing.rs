@@ -41,6 +41,11 @@ pub(crate) fn if_tracing<F: FnOnce(&mut (dyn Tracer + 'static))>(f: F) {
 
 /// Defines methods to trace contract interactions.
 pub trait Tracer {
+	/// Called before the balance or nonce of `address` may be modified by a contract call.
+	///
+	/// Unlike [`Self::enter_child_span`], this is called before the value transfer of the call.
+	fn watch_address(&mut self, _address: &H160) {}
+
 	/// Called before a contract call is executed
 	fn enter_child_span(
 		&mut self,
@@ -61,4 +66,31 @@ pub trait Tracer {
 
 	/// Called when a contract call terminates with an error
 	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_left: Weight);
+
+	/// Called when the persistent storage of `address` is read.
+	fn storage_read(&mut self, _address: &H160, _key: &[u8], _value: Option<&[u8]>) {}
+
+	/// Called before the persistent storage of `address` is written.
+	fn storage_write(
+		&mut self,
+		_address: &H160,
+		_key: &[u8],
+		_old_value: Option<&[u8]>,
+		_new_value: Option<&[u8]>,
+	) {
+	}
+
+	/// Whether [`Self::step`] should be called for every executed instruction.
+	///
+	/// Step tracing slows down execution considerably, it is therefore only enabled for the
+	/// tracers that need it.
+	fn is_step_tracing_enabled(&self) -> bool {
+		false
+	}
+
+	/// Called before an instruction is executed, when step tracing is enabled.
+	fn step(&mut self, _program_counter: u32, _gas_left: u64) {}
+
+	/// Called before a syscall is executed.
+	fn syscall(&mut self, _program_counter: u32, _gas_left: u64, _name: &[u8]) {}
 }
@@ -24,6 +24,7 @@ use crate::{
 	gas::{ChargedAmount, Token},
 	limits,
 	primitives::ExecReturnValue,
+	tracing::if_tracing,
 	weights::WeightInfo,
 	Config, Error, LOG_TARGET, SENTINEL,
 };
@@ -143,6 +144,7 @@ pub trait Memory<T: Config> {
 pub trait PolkaVmInstance<T: Config>: Memory<T> {
 	fn gas(&self) -> polkavm::Gas;
 	fn set_gas(&mut self, gas: polkavm::Gas);
+	fn program_counter(&self) -> Option<u32>;
 	fn read_input_regs(&self) -> (u64, u64, u64, u64, u64, u64);
 	fn write_output(&mut self, output: u64);
 }
@@ -201,6 +203,10 @@ impl<T: Config> PolkaVmInstance<T> for polkavm::RawInstance {
 		self.set_gas(gas)
 	}
 
+	fn program_counter(&self) -> Option<u32> {
+		self.program_counter().map(|pc| pc.0)
+	}
+
 	fn read_input_regs(&self) -> (u64, u64, u64, u64, u64, u64) {
 		(
 			self.reg(polkavm::Reg::A0),
@@ -599,11 +605,25 @@ impl<'a, E: Ext, M: PolkaVmInstance<E::T>> Runtime<'a, E, M> {
 			Ok(Trap) => Some(Err(Error::<E::T>::ContractTrapped.into())),
 			Ok(Segfault(_)) => Some(Err(Error::<E::T>::ExecutionFailed.into())),
 			Ok(NotEnoughGas) => Some(Err(Error::<E::T>::OutOfGas.into())),
-			Ok(Step) => None,
+			Ok(Step) => {
+				if_tracing(|tracer| {
+					let program_counter = instance.program_counter().unwrap_or_default();
+					tracer.step(program_counter, instance.gas().max(0) as u64);
+				});
+				None
+			},
 			Ok(Ecalli(idx)) => {
 				let Some(syscall_symbol) = module.imports().get(idx) else {
 					return Some(Err(<Error<E::T>>::InvalidSyscall.into()));
 				};
+				if_tracing(|tracer| {
+					let program_counter = instance.program_counter().unwrap_or_default();
+					tracer.syscall(
+						program_counter,
+						instance.gas().max(0) as u64,
+						syscall_symbol.as_bytes(),
+					);
+				});
 				match self.handle_ecall(instance, syscall_symbol.as_bytes()) {
 					Ok(None) => None,
 					Ok(Some(return_value)) => {
@@ -27,6 +27,9 @@
 //! amount for any reason other than the ones specified in `UnvestedFundsAllowedWithdrawReasons`
 //! configuration value.
 //!
+//! Besides linear schedules, a schedule may unlock nothing before a cliff and then continue
+//! linearly, or unlock in steps at a fixed period. See [`VestingShape`].
+//!
 //! As the amount vested increases over time, the amount unvested reduces. However, locks remain in
 //! place and explicit action is needed on behalf of the user to ensure that the amount locked is
 //! equivalent to the amount remaining to be vested. This is done through a dispatchable function,
@@ -99,6 +102,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -231,7 +235,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -285,6 +289,8 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The shapes of the schedules to merge are not compatible.
+		ScheduleShapesNotMergeable,
 	}
 
 	#[pallet::call]
@@ -398,9 +404,13 @@ pub mod pallet {
 		///
 		/// Merged schedule attributes:
 		/// - `starting_block`: `MAX(schedule1.starting_block, scheduled2.starting_block,
-		///   current_block)`.
+		///   current_block)`, rounded up to the next unlock of a step schedule.
 		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
 		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
+		/// - `shape`: linear if both schedules are linear; a cliff at the latest cliff not reached
+		///   by the current block; or steps of the period of the step schedules. A cliff schedule
+		///   can't be merged with a step schedule, and two step schedules must unlock at the same
+		///   blocks.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
@@ -482,7 +492,7 @@ impl<T: Config> Pallet<T> {
 		now: BlockNumberFor<T>,
 		schedule1: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 		schedule2: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-	) -> Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+	) -> Result<Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, DispatchError> {
 		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let now_as_balance = T::BlockNumberToBalance::convert(now);
@@ -490,15 +500,19 @@ impl<T: Config> Pallet<T> {
 		// Check if one or both schedules have ended.
 		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
 			// If both schedules have ended, we don't merge and exit early.
-			(true, true) => return None,
+			(true, true) => return Ok(None),
 			// If one schedule has ended, we treat the one that has not ended as the new
 			// merged schedule.
-			(true, false) => return Some(schedule2),
-			(false, true) => return Some(schedule1),
+			(true, false) => return Ok(Some(schedule2)),
+			(false, true) => return Ok(Some(schedule1)),
 			// If neither schedule has ended don't exit early.
 			_ => {},
 		}
 
+		let mut starting_block =
+			now.max(schedule1.starting_block()).max(schedule2.starting_block());
+		let shape = Self::merge_vesting_shapes(now, &schedule1, &schedule2, &mut starting_block)?;
+
 		let locked = schedule1
 			.locked_at::<T::BlockNumberToBalance>(now)
 			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
@@ -510,7 +524,6 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let ending_block = schedule1_ending_block.max(schedule2_ending_block);
-		let starting_block = now.max(schedule1.starting_block()).max(schedule2.starting_block());
 
 		let per_block = {
 			let duration = ending_block
@@ -519,10 +532,79 @@ impl<T: Config> Pallet<T> {
 			(locked / duration).max(One::one())
 		};
 
-		let schedule = VestingInfo::new(locked, per_block, starting_block);
+		let schedule = VestingInfo::new_with_shape(locked, per_block, starting_block, shape);
 		debug_assert!(schedule.is_valid(), "merge_vesting_info schedule validation check failed");
 
-		Some(schedule)
+		Ok(Some(schedule))
+	}
+
+	// Pick the shape of the schedule merging `schedule1` and `schedule2`, such that it never
+	// unlocks more than both schedules together. The `starting_block` of the merged schedule is
+	// moved to the next unlock of a step schedule, so the merged schedule unlocks along with it.
+	fn merge_vesting_shapes(
+		now: BlockNumberFor<T>,
+		schedule1: &VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		schedule2: &VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		starting_block: &mut BlockNumberFor<T>,
+	) -> Result<VestingShape<BlockNumberFor<T>>, DispatchError> {
+		// A cliff that has been reached no longer restricts the schedule.
+		let shape_at = |shape| match shape {
+			VestingShape::Cliff { cliff_block } if cliff_block <= now => VestingShape::Linear,
+			VestingShape::Step { period } => VestingShape::Step { period: period.max(One::one()) },
+			shape => shape,
+		};
+
+		let step_phase = match (shape_at(schedule1.shape()), shape_at(schedule2.shape())) {
+			(VestingShape::Linear, VestingShape::Linear) => return Ok(VestingShape::Linear),
+			(VestingShape::Linear, VestingShape::Cliff { cliff_block }) |
+			(VestingShape::Cliff { cliff_block }, VestingShape::Linear) =>
+				return Ok(Self::cliff_or_linear(cliff_block, *starting_block)),
+			(
+				VestingShape::Cliff { cliff_block: cliff_block1 },
+				VestingShape::Cliff { cliff_block: cliff_block2 },
+			) => return Ok(Self::cliff_or_linear(cliff_block1.max(cliff_block2), *starting_block)),
+			(VestingShape::Step { period }, VestingShape::Linear) =>
+				(period, schedule1.starting_block()),
+			(VestingShape::Linear, VestingShape::Step { period }) =>
+				(period, schedule2.starting_block()),
+			(VestingShape::Step { period: period1 }, VestingShape::Step { period: period2 }) => {
+				// Both schedules must unlock at the same blocks.
+				let offset = schedule1
+					.starting_block()
+					.max(schedule2.starting_block())
+					.saturating_sub(schedule1.starting_block().min(schedule2.starting_block()));
+				ensure!(
+					period1 == period2 && (offset % period1).is_zero(),
+					Error::<T>::ScheduleShapesNotMergeable
+				);
+				(period1, schedule1.starting_block())
+			},
+			(VestingShape::Cliff { .. }, VestingShape::Step { .. }) |
+			(VestingShape::Step { .. }, VestingShape::Cliff { .. }) =>
+				return Err(Error::<T>::ScheduleShapesNotMergeable.into()),
+		};
+
+		// Move the start to the next unlock of the step schedule.
+		let (period, phase_start) = step_phase;
+		let elapsed = starting_block.saturating_sub(phase_start);
+		let periods =
+			elapsed / period + if (elapsed % period).is_zero() { Zero::zero() } else { One::one() };
+		*starting_block = phase_start.saturating_add(periods.saturating_mul(period));
+
+		Ok(VestingShape::Step { period })
+	}
+
+	// A cliff at `cliff_block` for a schedule starting at `starting_block`, unless the schedule
+	// starts after the cliff.
+	fn cliff_or_linear(
+		cliff_block: BlockNumberFor<T>,
+		starting_block: BlockNumberFor<T>,
+	) -> VestingShape<BlockNumberFor<T>> {
+		if cliff_block > starting_block {
+			VestingShape::Cliff { cliff_block }
+		} else {
+			VestingShape::Linear
+		}
 	}
 
 	// Execute a vested transfer from `source` to `target` with the given `schedule`.
@@ -538,29 +620,66 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		)?;
+		Self::can_add_vesting_info(target, &schedule)?;
 
 		T::Currency::transfer(source, target, schedule.locked(), ExistenceRequirement::AllowDeath)?;
 
 		// We can't let this fail because the currency transfer has already happened.
 		// Must be successful as it has been checked before.
 		// Better to return error on failure anyway.
-		let res = Self::add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::add_vesting_info(target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
 	}
 
+	// Add `schedule` to the schedules of `who`. Is a no-op if the amount to be vested is zero.
+	fn add_vesting_info(
+		who: &T::AccountId,
+		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		if schedule.locked().is_zero() {
+			return Ok(())
+		}
+
+		// Check for `per_block` or `locked` of 0, and an invalid shape.
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		};
+
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
+	// Ensure we can call `add_vesting_info` with `schedule` without error.
+	fn can_add_vesting_info(
+		who: &T::AccountId,
+		schedule: &VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		// Check for `per_block` or `locked` of 0, and an invalid shape.
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		}
+
+		ensure!(
+			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
+
+		Ok(())
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -660,7 +779,7 @@ impl<T: Config> Pallet<T> {
 					Self::report_schedule_updates(schedules.to_vec(), action);
 
 				let now = T::BlockNumberProvider::current_block_number();
-				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
+				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2)? {
 					// Merging created a new schedule so we:
 					// 1) need to add it to the accounts vesting schedule collection,
 					schedules.push(new_schedule);
@@ -723,29 +842,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(())
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		};
-
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::add_vesting_info(who, VestingInfo::new(locked, per_block, starting_block))
 	}
 
 	/// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -756,17 +853,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		// Check for `per_block` or `locked` of 0.
-		if !VestingInfo::new(locked, per_block, starting_block).is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		}
-
-		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
-			Error::<T>::AtMaxVestingSchedules
-		);
-
-		Ok(())
+		Self::can_add_vesting_info(who, &VestingInfo::new(locked, per_block, starting_block))
 	}
 
 	/// Remove a vesting schedule for a given account.
@@ -19,11 +19,31 @@
 
 use super::*;
 use alloc::vec;
+use frame_support::{storage_alias, traits::OnRuntimeUpgrade, Blake2_128Concat};
+
+/// A vesting schedule as stored before [`VestingShape`] was introduced, which is always linear.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingInfoV1<Balance, BlockNumber> {
+	/// Locked amount at genesis.
+	pub locked: Balance,
+	/// Amount that gets unlocked every block after `starting_block`.
+	pub per_block: Balance,
+	/// Starting block for unlocking(vesting).
+	pub starting_block: BlockNumber,
+}
 
 // Migration from single schedule to multiple schedules.
 pub mod v1 {
 	use super::*;
 
+	#[storage_alias]
+	pub(crate) type Vesting<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<VestingInfoV1<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+	>;
+
 	#[cfg(feature = "try-runtime")]
 	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
 		assert!(StorageVersion::<T>::get() == Releases::V0, "Storage version too high.");
@@ -41,12 +61,12 @@ pub mod v1 {
 	pub fn migrate<T: Config>() -> Weight {
 		let mut reads_writes = 0;
 
-		Vesting::<T>::translate::<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
+		Vesting::<T>::translate::<VestingInfoV1<BalanceOf<T>, BlockNumberFor<T>>, _>(
 			|_key, vesting_info| {
 				reads_writes += 1;
 				let v: Option<
 					BoundedVec<
-						VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+						VestingInfoV1<BalanceOf<T>, BlockNumberFor<T>>,
 						MaxVestingSchedulesGet<T>,
 					>,
 				> = vec![vesting_info].try_into().ok();
@@ -78,7 +98,7 @@ pub mod v1 {
 			for s in schedules {
 				// It is ok if this does not pass, but ideally pre-existing schedules would pass
 				// this validation logic so we can be more confident about edge cases.
-				if !s.is_valid() {
+				if !VestingInfo::new(s.locked, s.per_block, s.starting_block).is_valid() {
 					log::warn!(
 						target: "runtime::vesting",
 						"migration: A schedule does not pass new validation logic.",
@@ -94,3 +114,80 @@ pub mod v1 {
 		Ok(())
 	}
 }
+
+// Migration from linear schedules to schedules with a `VestingShape`.
+pub mod v2 {
+	use super::*;
+
+	/// Migrate all existing schedules to linear schedules. Only runs if the storage version is
+	/// `V1`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				log::info!(
+					target: "runtime::vesting",
+					"migration: Vesting storage version v2 migration should be removed.",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Vesting::<T>::translate::<
+				BoundedVec<
+					VestingInfoV1<BalanceOf<T>, BlockNumberFor<T>>,
+					MaxVestingSchedulesGet<T>,
+				>,
+				_,
+			>(|_key, schedules| {
+				translated.saturating_inc();
+				let schedules: Vec<_> = schedules
+					.into_iter()
+					.map(|s| VestingInfo::new(s.locked, s.per_block, s.starting_block))
+					.collect();
+				// Can't fail, the number of schedules is unchanged.
+				schedules.try_into().ok()
+			});
+			StorageVersion::<T>::put(Releases::V2);
+
+			log::info!(
+				target: "runtime::vesting",
+				"migration: Vesting storage version v2 migrated {} accounts.",
+				translated,
+			);
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Vesting::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| {
+				"the state parameter should be something that was generated by pre_upgrade"
+			})?;
+
+			ensure!(StorageVersion::<T>::get() == Releases::V2, "Storage version not updated.");
+			ensure!(
+				Vesting::<T>::iter_keys().count() as u32 == count,
+				"The number of accounts with schedules has changed."
+			);
+			ensure!(
+				Vesting::<T>::iter_values().flatten().all(|s| s.shape() == VestingShape::Linear),
+				"A migrated schedule is not linear."
+			);
+
+			log::debug!(
+				target: "runtime::vesting",
+				"migration: Vesting storage version v2 POST migration checks successful!"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1049,9 +1049,43 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+	});
+}
+
+#[test]
+fn migrate_to_v2_makes_schedules_linear() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched0 =
+			migrations::VestingInfoV1 { locked: ED * 5, per_block: ED, starting_block: 10 };
+		let sched1 = migrations::VestingInfoV1 { locked: ED * 2, per_block: 1, starting_block: 0 };
+		// Replace the genesis schedules with schedules in the old format.
+		StorageVersion::<Test>::put(Releases::V1);
+		let _ = VestingStorage::<Test>::clear(u32::MAX, None);
+		migrations::v1::Vesting::<Test>::insert(
+			&1,
+			BoundedVec::truncate_from(vec![sched0, sched1]),
+		);
+		migrations::v1::Vesting::<Test>::insert(&2, BoundedVec::truncate_from(vec![sched0]));
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+		assert_eq!(
+			VestingStorage::<Test>::get(&1).unwrap(),
+			vec![VestingInfo::new(ED * 5, ED, 10), VestingInfo::new(ED * 2, 1, 0)]
+		);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![VestingInfo::new(ED * 5, ED, 10)]
+		);
+
+		// Running the migration again is a no-op.
+		assert_storage_noop!(migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade());
 	});
 }
 
@@ -1122,6 +1156,156 @@ fn vesting_info_ending_block_as_balance_works() {
 	);
 }
 
+#[test]
+fn vesting_info_shapes_validate_works() {
+	// The cliff must come after the start.
+	let cliff = VestingShape::Cliff { cliff_block: 10u64 };
+	assert_eq!(VestingInfo::new_with_shape(ED, 1u64, 10u64, cliff).is_valid(), false);
+	assert_eq!(VestingInfo::new_with_shape(ED, 1u64, 9u64, cliff).is_valid(), true);
+
+	// The period can't be 0.
+	let step_0 = VestingShape::Step { period: 0u64 };
+	assert_eq!(VestingInfo::new_with_shape(ED, 1u64, 10u64, step_0).is_valid(), false);
+	let step_1 = VestingShape::Step { period: 1u64 };
+	assert_eq!(VestingInfo::new_with_shape(ED, 1u64, 10u64, step_1).is_valid(), true);
+}
+
+#[test]
+fn cliff_vesting_info_works() {
+	// Vests over 32 blocks, but nothing unlocks before block 20.
+	let cliff =
+		VestingInfo::new_with_shape(256u32, 8u32, 10u32, VestingShape::Cliff { cliff_block: 20 });
+	assert_eq!(cliff.locked_at::<Identity>(15), 256);
+	assert_eq!(cliff.locked_at::<Identity>(19), 256);
+	// Everything that vested since the start unlocks at the cliff.
+	assert_eq!(cliff.locked_at::<Identity>(20), 256 - 10 * 8);
+	assert_eq!(cliff.locked_at::<Identity>(30), 256 - 20 * 8);
+	assert_eq!(cliff.ending_block_as_balance::<Identity>(), 42);
+	assert_eq!(cliff.locked_at::<Identity>(42), 0);
+
+	// A cliff after the linear end unlocks everything at once.
+	let late_cliff =
+		VestingInfo::new_with_shape(256u32, 8u32, 10u32, VestingShape::Cliff { cliff_block: 50 });
+	assert_eq!(late_cliff.locked_at::<Identity>(49), 256);
+	assert_eq!(late_cliff.ending_block_as_balance::<Identity>(), 50);
+	assert_eq!(late_cliff.locked_at::<Identity>(50), 0);
+}
+
+#[test]
+fn step_vesting_info_works() {
+	// Unlocks 5 * 8 every 5 blocks.
+	let step = VestingInfo::new_with_shape(256u32, 8u32, 10u32, VestingShape::Step { period: 5 });
+	assert_eq!(step.locked_at::<Identity>(14), 256);
+	assert_eq!(step.locked_at::<Identity>(15), 256 - 5 * 8);
+	assert_eq!(step.locked_at::<Identity>(19), 256 - 5 * 8);
+	assert_eq!(step.locked_at::<Identity>(20), 256 - 10 * 8);
+	// The remainder unlocks at the end of the period the linear schedule ends in.
+	assert_eq!(step.locked_at::<Identity>(44), 256 - 30 * 8);
+	assert_eq!(step.ending_block_as_balance::<Identity>(), 45);
+	assert_eq!(step.locked_at::<Identity>(45), 0);
+}
+
+#[test]
+fn merge_cliff_and_linear_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 should already have a vesting schedule.
+		let sched0 = VestingInfo::new(
+			ED * 20,
+			ED, // Vest over 20 blocks.
+			10,
+		);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+
+		// Nothing unlocks before block 15.
+		let sched1 =
+			VestingInfo::new_with_shape(ED * 10, ED, 10, VestingShape::Cliff { cliff_block: 15 });
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+
+		// The merged schedule keeps the cliff, and ends with the linear schedule.
+		let sched2 = VestingInfo::new_with_shape(
+			ED * 30,
+			ED * 30 / 20,
+			10,
+			VestingShape::Cliff { cliff_block: 15 },
+		);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2]);
+
+		System::set_block_number(14);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 30));
+		System::set_block_number(15);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 30 - 5 * (ED * 30 / 20)));
+	});
+}
+
+#[test]
+fn merge_step_and_linear_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched0 = VestingInfo::new(
+			ED * 20,
+			ED, // Vest over 20 blocks.
+			10,
+		);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+
+		// Unlocks every 4 blocks from block 12 and ends at block 24.
+		let sched1 = VestingInfo::new_with_shape(ED * 10, ED, 12, VestingShape::Step { period: 4 });
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched1));
+
+		System::set_block_number(14);
+		let locked = sched0.locked_at::<Identity>(14) + sched1.locked_at::<Identity>(14);
+		assert_eq!(locked, ED * 16 + ED * 10);
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+
+		// The merged schedule starts at the next step, and ends with the linear schedule.
+		let sched2 = VestingInfo::new_with_shape(
+			locked,
+			locked / (30 - 16),
+			16,
+			VestingShape::Step { period: 4 },
+		);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2]);
+
+		System::set_block_number(19);
+		assert_eq!(Vesting::vesting_balance(&2), Some(locked));
+		System::set_block_number(20);
+		assert_eq!(Vesting::vesting_balance(&2), Some(locked - 4 * (locked / 14)));
+	});
+}
+
+#[test]
+fn merge_schedules_with_incompatible_shapes_fails() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let cliff = VestingInfo::new_with_shape(
+			ED * 2,
+			ED / 2,
+			10,
+			VestingShape::Cliff { cliff_block: 12 },
+		);
+		let step = VestingInfo::new_with_shape(ED * 2, ED, 12, VestingShape::Step { period: 4 });
+		let other_step =
+			VestingInfo::new_with_shape(ED * 2, ED, 14, VestingShape::Step { period: 4 });
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, cliff));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, step));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, other_step));
+
+		// A cliff can't be merged with steps.
+		assert_noop!(
+			Vesting::merge_schedules(Some(4).into(), 0, 1),
+			Error::<Test>::ScheduleShapesNotMergeable
+		);
+		// Steps must unlock at the same blocks.
+		assert_noop!(
+			Vesting::merge_schedules(Some(4).into(), 1, 2),
+			Error::<Test>::ScheduleShapesNotMergeable
+		);
+
+		// Once the cliff has been reached, the schedule merges like a linear one.
+		System::set_block_number(12);
+		assert_ok!(Vesting::merge_schedules(Some(4).into(), 0, 1));
+	});
+}
+
 #[test]
 fn per_block_works() {
 	let per_block_0 = VestingInfo::new(256u32, 0u32, 10u32);
@@ -19,15 +19,36 @@
 
 use super::*;
 
+/// The shape of the curve along which a vesting schedule unlocks its funds.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum VestingShape<BlockNumber> {
+	/// `per_block` is unlocked every block after `starting_block`.
+	Linear,
+	/// Nothing is unlocked before `cliff_block`. At `cliff_block`, everything that vested linearly
+	/// since `starting_block` is unlocked at once, after which the schedule continues linearly.
+	Cliff {
+		/// The first block at which funds are unlocked.
+		cliff_block: BlockNumber,
+	},
+	/// `per_block * period` is unlocked every `period` blocks after `starting_block`.
+	Step {
+		/// Number of blocks between two unlocks.
+		period: BlockNumber,
+	},
+}
+
 /// Struct to encode the vesting schedule of an individual account.
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct VestingInfo<Balance, BlockNumber> {
 	/// Locked amount at genesis.
 	locked: Balance,
-	/// Amount that gets unlocked every block after `starting_block`.
+	/// Amount that gets unlocked every block after `starting_block`, on average for non-linear
+	/// shapes.
 	per_block: Balance,
 	/// Starting block for unlocking(vesting).
 	starting_block: BlockNumber,
+	/// Shape of the unlocking curve.
+	shape: VestingShape<BlockNumber>,
 }
 
 impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
@@ -35,19 +56,34 @@ where
 	Balance: AtLeast32BitUnsigned + Copy,
 	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
 {
-	/// Instantiate a new `VestingInfo`.
+	/// Instantiate a new linear `VestingInfo`.
 	pub fn new(
 		locked: Balance,
 		per_block: Balance,
 		starting_block: BlockNumber,
 	) -> VestingInfo<Balance, BlockNumber> {
-		VestingInfo { locked, per_block, starting_block }
+		Self::new_with_shape(locked, per_block, starting_block, VestingShape::Linear)
+	}
+
+	/// Instantiate a new `VestingInfo` unlocking along the given `shape`.
+	pub fn new_with_shape(
+		locked: Balance,
+		per_block: Balance,
+		starting_block: BlockNumber,
+		shape: VestingShape<BlockNumber>,
+	) -> VestingInfo<Balance, BlockNumber> {
+		VestingInfo { locked, per_block, starting_block, shape }
 	}
 
 	/// Validate parameters for `VestingInfo`. Note that this does not check
 	/// against `MinVestedTransfer`.
 	pub fn is_valid(&self) -> bool {
-		!self.locked.is_zero() && !self.raw_per_block().is_zero()
+		let valid_shape = match self.shape {
+			VestingShape::Linear => true,
+			VestingShape::Cliff { cliff_block } => cliff_block > self.starting_block,
+			VestingShape::Step { period } => !period.is_zero(),
+		};
+		valid_shape && !self.locked.is_zero() && !self.raw_per_block().is_zero()
 	}
 
 	/// Locked amount at schedule creation.
@@ -73,6 +109,11 @@ where
 		self.starting_block
 	}
 
+	/// Shape of the unlocking curve.
+	pub fn shape(&self) -> VestingShape<BlockNumber> {
+		self.shape
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
 		&self,
@@ -81,6 +122,16 @@ where
 		// Number of blocks that count toward vesting;
 		// saturating to 0 when n < starting_block.
 		let vested_block_count = n.saturating_sub(self.starting_block);
+		let vested_block_count = match self.shape {
+			VestingShape::Linear => vested_block_count,
+			VestingShape::Cliff { cliff_block } if n < cliff_block => Zero::zero(),
+			VestingShape::Cliff { .. } => vested_block_count,
+			VestingShape::Step { period } => {
+				// Only whole periods count toward vesting.
+				let period = period.max(One::one());
+				vested_block_count / period * period
+			},
+		};
 		let vested_block_count = BlockNumberToBalance::convert(vested_block_count);
 		// Return amount that is still locked in vesting.
 		vested_block_count
@@ -109,6 +160,19 @@ where
 				}
 		};
 
-		starting_block.saturating_add(duration)
+		match self.shape {
+			VestingShape::Linear => starting_block.saturating_add(duration),
+			// Nothing unlocks before the cliff.
+			VestingShape::Cliff { cliff_block } => starting_block
+				.saturating_add(duration)
+				.max(BlockNumberToBalance::convert(cliff_block)),
+			VestingShape::Step { period } => {
+				// The last unlock happens at the end of the period the linear schedule ends in.
+				let period = BlockNumberToBalance::convert(period.max(One::one()));
+				let periods = duration / period +
+					if (duration % period).is_zero() { Zero::zero() } else { One::one() };
+				starting_block.saturating_add(periods.saturating_mul(period))
+			},
+		}
 	}
 }
@@ -577,6 +577,16 @@ pub trait DelegationInterface {
 		value: Self::Balance,
 		maybe_reporter: Option<Self::AccountId>,
 	) -> DispatchResult;
+
+	/// Transfer the entire delegation of `delegator` to `Agent` over to `new_delegator`.
+	///
+	/// The held funds are moved along with the delegation. `new_delegator` must not already be
+	/// delegating or be an `Agent`.
+	fn transfer_delegation(
+		agent: Agent<Self::AccountId>,
+		delegator: Delegator<Self::AccountId>,
+		new_delegator: Delegator<Self::AccountId>,
+	) -> DispatchResult;
 }
 
 /// Trait to provide functionality for direct stakers to migrate to delegation agents.
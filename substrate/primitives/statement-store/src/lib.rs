@@ -48,7 +48,8 @@ pub const MAX_TOPICS: usize = 4;
 
 #[cfg(feature = "std")]
 pub use store_api::{
-	Error, NetworkPriority, Result, StatementSource, StatementStore, SubmitResult,
+	AccountUsage, Error, NetworkPriority, RejectedStatements, Result, StatementFilter,
+	StatementSource, StatementStore, StatementStream, SubmitResult,
 };
 
 #[cfg(feature = "std")]
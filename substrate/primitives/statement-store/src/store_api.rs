@@ -16,7 +16,10 @@
 // limitations under the License.
 
 pub use crate::runtime_api::StatementSource;
-use crate::{Hash, Statement, Topic};
+use crate::{AccountId, DecryptionKey, Hash, Statement, Topic, MAX_TOPICS};
+use codec::{Decode, Encode};
+use futures::Stream;
+use std::pin::Pin;
 
 /// Statement store error.
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -61,6 +64,67 @@ pub enum SubmitResult {
 /// Result type for `Error`
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Stream of the statements added to the store.
+pub type StatementStream = Pin<Box<dyn Stream<Item = Statement> + Send>>;
+
+/// Filter of the statements notified to a subscriber.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementFilter {
+	/// Statements which include all of the topics.
+	MatchAll(Vec<Topic>),
+	/// Statements which include at least one of the topics.
+	MatchAny(Vec<Topic>),
+	/// Statements whose decryption key is identified as the given key.
+	DecryptionKey(DecryptionKey),
+}
+
+impl StatementFilter {
+	/// Check if the statement passes the filter.
+	pub fn matches(&self, statement: &Statement) -> bool {
+		let mut topics = (0..MAX_TOPICS).filter_map(|index| statement.topic(index));
+		match self {
+			StatementFilter::MatchAll(match_all) => {
+				let topics = topics.collect::<Vec<_>>();
+				match_all.iter().all(|topic| topics.contains(topic))
+			},
+			StatementFilter::MatchAny(match_any) => topics.any(|topic| match_any.contains(&topic)),
+			StatementFilter::DecryptionKey(key) => statement.decryption_key() == Some(*key),
+		}
+	}
+}
+
+/// Number of valid statements of an account which were not added to the store, by reason.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+pub struct RejectedStatements {
+	/// The statement data was larger than the account `max_size`.
+	pub data_too_large: u64,
+	/// The channel of the statement was holding a statement with higher or equal priority.
+	pub channel_priority_too_low: u64,
+	/// The account quota was full with statements of higher or equal priority.
+	pub account_full: u64,
+	/// The store was full.
+	pub store_full: u64,
+}
+
+/// Store usage and quota of an account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountUsage {
+	/// Number of statements of the account in the store.
+	pub statement_count: u32,
+	/// Total data size of the statements of the account in the store.
+	pub data_size: u64,
+	/// Maximum number of statements, as computed by the runtime for the last statement of the
+	/// account. `None` if it is not known.
+	pub max_count: Option<u32>,
+	/// Maximum total data size, as computed by the runtime for the last statement of the
+	/// account. `None` if it is not known.
+	pub max_size: Option<u32>,
+	/// Number of statements evicted to make room for higher priority statements.
+	pub evicted: u64,
+	/// Number of statements which were not added to the store.
+	pub rejected: RejectedStatements,
+}
+
 /// Statement store API.
 pub trait StatementStore: Send + Sync {
 	/// Return all statements.
@@ -87,4 +151,19 @@ pub trait StatementStore: Send + Sync {
 
 	/// Remove a statement from the store.
 	fn remove(&self, hash: &Hash) -> Result<()>;
+
+	/// Subscribe to the statements matching `filter` which are added to the store from now on,
+	/// whether they are submitted locally or received from the network.
+	///
+	/// Stores which don't support subscriptions return a stream which ends immediately.
+	fn subscribe_statements(&self, _filter: StatementFilter) -> StatementStream {
+		Box::pin(futures::stream::empty())
+	}
+
+	/// Return the store usage and quota of `account`.
+	///
+	/// Stores which don't track the usage of accounts return an empty usage.
+	fn account_usage(&self, _account: &AccountId) -> Result<AccountUsage> {
+		Ok(AccountUsage::default())
+	}
 }